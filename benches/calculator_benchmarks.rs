@@ -1,7 +1,73 @@
+//! Calculator benchmarks.
+//!
+//! To track regressions, save a baseline on the main branch and compare a
+//! change against it; criterion prints the change from the baseline for
+//! each benchmark:
+//!
+//! ```text
+//! cargo bench -- --save-baseline main
+//! cargo bench -- --baseline main
+//! ```
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use tax_engine::calculators::RoundingMode;
 use tax_engine::*;
 
+/// The 2024 US federal schedule for single filers.
+fn federal_schedule_2024() -> TaxSchedule {
+    let thresholds = [
+        dec!(0),
+        dec!(11600),
+        dec!(47150),
+        dec!(100525),
+        dec!(191950),
+        dec!(243725),
+        dec!(609350),
+    ];
+    let rates = [
+        dec!(0.10),
+        dec!(0.12),
+        dec!(0.22),
+        dec!(0.24),
+        dec!(0.32),
+        dec!(0.35),
+        dec!(0.37),
+    ];
+
+    let brackets = thresholds
+        .iter()
+        .zip(rates)
+        .enumerate()
+        .map(|(index, (lower_bound, rate))| TaxBracket {
+            lower_bound: *lower_bound,
+            upper_bound: thresholds.get(index + 1).copied(),
+            rate,
+        })
+        .collect();
+
+    TaxSchedule::new(2024, brackets)
+}
+
+/// An entity with 50 deductions spread across every category.
+fn deduction_heavy_entity() -> TaxEntity {
+    let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(250000), 2024);
+    for i in 0..50u32 {
+        let category = match i % 3 {
+            0 => DeductionType::Personal,
+            1 => DeductionType::Business,
+            _ => DeductionType::Charitable,
+        };
+        entity.add_documented_deduction(
+            dec!(250) + Decimal::from(i),
+            category,
+            format!("receipt-{i}"),
+        );
+    }
+    entity
+}
+
 fn tax_calculation_benchmark(c: &mut Criterion) {
     let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
 
@@ -28,5 +94,75 @@ fn tax_calculation_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, tax_calculation_benchmark);
+fn federal_schedule_benchmark(c: &mut Criterion) {
+    let schedule = federal_schedule_2024();
+    let entity = TaxEntity::new(TaxEntityType::Individual, dec!(750000), 2024);
+
+    c.bench_function("calculate tax across 7 federal brackets", |b| {
+        b.iter(|| IncomeTaxCalculator::calculate_tax(black_box(&entity), black_box(&schedule)))
+    });
+
+    c.bench_function("tax breakdown across 7 federal brackets", |b| {
+        b.iter(|| {
+            IncomeTaxCalculator::calculate_tax_breakdown(
+                black_box(&entity),
+                black_box(&schedule),
+                RoundingMode::PerBracket,
+            )
+        })
+    });
+
+    c.bench_function("tax in top of 7 federal brackets", |b| {
+        b.iter(|| IncomeTaxCalculator::tax_in_top_bracket(black_box(&entity), black_box(&schedule)))
+    });
+
+    let table_entity = TaxEntity::new(TaxEntityType::Individual, dec!(62540), 2024);
+    c.bench_function("tax-table method under 100k", |b| {
+        b.iter(|| {
+            IncomeTaxCalculator::calculate_tax_table_method(
+                black_box(&table_entity),
+                black_box(&schedule),
+            )
+        })
+    });
+}
+
+fn deduction_heavy_benchmark(c: &mut Criterion) {
+    let schedule = federal_schedule_2024();
+    let entity = deduction_heavy_entity();
+
+    c.bench_function("calculate tax with 50 deductions", |b| {
+        b.iter(|| IncomeTaxCalculator::calculate_tax(black_box(&entity), black_box(&schedule)))
+    });
+
+    c.bench_function("diff entities with 50 deductions", |b| {
+        let mut updated = entity.clone();
+        updated.deductions.reverse();
+        updated.add_deduction(dec!(1000), DeductionType::Charitable);
+        b.iter(|| black_box(&entity).diff(black_box(&updated)))
+    });
+}
+
+fn top_bracket_benchmark(c: &mut Criterion) {
+    let schedule = federal_schedule_2024();
+    let incomes: Vec<Decimal> = (0..10_000u32)
+        .map(|i| Decimal::from(i) * dec!(73.17))
+        .collect();
+
+    c.bench_function("top bracket reached for 10,000 incomes", |b| {
+        b.iter(|| {
+            for income in &incomes {
+                black_box(schedule.top_bracket_reached(black_box(*income)));
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    tax_calculation_benchmark,
+    federal_schedule_benchmark,
+    deduction_heavy_benchmark,
+    top_bracket_benchmark
+);
 criterion_main!(benches);
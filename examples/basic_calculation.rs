@@ -16,6 +16,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .fetch_rates(
             &Jurisdiction::Federal(Country::USA),
             &entity.entity_type,
+            entity.filing_status,
             entity.tax_year,
         )
         .await?;
@@ -1,7 +1,7 @@
 use rust_decimal_macros::dec;
 use tax_engine::{
-    format_currency, Country, DeductionType, IncomeTaxCalculator, Jurisdiction, MemoryCache,
-    TaxEntity, TaxEntityType, TaxRateScraper, USFederalScraper,
+    data::cache::TaxDataCache, format_currency, Country, DeductionType, IncomeTaxCalculator,
+    Jurisdiction, MemoryCache, TaxEntity, TaxEntityType, TaxRateScraper, USFederalScraper,
 };
 
 #[tokio::main]
@@ -26,10 +26,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .fetch_rates(
             &Jurisdiction::Federal(Country::USA),
             &entity.entity_type,
+            entity.filing_status,
             entity.tax_year,
         )
         .await?;
 
+    // Cache the schedule for subsequent calculations
+    cache
+        .set(
+            &Jurisdiction::Federal(Country::USA),
+            &entity.entity_type,
+            entity.filing_status,
+            entity.tax_year,
+            us_schedule.clone(),
+        )
+        .await?;
+
     // Calculate federal tax
     let federal_tax = IncomeTaxCalculator::calculate_tax(&entity, &us_schedule)?;
 
@@ -0,0 +1,119 @@
+//! File-backed implementation of the audit log.
+//!
+//! Appends each calculation as one line of JSON, so the log can be inspected
+//! with standard line-oriented tools and is never rewritten.
+
+use super::{AuditLog, CalculationEvent};
+use crate::errors::TaxError;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// An append-only audit log written as JSON lines.
+///
+/// The file is opened in append mode, so existing lines are never modified
+/// and several processes can share a log.
+pub struct FileAuditLog {
+    file: Mutex<File>,
+}
+
+impl FileAuditLog {
+    /// Opens the log at `path`, creating the file if it does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The log file to append to
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::AuditError` if the file cannot be opened for appending.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, TaxError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| TaxError::AuditError(e.to_string()))?;
+
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl AuditLog for FileAuditLog {
+    fn record(&self, event: CalculationEvent) -> Result<(), TaxError> {
+        let mut line =
+            serde_json::to_string(&event).map_err(|e| TaxError::AuditError(e.to_string()))?;
+        line.push('\n');
+
+        // Write the whole line at once so concurrent appends don't interleave
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| TaxError::AuditError("audit log lock poisoned".to_string()))?;
+        file.write_all(line.as_bytes())
+            .and_then(|()| file.flush())
+            .map_err(|e| TaxError::AuditError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::AuditedCalculator;
+    use crate::models::{TaxBracket, TaxEntity, TaxEntityType, TaxSchedule};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_each_calculation_appends_a_line() {
+        let path =
+            std::env::temp_dir().join(format!("tax_engine_audit_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.15),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.25),
+                },
+            ],
+        );
+        let calculator = AuditedCalculator::new(FileAuditLog::open(&path).unwrap());
+        calculator
+            .calculate_tax(
+                &TaxEntity::new(TaxEntityType::Individual, dec!(40000), 2024),
+                &schedule,
+            )
+            .unwrap();
+        calculator
+            .calculate_tax(
+                &TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024),
+                &schedule,
+            )
+            .unwrap();
+
+        // A failed calculation is not recorded
+        let wrong_year = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2023);
+        assert!(calculator.calculate_tax(&wrong_year, &schedule).is_err());
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let events: Vec<CalculationEvent> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].tax, dec!(6000));
+        assert_eq!(events[1].tax, dec!(15000));
+        assert_eq!(events[1].schedule_year, 2024);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
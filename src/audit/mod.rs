@@ -0,0 +1,111 @@
+//! Audit trail of completed tax calculations.
+//!
+//! This module provides a trait for recording each calculation as it happens,
+//! and a calculator wrapper that records every successful result, so the
+//! figures a system reported can be traced back to their inputs later.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, TaxEntity, TaxEntityType, TaxSchedule};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+/// A summary of one completed tax calculation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalculationEvent {
+    /// The type of the entity that was taxed
+    pub entity_type: TaxEntityType,
+    /// The entity's filing status
+    pub filing_status: FilingStatus,
+    /// The entity's gross income
+    pub income: Decimal,
+    /// The taxable income the schedule was applied to
+    pub taxable_income: Decimal,
+    /// The tax year of the schedule used
+    pub schedule_year: u16,
+    /// The computed tax
+    pub tax: Decimal,
+    /// When the calculation was recorded
+    pub recorded_at: SystemTime,
+}
+
+impl CalculationEvent {
+    /// Summarizes a calculation of `tax` for an entity under a schedule,
+    /// timestamped now.
+    pub fn new(entity: &TaxEntity, schedule: &TaxSchedule, tax: Decimal) -> Self {
+        Self {
+            entity_type: entity.entity_type.clone(),
+            filing_status: entity.filing_status,
+            income: entity.income,
+            taxable_income: entity.taxable_income(),
+            schedule_year: schedule.tax_year,
+            tax,
+            recorded_at: SystemTime::now(),
+        }
+    }
+}
+
+/// Defines the interface for audit log implementations.
+pub trait AuditLog: Send + Sync {
+    /// Records a completed calculation.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The calculation to record
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the event was durably recorded
+    /// * `Err(TaxError)` if recording fails
+    fn record(&self, event: CalculationEvent) -> Result<(), TaxError>;
+}
+
+/// Income tax calculator that records every successful calculation.
+pub struct AuditedCalculator<L: AuditLog> {
+    log: L,
+}
+
+impl<L: AuditLog> AuditedCalculator<L> {
+    /// Creates a calculator that records calculations to `log`.
+    pub fn new(log: L) -> Self {
+        Self { log }
+    }
+
+    /// Returns the audit log calculations are recorded to.
+    pub fn log(&self) -> &L {
+        &self.log
+    }
+
+    /// Calculates tax as [`IncomeTaxCalculator::calculate_tax`] does and
+    /// records the result.
+    ///
+    /// Failed calculations are not recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The calculated tax amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the calculation, or the audit log's error if
+    /// the result could not be recorded. A result that could not be recorded
+    /// is not returned.
+    pub fn calculate_tax(
+        &self,
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        let tax = IncomeTaxCalculator::calculate_tax(entity, schedule)?;
+        self.log
+            .record(CalculationEvent::new(entity, schedule, tax))?;
+        Ok(tax)
+    }
+}
+
+pub mod file;
@@ -0,0 +1,251 @@
+//! Alternative Minimum Tax calculations.
+//!
+//! The AMT recomputes tax on a broader base, alternative minimum taxable
+//! income (AMTI), which adds back deductions the AMT doesn't allow. A flat
+//! exemption is subtracted, shrinking by 25 cents per dollar of AMTI above a
+//! phase-out threshold, and the remainder is taxed under a two-tier schedule.
+//! The taxpayer owes the greater of the regular tax and this tentative
+//! minimum tax.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{DeductionType, TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Reduction in the AMT exemption per dollar of AMTI above the phase-out
+/// threshold.
+pub const AMT_EXEMPTION_PHASE_OUT_RATE: Decimal = dec!(0.25);
+
+/// The tax regime that determined the amount owed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TaxRegime {
+    /// The regular income tax was at least the tentative minimum tax
+    Regular,
+    /// The tentative minimum tax exceeded the regular income tax
+    Alternative,
+}
+
+/// The tax owed once the regular tax and the AMT are compared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxLiability {
+    /// The tax actually owed
+    pub amount: Decimal,
+    /// Which regime produced `amount`
+    pub regime: TaxRegime,
+}
+
+/// Calculator for the Alternative Minimum Tax.
+pub struct AmtCalculator;
+
+impl AmtCalculator {
+    /// Calculates alternative minimum taxable income.
+    ///
+    /// This is taxable income with personal and state and local tax
+    /// deductions added back, since the AMT allows neither.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose AMTI should be calculated
+    pub fn alternative_minimum_taxable_income(entity: &TaxEntity) -> Decimal {
+        let income = entity.recognized_income();
+        let add_backs = entity
+            .deductions
+            .iter()
+            .filter(|d| {
+                matches!(
+                    d.category,
+                    DeductionType::Personal | DeductionType::StateAndLocalTax
+                )
+            })
+            .fold(Decimal::ZERO, |acc, d| acc + d.amount_for(income));
+
+        entity.taxable_income() + add_backs
+    }
+
+    /// Calculates the tentative minimum tax.
+    ///
+    /// The exemption is reduced by [`AMT_EXEMPTION_PHASE_OUT_RATE`] of the
+    /// AMTI above `phase_out_threshold`, down to zero, and the AMTI less the
+    /// remaining exemption is taxed under `amt_schedule`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `exemption` - The AMT exemption amount for the entity's filing status
+    /// * `phase_out_threshold` - AMTI above which the exemption phases out
+    /// * `amt_schedule` - The AMT rate schedule, such as 26% and 28% tiers
+    ///
+    /// # Returns
+    ///
+    /// The tentative minimum tax, or zero if the exemption covers all AMTI.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn tentative_minimum_tax(
+        entity: &TaxEntity,
+        exemption: Decimal,
+        phase_out_threshold: Decimal,
+        amt_schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        IncomeTaxCalculator::check_compatibility(entity, amt_schedule)?;
+
+        let amti = Self::alternative_minimum_taxable_income(entity);
+        let phase_out =
+            (amti - phase_out_threshold).max(Decimal::ZERO) * AMT_EXEMPTION_PHASE_OUT_RATE;
+        let exemption = (exemption - phase_out).max(Decimal::ZERO);
+        let amt_base = (amti - exemption).max(Decimal::ZERO);
+
+        Ok(IncomeTaxCalculator::tax_on(amt_base, amt_schedule))
+    }
+
+    /// Determines the tax owed from the regular tax and the tentative
+    /// minimum tax.
+    ///
+    /// # Arguments
+    ///
+    /// * `regular_tax` - The tax from [`IncomeTaxCalculator::calculate_tax`]
+    /// * `tentative_minimum_tax` - The tax from [`AmtCalculator::tentative_minimum_tax`]
+    ///
+    /// # Returns
+    ///
+    /// The greater of the two amounts, and the regime it came from. A tie
+    /// owes no AMT, so is attributed to the regular regime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::{AmtCalculator, TaxRegime};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let liability = AmtCalculator::liability(dec!(42000), dec!(55000));
+    /// assert_eq!(liability.amount, dec!(55000));
+    /// assert_eq!(liability.regime, TaxRegime::Alternative);
+    /// ```
+    pub fn liability(regular_tax: Decimal, tentative_minimum_tax: Decimal) -> TaxLiability {
+        if tentative_minimum_tax > regular_tax {
+            TaxLiability {
+                amount: tentative_minimum_tax,
+                regime: TaxRegime::Alternative,
+            }
+        } else {
+            TaxLiability {
+                amount: regular_tax,
+                regime: TaxRegime::Regular,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TaxBracket, TaxEntityType};
+
+    const EXEMPTION: Decimal = dec!(85700);
+    const PHASE_OUT_THRESHOLD: Decimal = dec!(609350);
+
+    fn regular_schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.24),
+                },
+            ],
+        )
+    }
+
+    fn amt_schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(232600)),
+                    rate: dec!(0.26),
+                },
+                TaxBracket {
+                    lower_bound: dec!(232600),
+                    upper_bound: None,
+                    rate: dec!(0.28),
+                },
+            ],
+        )
+    }
+
+    fn liability_for(entity: &TaxEntity) -> TaxLiability {
+        let regular = IncomeTaxCalculator::calculate_tax(entity, &regular_schedule()).unwrap();
+        let amt = AmtCalculator::tentative_minimum_tax(
+            entity,
+            EXEMPTION,
+            PHASE_OUT_THRESHOLD,
+            &amt_schedule(),
+        )
+        .unwrap();
+        AmtCalculator::liability(regular, amt)
+    }
+
+    #[test]
+    fn test_amt_applies_with_large_add_backs() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(300000), 2024);
+        entity.add_deduction(dec!(80000), DeductionType::StateAndLocalTax);
+        entity.add_deduction(dec!(14600), DeductionType::Personal);
+
+        assert_eq!(
+            AmtCalculator::alternative_minimum_taxable_income(&entity),
+            dec!(300000)
+        );
+        // Regular tax on 205,400 is 42,296; AMT on 214,300 is 55,718
+        assert_eq!(
+            liability_for(&entity),
+            TaxLiability {
+                amount: dec!(55718),
+                regime: TaxRegime::Alternative,
+            }
+        );
+    }
+
+    #[test]
+    fn test_regular_tax_applies_without_add_backs() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+
+        assert_eq!(
+            liability_for(&entity),
+            TaxLiability {
+                amount: dec!(17000),
+                regime: TaxRegime::Regular,
+            }
+        );
+    }
+
+    #[test]
+    fn test_exemption_phases_out_above_threshold() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(700000), 2024);
+
+        // The exemption shrinks by 22,662.50 to 63,037.50
+        let amt = AmtCalculator::tentative_minimum_tax(
+            &entity,
+            EXEMPTION,
+            PHASE_OUT_THRESHOLD,
+            &amt_schedule(),
+        )
+        .unwrap();
+        assert_eq!(amt, dec!(173697.50));
+
+        let stale = TaxSchedule::new(2023, amt_schedule().brackets);
+        assert!(matches!(
+            AmtCalculator::tentative_minimum_tax(&entity, EXEMPTION, PHASE_OUT_THRESHOLD, &stale),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+}
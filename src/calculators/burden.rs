@@ -0,0 +1,116 @@
+//! Estimates the overall tax burden including consumption taxes.
+//!
+//! Income tax is only part of what a household pays; sales taxes on the
+//! after-tax income it spends add to the burden.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+
+/// Income and consumption taxes combined.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxBurden {
+    /// Income tax owed
+    pub income_tax: Decimal,
+    /// Estimated sales tax on the after-tax income spent
+    pub consumption_tax: Decimal,
+    /// Combined taxes as a fraction of gross income (e.g., 0.25 for 25%)
+    pub burden_rate: Decimal,
+}
+
+impl TaxBurden {
+    /// Combined income and consumption tax.
+    pub fn total_tax(&self) -> Decimal {
+        self.income_tax + self.consumption_tax
+    }
+}
+
+/// Calculator for the combined income and consumption tax burden.
+pub struct TaxBurdenCalculator;
+
+impl TaxBurdenCalculator {
+    /// Estimates income and consumption taxes as a share of gross income.
+    ///
+    /// After-tax income is gross recognized income less income tax. The share
+    /// given by `consumption_fraction` is assumed to be spent on purchases
+    /// priced before sales tax, each taxed at `sales_tax_rate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The income tax schedule
+    /// * `sales_tax_rate` - Sales tax rate as a decimal (e.g., 0.07 for 7%)
+    /// * `consumption_fraction` - Share of after-tax income spent, clamped to the range 0 to 1
+    ///
+    /// # Returns
+    ///
+    /// The income tax, estimated consumption tax and combined burden rate. The
+    /// burden rate is zero when gross income is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year doesn't match
+    /// the schedule's tax year.
+    pub fn calculate(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        sales_tax_rate: Decimal,
+        consumption_fraction: Decimal,
+    ) -> Result<TaxBurden, TaxError> {
+        let income_tax = IncomeTaxCalculator::calculate_tax(entity, schedule)?;
+        let gross_income = entity.recognized_income();
+
+        let fraction = consumption_fraction.max(Decimal::ZERO).min(Decimal::ONE);
+        let spending = ((gross_income - income_tax) * fraction).max(Decimal::ZERO);
+        let consumption_tax = spending * sales_tax_rate;
+
+        let burden_rate = if gross_income > Decimal::ZERO {
+            (income_tax + consumption_tax) / gross_income
+        } else {
+            Decimal::ZERO
+        };
+
+        Ok(TaxBurden {
+            income_tax,
+            consumption_tax,
+            burden_rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TaxBracket, TaxEntityType};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_burden_with_sales_tax() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.15),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.25),
+                },
+            ],
+        );
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+
+        let burden =
+            TaxBurdenCalculator::calculate(&entity, &schedule, dec!(0.05), dec!(0.80)).unwrap();
+
+        // 80% of the 80,000 left after income tax is spent, taxed at 5%
+        assert_eq!(burden.income_tax, dec!(20000));
+        assert_eq!(burden.consumption_tax, dec!(3200));
+        assert_eq!(burden.total_tax(), dec!(23200));
+        assert_eq!(burden.burden_rate, dec!(0.232));
+    }
+}
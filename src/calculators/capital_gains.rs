@@ -0,0 +1,210 @@
+//! Tax calculations that give capital gains their own rates.
+//!
+//! Long-term capital gains and qualified dividends are taxed under a separate
+//! schedule from wages. That income is stacked on top of ordinary income: its
+//! brackets are filled starting from the ordinary taxable income, so gains
+//! only reach the zero-rate bracket to the extent ordinary income leaves room
+//! in it.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+
+/// Tax owed on ordinary and preferential income.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapitalGainsTax {
+    /// Tax on ordinary income, including short-term gains
+    pub ordinary_tax: Decimal,
+    /// Tax on long-term gains and qualified dividends
+    pub gains_tax: Decimal,
+}
+
+impl CapitalGainsTax {
+    /// Combined tax on ordinary and preferential income.
+    pub fn total_tax(&self) -> Decimal {
+        self.ordinary_tax + self.gains_tax
+    }
+}
+
+/// Calculator for entities with capital gains.
+pub struct CapitalGainsCalculator;
+
+impl CapitalGainsCalculator {
+    /// Calculates tax with long-term gains and qualified dividends taxed
+    /// under their own schedule.
+    ///
+    /// Deductions reduce ordinary income first, so preferential income is
+    /// only reduced once ordinary income is used up. Short-term gains and
+    /// untyped income are ordinary income. A net long-term loss leaves no
+    /// preferential income; the loss has already reduced ordinary income
+    /// through the entity's total income.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `ordinary_schedule` - The schedule for ordinary income
+    /// * `gains_schedule` - The schedule for long-term gains and qualified
+    ///   dividends
+    ///
+    /// # Returns
+    ///
+    /// The tax on ordinary income and on preferential income. Zero or
+    /// negative taxable income yields zero tax on both.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match either schedule's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::CapitalGainsCalculator;
+    /// use tax_engine::models::{IncomeType, TaxEntity, TaxEntityType, TaxSchedule};
+    /// # use rust_decimal_macros::dec;
+    ///
+    /// let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(60000), 2024);
+    /// entity.add_income(dec!(20000), IncomeType::LongTermGain);
+    /// # let ordinary = TaxSchedule::new(2024, vec![]);
+    /// # let gains = TaxSchedule::new(2024, vec![]);
+    /// let tax = CapitalGainsCalculator::calculate(&entity, &ordinary, &gains)?;
+    /// # Ok::<(), tax_engine::TaxError>(())
+    /// ```
+    pub fn calculate(
+        entity: &TaxEntity,
+        ordinary_schedule: &TaxSchedule,
+        gains_schedule: &TaxSchedule,
+    ) -> Result<CapitalGainsTax, TaxError> {
+        IncomeTaxCalculator::check_compatibility(entity, ordinary_schedule)?;
+        IncomeTaxCalculator::check_compatibility(entity, gains_schedule)?;
+
+        let taxable_income = entity.taxable_income().max(Decimal::ZERO);
+        let preferential = entity
+            .preferential_income()
+            .max(Decimal::ZERO)
+            .min(taxable_income);
+        let ordinary_income = taxable_income - preferential;
+
+        // Gains fill the gains brackets from where ordinary income leaves off
+        let gains_tax = IncomeTaxCalculator::tax_on(taxable_income, gains_schedule)
+            - IncomeTaxCalculator::tax_on(ordinary_income, gains_schedule);
+
+        Ok(CapitalGainsTax {
+            ordinary_tax: IncomeTaxCalculator::tax_on(ordinary_income, ordinary_schedule),
+            gains_tax,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DeductionType, IncomeType, TaxBracket, TaxEntityType};
+    use rust_decimal_macros::dec;
+
+    fn ordinary_schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.30),
+                },
+            ],
+        )
+    }
+
+    fn gains_schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(40000)),
+                    rate: dec!(0),
+                },
+                TaxBracket {
+                    lower_bound: dec!(40000),
+                    upper_bound: None,
+                    rate: dec!(0.15),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_gains_bracketed_above_ordinary_income() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(30000), 2024);
+        entity.add_income(dec!(30000), IncomeType::LongTermGain);
+        assert_eq!(entity.income, dec!(60000));
+
+        let tax =
+            CapitalGainsCalculator::calculate(&entity, &ordinary_schedule(), &gains_schedule())
+                .unwrap();
+        // Ordinary income stays in the 10% bracket; the gains straddle 40,000
+        assert_eq!(tax.ordinary_tax, dec!(3000));
+        assert_eq!(tax.gains_tax, dec!(20000) * dec!(0.15));
+        assert_eq!(tax.total_tax(), dec!(6000));
+
+        // Taxed as ordinary income, the same 60,000 would owe 8,000
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&entity, &ordinary_schedule()).unwrap(),
+            dec!(8000)
+        );
+    }
+
+    #[test]
+    fn test_short_term_gains_taxed_as_ordinary() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(30000), 2024);
+        entity.add_income(dec!(30000), IncomeType::ShortTermGain);
+
+        let tax =
+            CapitalGainsCalculator::calculate(&entity, &ordinary_schedule(), &gains_schedule())
+                .unwrap();
+        assert_eq!(tax.ordinary_tax, dec!(8000));
+        assert_eq!(tax.gains_tax, dec!(0));
+    }
+
+    #[test]
+    fn test_net_long_term_loss_taxed_as_ordinary() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(60000), 2024);
+        entity.add_income(dec!(5000), IncomeType::QualifiedDividend);
+        entity.add_income(dec!(-10000), IncomeType::LongTermGain);
+        assert_eq!(entity.income, dec!(55000));
+
+        let tax =
+            CapitalGainsCalculator::calculate(&entity, &ordinary_schedule(), &gains_schedule())
+                .unwrap();
+        // The 5,000 net loss offsets ordinary income, and nothing is left
+        // for the gains schedule
+        assert_eq!(tax.ordinary_tax, dec!(6500));
+        assert_eq!(tax.gains_tax, dec!(0));
+    }
+
+    #[test]
+    fn test_deductions_reduce_ordinary_income_first() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(10000), 2024);
+        entity.add_income(dec!(50000), IncomeType::QualifiedDividend);
+        entity.add_deduction(dec!(15000), DeductionType::Personal);
+
+        let tax =
+            CapitalGainsCalculator::calculate(&entity, &ordinary_schedule(), &gains_schedule())
+                .unwrap();
+        // 45,000 taxable, all of it dividends: 5,000 falls above 40,000
+        assert_eq!(tax.ordinary_tax, dec!(0));
+        assert_eq!(tax.gains_tax, dec!(750));
+
+        let stale = TaxSchedule::new(2023, gains_schedule().brackets);
+        assert!(matches!(
+            CapitalGainsCalculator::calculate(&entity, &ordinary_schedule(), &stale),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+}
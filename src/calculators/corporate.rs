@@ -0,0 +1,274 @@
+//! Corporate tax calculations.
+//!
+//! US federal corporate income tax is a flat rate on taxable income. Some
+//! other regimes charge a lower rate on small profits and a main rate on
+//! large ones, with marginal relief tapering between them so the tax owed
+//! rises smoothly across the profit band instead of jumping at a threshold.
+//!
+//! Partnerships are pass-through entities: they pay no income tax
+//! themselves, and each partner's share of the profits is taxed on the
+//! partner's own return, so these calculators reject them.
+
+use crate::errors::TaxError;
+use crate::models::{TaxEntity, TaxEntityType};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// US federal corporate income tax rate for tax years from 2018.
+pub const US_FEDERAL_CORPORATE_RATE: Decimal = dec!(0.21);
+
+/// First tax year the flat US federal corporate rate applies to.
+pub const US_FEDERAL_FLAT_CORPORATE_RATE_SINCE: u16 = 2018;
+
+/// Rates and thresholds for a small-profits rate with marginal relief.
+///
+/// Profits up to `lower_threshold` are taxed at `small_profits_rate` and
+/// profits of `upper_threshold` or more at `main_rate`. In between, tax is
+/// charged at the main rate less `relief_fraction` of the amount by which
+/// profits fall short of the upper threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarginalRelief {
+    /// Profits at or below which the small-profits rate applies
+    pub lower_threshold: Decimal,
+    /// Profits at or above which the main rate applies without relief
+    pub upper_threshold: Decimal,
+    /// Rate on profits at or below the lower threshold as a decimal
+    pub small_profits_rate: Decimal,
+    /// Rate on profits at or above the upper threshold as a decimal
+    pub main_rate: Decimal,
+    /// Share of the shortfall below the upper threshold that is relieved
+    /// (e.g., 0.015 for the UK's 3/200)
+    pub relief_fraction: Decimal,
+}
+
+/// Calculator for tax owed by corporations.
+pub struct CorporateTaxCalculator;
+
+impl CorporateTaxCalculator {
+    /// Calculates US federal corporate income tax at the flat 21% rate.
+    ///
+    /// The flat rate applies from the 2018 tax year; earlier years used
+    /// graduated brackets. See [`CorporateTaxCalculator::calculate_flat_rate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity is not a corporation, or
+    /// `TaxError::RateNotAvailable` if its tax year is before
+    /// [`US_FEDERAL_FLAT_CORPORATE_RATE_SINCE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::CorporateTaxCalculator;
+    /// use tax_engine::{TaxEntity, TaxEntityType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let company = TaxEntity::new(TaxEntityType::Corporation, dec!(1000000), 2024);
+    /// let tax = CorporateTaxCalculator::calculate(&company).unwrap();
+    /// assert_eq!(tax, dec!(210000));
+    /// ```
+    pub fn calculate(entity: &TaxEntity) -> Result<Decimal, TaxError> {
+        require_corporation(entity, "corporate income tax")?;
+        if entity.tax_year < US_FEDERAL_FLAT_CORPORATE_RATE_SINCE {
+            return Err(TaxError::RateNotAvailable(entity.tax_year));
+        }
+
+        Self::calculate_flat_rate(entity, US_FEDERAL_CORPORATE_RATE)
+    }
+
+    /// Calculates corporate income tax at a single flat rate.
+    ///
+    /// Profits are the entity's taxable income; a loss owes no tax.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The corporation whose tax should be calculated
+    /// * `rate` - The tax rate as a decimal (e.g., 0.21 for 21%)
+    ///
+    /// # Returns
+    ///
+    /// The tax owed on the entity's profits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity is not a corporation.
+    /// Partnerships are rejected because their profits are taxed to their
+    /// partners.
+    pub fn calculate_flat_rate(entity: &TaxEntity, rate: Decimal) -> Result<Decimal, TaxError> {
+        require_corporation(entity, "corporate income tax")?;
+
+        Ok(entity.taxable_income().max(Decimal::ZERO) * rate)
+    }
+
+    /// Calculates corporation tax with marginal relief between two rates.
+    ///
+    /// Profits are the entity's taxable income; a loss owes no tax. The relief
+    /// fraction is normally chosen so the taper meets the small-profits rate
+    /// exactly at the lower threshold, giving a continuous schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The corporation whose tax should be calculated
+    /// * `relief` - The rates, thresholds and relief fraction
+    ///
+    /// # Returns
+    ///
+    /// The tax owed on the entity's profits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity is not a corporation, or
+    /// `TaxError::InvalidBrackets` if the upper threshold is not above the
+    /// lower threshold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::{CorporateTaxCalculator, MarginalRelief};
+    /// use tax_engine::{TaxEntity, TaxEntityType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let relief = MarginalRelief {
+    ///     lower_threshold: dec!(50000),
+    ///     upper_threshold: dec!(250000),
+    ///     small_profits_rate: dec!(0.19),
+    ///     main_rate: dec!(0.25),
+    ///     relief_fraction: dec!(0.015),
+    /// };
+    /// let company = TaxEntity::new(TaxEntityType::Corporation, dec!(100000), 2024);
+    /// let tax = CorporateTaxCalculator::calculate_with_marginal_relief(&company, &relief).unwrap();
+    /// assert_eq!(tax, dec!(22750));
+    /// ```
+    pub fn calculate_with_marginal_relief(
+        entity: &TaxEntity,
+        relief: &MarginalRelief,
+    ) -> Result<Decimal, TaxError> {
+        require_corporation(entity, "marginal relief")?;
+        if relief.upper_threshold <= relief.lower_threshold {
+            return Err(TaxError::InvalidBrackets(format!(
+                "marginal relief upper threshold {} is not above lower threshold {}",
+                relief.upper_threshold, relief.lower_threshold
+            )));
+        }
+
+        let profits = entity.taxable_income().max(Decimal::ZERO);
+
+        let tax = if profits <= relief.lower_threshold {
+            profits * relief.small_profits_rate
+        } else if profits >= relief.upper_threshold {
+            profits * relief.main_rate
+        } else {
+            profits * relief.main_rate - (relief.upper_threshold - profits) * relief.relief_fraction
+        };
+
+        Ok(tax)
+    }
+}
+
+/// Checks that a corporate calculation is being applied to a corporation.
+fn require_corporation(entity: &TaxEntity, calculation: &str) -> Result<(), TaxError> {
+    match entity.entity_type {
+        TaxEntityType::Corporation => Ok(()),
+        TaxEntityType::Partnership => Err(TaxError::Incompatible {
+            reason: format!(
+                "{} applies to corporations, not Partnership; partnership profits \
+                 pass through and are taxed on each partner's return",
+                calculation
+            ),
+        }),
+        TaxEntityType::Individual => Err(TaxError::Incompatible {
+            reason: format!(
+                "{} applies to corporations, not {:?}",
+                calculation, entity.entity_type
+            ),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DeductionType;
+
+    fn uk_relief() -> MarginalRelief {
+        MarginalRelief {
+            lower_threshold: dec!(50000),
+            upper_threshold: dec!(250000),
+            small_profits_rate: dec!(0.19),
+            main_rate: dec!(0.25),
+            relief_fraction: dec!(0.015),
+        }
+    }
+
+    fn tax_on(profits: Decimal) -> Decimal {
+        let company = TaxEntity::new(TaxEntityType::Corporation, profits, 2024);
+        CorporateTaxCalculator::calculate_with_marginal_relief(&company, &uk_relief()).unwrap()
+    }
+
+    #[test]
+    fn test_effective_rate_tapers_within_band() {
+        let profits = dec!(150000);
+        let tax = tax_on(profits);
+
+        // 37,500 at the main rate less 1.5% of the 100,000 shortfall
+        assert_eq!(tax, dec!(36000));
+        let effective_rate = tax / profits;
+        assert!(effective_rate > dec!(0.19) && effective_rate < dec!(0.25));
+
+        // The taper meets each statutory rate at its threshold
+        assert_eq!(tax_on(dec!(50000)), dec!(9500));
+        assert_eq!(tax_on(dec!(50001)), dec!(9500.265));
+        assert_eq!(tax_on(dec!(250000)), dec!(62500));
+        assert_eq!(tax_on(dec!(-1000)), dec!(0));
+    }
+
+    #[test]
+    fn test_rejects_non_corporations() {
+        let individual = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        assert!(matches!(
+            CorporateTaxCalculator::calculate_with_marginal_relief(&individual, &uk_relief()),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+
+    #[test]
+    fn test_flat_corporate_rate() {
+        let mut company = TaxEntity::new(TaxEntityType::Corporation, dec!(500000), 2024);
+        company.add_deduction(dec!(100000), DeductionType::Business);
+        assert_eq!(
+            CorporateTaxCalculator::calculate(&company).unwrap(),
+            dec!(84000)
+        );
+        assert_eq!(
+            CorporateTaxCalculator::calculate_flat_rate(&company, dec!(0.15)).unwrap(),
+            dec!(60000)
+        );
+
+        let loss = TaxEntity::new(TaxEntityType::Corporation, dec!(-20000), 2024);
+        assert_eq!(CorporateTaxCalculator::calculate(&loss).unwrap(), dec!(0));
+    }
+
+    #[test]
+    fn test_flat_rate_starts_in_2018() {
+        let company_2018 = TaxEntity::new(TaxEntityType::Corporation, dec!(100000), 2018);
+        assert_eq!(
+            CorporateTaxCalculator::calculate(&company_2018).unwrap(),
+            dec!(21000)
+        );
+
+        let company_2017 = TaxEntity::new(TaxEntityType::Corporation, dec!(100000), 2017);
+        assert!(matches!(
+            CorporateTaxCalculator::calculate(&company_2017),
+            Err(TaxError::RateNotAvailable(2017))
+        ));
+    }
+
+    #[test]
+    fn test_partnerships_pass_through() {
+        let partnership = TaxEntity::new(TaxEntityType::Partnership, dec!(500000), 2024);
+        match CorporateTaxCalculator::calculate(&partnership) {
+            Err(TaxError::Incompatible { reason }) => assert!(reason.contains("partner")),
+            other => panic!("expected Incompatible, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Chooses between the standard deduction and itemizing.
+//!
+//! Taxpayers can usually either claim a flat standard deduction or itemize
+//! their actual deductions. When both a federal and a state return are filed,
+//! some states require the same choice as the federal return while others let
+//! each return choose independently.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{DeductionType, TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+
+/// How deductions are claimed on a single return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeductionMethod {
+    /// Claim the flat standard deduction in place of the entity's deductions
+    Standard,
+    /// Claim the entity's own deductions
+    Itemized,
+}
+
+/// The deduction methods chosen for a federal and state return, with the resulting tax.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeductionPlan {
+    /// Method used on the federal return
+    pub federal_method: DeductionMethod,
+    /// Method used on the state return
+    pub state_method: DeductionMethod,
+    /// Federal tax under the chosen method
+    pub federal_tax: Decimal,
+    /// State tax under the chosen method
+    pub state_tax: Decimal,
+}
+
+impl DeductionPlan {
+    /// Combined federal and state tax for this plan.
+    pub fn total_tax(&self) -> Decimal {
+        self.federal_tax + self.state_tax
+    }
+}
+
+/// Optimizer that picks the standard-or-itemize combination with the lowest total tax.
+pub struct DeductionOptimizer;
+
+impl DeductionOptimizer {
+    /// Finds the deduction methods that minimize combined federal and state tax.
+    ///
+    /// Every combination of standard and itemized deductions across the two
+    /// returns is evaluated. When `coupled` is true, only combinations where
+    /// both returns use the same method are considered. Ties are resolved in
+    /// favor of the standard deduction, which needs no supporting records.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity; its deductions are the itemized deductions
+    /// * `federal_schedule` - The federal tax schedule
+    /// * `state_schedule` - The state tax schedule
+    /// * `federal_standard` - The federal standard deduction amount
+    /// * `state_standard` - The state standard deduction amount
+    /// * `coupled` - Whether the state requires the same method as the federal return
+    ///
+    /// # Returns
+    ///
+    /// The combination with the lowest total tax.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year doesn't match
+    /// either schedule's tax year.
+    pub fn optimize(
+        entity: &TaxEntity,
+        federal_schedule: &TaxSchedule,
+        state_schedule: &TaxSchedule,
+        federal_standard: Decimal,
+        state_standard: Decimal,
+        coupled: bool,
+    ) -> Result<DeductionPlan, TaxError> {
+        let methods = [DeductionMethod::Standard, DeductionMethod::Itemized];
+
+        let mut plans = Vec::with_capacity(4);
+        for federal_method in methods {
+            let federal_entity = Self::entity_for(entity, federal_method, federal_standard);
+            let federal_tax =
+                IncomeTaxCalculator::calculate_tax(&federal_entity, federal_schedule)?;
+
+            for state_method in methods {
+                if coupled && state_method != federal_method {
+                    continue;
+                }
+
+                let state_entity = Self::entity_for(entity, state_method, state_standard);
+                plans.push(DeductionPlan {
+                    federal_method,
+                    state_method,
+                    federal_tax,
+                    state_tax: IncomeTaxCalculator::calculate_tax(&state_entity, state_schedule)?,
+                });
+            }
+        }
+
+        Ok(plans
+            .into_iter()
+            .min_by_key(DeductionPlan::total_tax)
+            .expect("at least one deduction combination is always evaluated"))
+    }
+
+    /// Returns the entity as it would file under the given deduction method.
+    fn entity_for(entity: &TaxEntity, method: DeductionMethod, standard: Decimal) -> TaxEntity {
+        let mut filed = entity.clone();
+        if method == DeductionMethod::Standard {
+            filed.deductions.clear();
+            filed.add_deduction(standard, DeductionType::Personal);
+        }
+        filed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TaxEntityType;
+    use crate::testing::flat_schedule;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_independent_choice_beats_coupled() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        entity.add_deduction(dec!(20000), DeductionType::Charitable);
+
+        let federal = flat_schedule(2024, dec!(0.10));
+        let state = flat_schedule(2024, dec!(0.05));
+
+        // Federal standard (25,000) beats itemizing, state itemizing beats its standard (5,000)
+        let independent =
+            DeductionOptimizer::optimize(&entity, &federal, &state, dec!(25000), dec!(5000), false)
+                .unwrap();
+        assert_eq!(independent.federal_method, DeductionMethod::Standard);
+        assert_eq!(independent.state_method, DeductionMethod::Itemized);
+        assert_eq!(independent.total_tax(), dec!(11500));
+
+        let coupled =
+            DeductionOptimizer::optimize(&entity, &federal, &state, dec!(25000), dec!(5000), true)
+                .unwrap();
+        assert_eq!(coupled.federal_method, DeductionMethod::Itemized);
+        assert_eq!(coupled.state_method, DeductionMethod::Itemized);
+        assert_eq!(coupled.total_tax(), dec!(12000));
+    }
+}
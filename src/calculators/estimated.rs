@@ -0,0 +1,208 @@
+//! Quarterly estimated tax payments.
+//!
+//! Taxpayers without enough withholding, such as the self-employed, pay
+//! estimated tax in four installments. The underpayment penalty is avoided by
+//! paying a safe-harbor amount: 90% of the current year's tax, or 100% of the
+//! prior year's tax (110% when prior-year AGI was high).
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::models::{FilingStatus, TaxSchedule};
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+
+/// Share of the current year's tax that satisfies the safe harbor.
+pub const CURRENT_YEAR_SAFE_HARBOR_RATE: Decimal = dec!(0.90);
+
+/// Prior-year AGI above which the prior-year safe harbor rises to 110%.
+pub const HIGH_INCOME_AGI_THRESHOLD: Decimal = dec!(150000);
+
+/// The high-income AGI threshold for married couples filing separately.
+const HIGH_INCOME_AGI_THRESHOLD_SEPARATE: Decimal = dec!(75000);
+
+/// Number of estimated tax installments in a year.
+const QUARTERS: Decimal = Decimal::from_parts(4, 0, 0, false, 0);
+
+/// Which safe-harbor rule set the estimated tax target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafeHarbor {
+    /// 90% of the projected current-year tax
+    CurrentYear,
+    /// 100% or 110% of the prior year's tax
+    PriorYear,
+}
+
+/// The four quarterly payments that meet a safe-harbor target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimatedTaxPlan {
+    /// The total to pay across the year
+    pub target: Decimal,
+    /// The safe harbor the target is based on
+    pub safe_harbor: SafeHarbor,
+    /// The payment due each quarter, rounded to cents
+    pub installments: [Decimal; 4],
+}
+
+/// Calculator for quarterly estimated tax payments.
+pub struct EstimatedTaxCalculator;
+
+impl EstimatedTaxCalculator {
+    /// Plans the quarterly payments that meet the smaller safe-harbor target.
+    ///
+    /// The current-year target is 90% of the tax on `projected_income` under
+    /// `schedule`. The prior-year target is `prior_year_tax`, raised to 110%
+    /// when `prior_year_agi` exceeds [`HIGH_INCOME_AGI_THRESHOLD`] (half that
+    /// for married filing separately). The prior-year target is used when
+    /// the two are equal, since it doesn't depend on a projection.
+    ///
+    /// The target is rounded to cents and split into four equal installments,
+    /// with the last absorbing any rounding so they sum to the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `projected_income` - Projected taxable income for the current year
+    /// * `schedule` - The current year's tax schedule
+    /// * `prior_year_tax` - Total tax for the prior year
+    /// * `prior_year_agi` - Adjusted gross income for the prior year
+    ///
+    /// # Returns
+    ///
+    /// The target, the safe harbor it came from, and the four installments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::{EstimatedTaxCalculator, SafeHarbor};
+    /// use tax_engine::{TaxBracket, TaxSchedule};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::new(
+    ///     2024,
+    ///     vec![TaxBracket {
+    ///         lower_bound: dec!(0),
+    ///         upper_bound: None,
+    ///         rate: dec!(0.20),
+    ///     }],
+    /// );
+    ///
+    /// // Last year's $17,000 of tax is below 90% of this year's $20,000
+    /// let plan = EstimatedTaxCalculator::quarterly_installments(
+    ///     dec!(100000),
+    ///     &schedule,
+    ///     dec!(17000),
+    ///     dec!(90000),
+    /// );
+    /// assert_eq!(plan.safe_harbor, SafeHarbor::PriorYear);
+    /// assert_eq!(plan.installments, [dec!(4250); 4]);
+    /// ```
+    pub fn quarterly_installments(
+        projected_income: Decimal,
+        schedule: &TaxSchedule,
+        prior_year_tax: Decimal,
+        prior_year_agi: Decimal,
+    ) -> EstimatedTaxPlan {
+        let current_year_target =
+            CURRENT_YEAR_SAFE_HARBOR_RATE * IncomeTaxCalculator::tax_on(projected_income, schedule);
+        let prior_year_target = prior_year_tax
+            * Self::prior_year_safe_harbor_rate(prior_year_agi, schedule.filing_status);
+
+        let (target, safe_harbor) = if prior_year_target <= current_year_target {
+            (prior_year_target, SafeHarbor::PriorYear)
+        } else {
+            (current_year_target, SafeHarbor::CurrentYear)
+        };
+        let target = target
+            .max(Decimal::ZERO)
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+
+        let quarterly = (target / QUARTERS).round_dp_with_strategy(2, RoundingStrategy::ToZero);
+        let last = target - quarterly * dec!(3);
+
+        EstimatedTaxPlan {
+            target,
+            safe_harbor,
+            installments: [quarterly, quarterly, quarterly, last],
+        }
+    }
+
+    /// The share of prior-year tax needed for the prior-year safe harbor.
+    fn prior_year_safe_harbor_rate(
+        prior_year_agi: Decimal,
+        filing_status: Option<FilingStatus>,
+    ) -> Decimal {
+        let threshold = match filing_status {
+            Some(FilingStatus::MarriedFilingSeparately) => HIGH_INCOME_AGI_THRESHOLD_SEPARATE,
+            _ => HIGH_INCOME_AGI_THRESHOLD,
+        };
+        if prior_year_agi > threshold {
+            dec!(1.10)
+        } else {
+            Decimal::ONE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::flat_schedule;
+
+    #[test]
+    fn test_installments_sum_to_current_year_target() {
+        // 90% of 20% of 100,000.05 is 18,000.009, which rounds to 18,000.01
+        let plan = EstimatedTaxCalculator::quarterly_installments(
+            dec!(100000.05),
+            &flat_schedule(2024, dec!(0.20)),
+            dec!(25000),
+            dec!(120000),
+        );
+
+        assert_eq!(plan.safe_harbor, SafeHarbor::CurrentYear);
+        assert_eq!(plan.target, dec!(18000.01));
+        assert_eq!(
+            plan.installments,
+            [dec!(4500), dec!(4500), dec!(4500), dec!(4500.01)]
+        );
+        assert_eq!(plan.installments.iter().sum::<Decimal>(), plan.target);
+    }
+
+    #[test]
+    fn test_prior_year_chosen_when_lower() {
+        let schedule = flat_schedule(2024, dec!(0.20));
+
+        // 17,000 of prior-year tax is below 90% of 20,000
+        let plan = EstimatedTaxCalculator::quarterly_installments(
+            dec!(100000),
+            &schedule,
+            dec!(17000),
+            dec!(120000),
+        );
+        assert_eq!(plan.safe_harbor, SafeHarbor::PriorYear);
+        assert_eq!(plan.target, dec!(17000));
+        assert_eq!(plan.installments.iter().sum::<Decimal>(), plan.target);
+
+        // With high prior-year AGI the same tax needs 110%, which is 18,700
+        let plan = EstimatedTaxCalculator::quarterly_installments(
+            dec!(100000),
+            &schedule,
+            dec!(17000),
+            dec!(200000),
+        );
+        assert_eq!(plan.safe_harbor, SafeHarbor::CurrentYear);
+        assert_eq!(plan.target, dec!(18000));
+    }
+
+    #[test]
+    fn test_separate_filers_use_lower_agi_threshold() {
+        let mut schedule = flat_schedule(2024, dec!(0.20));
+        schedule.filing_status = Some(FilingStatus::MarriedFilingSeparately);
+
+        let plan = EstimatedTaxCalculator::quarterly_installments(
+            dec!(100000),
+            &schedule,
+            dec!(16000),
+            dec!(80000),
+        );
+        assert_eq!(plan.safe_harbor, SafeHarbor::PriorYear);
+        assert_eq!(plan.target, dec!(17600));
+    }
+}
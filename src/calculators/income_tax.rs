@@ -4,8 +4,86 @@
 //! based on tax brackets and entity information.
 
 use crate::errors::TaxError;
-use crate::models::{TaxEntity, TaxSchedule};
-use rust_decimal::Decimal;
+use crate::models::{DeductionBasis, DeductionType, TaxEntity, TaxSchedule};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Taxable income below which the IRS requires the tax-table method.
+pub const TAX_TABLE_THRESHOLD: Decimal = Decimal::from_parts(100_000, 0, 0, false, 0);
+
+/// Width of each income row in the IRS tax tables.
+const TAX_TABLE_ROW_WIDTH: Decimal = Decimal::from_parts(50, 0, 0, false, 0);
+
+/// The tax contributed by a single bracket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketTax {
+    /// The bracket's lower income bound
+    pub lower_bound: Decimal,
+    /// The bracket's upper income bound (None represents no upper limit)
+    pub upper_bound: Option<Decimal>,
+    /// The bracket's tax rate as a decimal
+    pub rate: Decimal,
+    /// The portion of taxable income taxed in this bracket
+    pub taxed_income: Decimal,
+    /// The tax owed on that portion
+    pub tax: Decimal,
+}
+
+/// When tax is rounded to the nearest dollar.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Bracket contributions are kept exact and only the total is rounded
+    #[default]
+    Total,
+    /// Each bracket's contribution is rounded before the contributions are summed
+    PerBracket,
+}
+
+/// Tax owed on the same entity in its own tax year and in the following year.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearComparison {
+    /// The entity's own tax year
+    pub current_year: u16,
+    /// Tax owed in the entity's own tax year
+    pub current_tax: Decimal,
+    /// Tax owed if the same income and deductions fell in the following year
+    pub next_year_tax: Decimal,
+}
+
+impl YearComparison {
+    /// Returns the year in which less tax is owed, preferring the current year on a tie.
+    pub fn lower_year(&self) -> u16 {
+        if self.next_year_tax < self.current_tax {
+            self.current_year + 1
+        } else {
+            self.current_year
+        }
+    }
+
+    /// Returns how much less tax is owed by shifting to the following year.
+    ///
+    /// Negative when shifting would increase tax.
+    pub fn savings_from_shifting(&self) -> Decimal {
+        self.current_tax - self.next_year_tax
+    }
+}
+
+/// Tax owed for a year compared with the amount already paid toward it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reconciliation {
+    /// Tax owed after credits (negative when refundable credits exceed it)
+    pub tax_owed: Decimal,
+    /// Tax withheld plus estimated payments
+    pub total_paid: Decimal,
+    /// Amount still due, or the refund due as a negative amount
+    pub balance: Decimal,
+}
+
+impl Reconciliation {
+    /// Returns whether more was paid than owed.
+    pub fn is_refund(&self) -> bool {
+        self.balance < Decimal::ZERO
+    }
+}
 
 /// Calculator for determining income tax based on progressive tax brackets.
 pub struct IncomeTaxCalculator;
@@ -20,12 +98,19 @@ impl IncomeTaxCalculator {
     ///
     /// # Returns
     ///
-    /// The calculated tax amount or an error if calculation fails.
+    /// The calculated tax amount or an error if calculation fails. Brackets with
+    /// negative rates reduce the total, so the result may be negative (a net credit);
+    /// such schedules must opt in with
+    /// [`TaxSchedule::with_negative_rates_allowed`] to pass validation.
+    /// Income below the first bracket's lower bound is untaxed, as is income
+    /// falling in a gap between brackets. Zero or negative taxable income, as
+    /// when deductions exceed income, always yields zero tax, never a negative
+    /// amount.
     ///
     /// # Errors
     ///
-    /// Returns `TaxError::YearMismatch` if the entity's tax year doesn't match
-    /// the schedule's tax year.
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
     ///
     /// # Examples
     ///
@@ -39,31 +124,1911 @@ impl IncomeTaxCalculator {
     /// let tax = IncomeTaxCalculator::calculate_tax(&entity, &schedule);
     /// ```
     pub fn calculate_tax(entity: &TaxEntity, schedule: &TaxSchedule) -> Result<Decimal, TaxError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "calculate_tax",
+            tax_year = entity.tax_year,
+            bracket_count = schedule.brackets.len(),
+            duration_us = tracing::field::Empty,
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        Self::check_compatibility(entity, schedule)?;
+
+        // Deductions exceeding income leave nothing to tax, whatever the brackets
+        let taxable_income = entity.taxable_income();
+        let total_tax = if taxable_income <= Decimal::ZERO {
+            Decimal::ZERO
+        } else {
+            Self::tax_on(taxable_income, schedule)
+        };
+
+        #[cfg(feature = "tracing")]
+        span.record("duration_us", started.elapsed().as_micros() as u64);
+
+        Ok(total_tax)
+    }
+
+    /// Calculates the tax for each of many entities under the same schedule.
+    ///
+    /// Each entity is checked and calculated independently, as by
+    /// [`IncomeTaxCalculator::calculate_tax`], so one entity's error does not
+    /// affect the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `entities` - The tax entities whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// One result per entity, in the same order as `entities`.
+    pub fn calculate_tax_batch(
+        entities: &[TaxEntity],
+        schedule: &TaxSchedule,
+    ) -> Vec<Result<Decimal, TaxError>> {
+        entities
+            .iter()
+            .map(|entity| Self::calculate_tax(entity, schedule))
+            .collect()
+    }
+
+    /// Calculates the tax for each of many entities, splitting the work
+    /// across threads.
+    ///
+    /// Produces the same results as
+    /// [`IncomeTaxCalculator::calculate_tax_batch`], in the same order. Only
+    /// available with the `rayon` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `entities` - The tax entities whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// One result per entity, in the same order as `entities`.
+    #[cfg(feature = "rayon")]
+    pub fn calculate_tax_batch_parallel(
+        entities: &[TaxEntity],
+        schedule: &TaxSchedule,
+    ) -> Vec<Result<Decimal, TaxError>> {
+        use rayon::prelude::*;
+
+        entities
+            .par_iter()
+            .map(|entity| Self::calculate_tax(entity, schedule))
+            .collect()
+    }
+
+    /// Calculates an entity's total tax under several stacked schedules.
+    ///
+    /// Each schedule is applied to the same taxable income, as by
+    /// [`IncomeTaxCalculator::calculate_tax`], and the results are summed.
+    /// This suits jurisdictions that tax the same income independently, such
+    /// as US federal, state and local income taxes.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedules` - The schedules of every jurisdiction taxing the entity
+    ///
+    /// # Returns
+    ///
+    /// The sum of the tax under each schedule, or zero if `schedules` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` for the first schedule, in order, that
+    /// is not for the entity's tax year or is otherwise incompatible with the
+    /// entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::{IncomeTaxCalculator, TaxEntity, TaxEntityType, TaxSchedule};
+    /// # use rust_decimal_macros::dec;
+    ///
+    /// let entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+    /// # let federal = TaxSchedule::new(2024, vec![]);
+    /// # let state = TaxSchedule::new(2024, vec![]);
+    /// let total = IncomeTaxCalculator::calculate_combined(&entity, &[&federal, &state]);
+    /// ```
+    pub fn calculate_combined(
+        entity: &TaxEntity,
+        schedules: &[&TaxSchedule],
+    ) -> Result<Decimal, TaxError> {
+        schedules
+            .iter()
+            .map(|schedule| Self::calculate_tax(entity, schedule))
+            .sum()
+    }
+
+    /// Checks that an entity can be taxed under a schedule.
+    ///
+    /// Every calculation runs this check before applying the schedule, so a
+    /// mismatch is reported the same way everywhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity to check
+    /// * `schedule` - The tax schedule to check it against
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` naming the attribute if the tax years
+    /// differ, the schedule is for a different filing status, or the
+    /// currencies differ. A schedule without a filing status is compatible
+    /// with every status.
+    pub fn check_compatibility(entity: &TaxEntity, schedule: &TaxSchedule) -> Result<(), TaxError> {
         if entity.tax_year != schedule.tax_year {
-            return Err(TaxError::YearMismatch);
+            return Err(TaxError::Incompatible {
+                reason: format!(
+                    "tax year mismatch: entity {}, schedule {}",
+                    entity.tax_year, schedule.tax_year
+                ),
+            });
+        }
+        if let Some(filing_status) = schedule.filing_status {
+            if entity.filing_status != filing_status {
+                return Err(TaxError::Incompatible {
+                    reason: format!(
+                        "filing status mismatch: entity files as {:?}, schedule is for {:?}",
+                        entity.filing_status, filing_status
+                    ),
+                });
+            }
+        }
+        if entity.currency != schedule.currency {
+            return Err(TaxError::Incompatible {
+                reason: format!(
+                    "currency mismatch: entity uses {}, schedule uses {}",
+                    entity.currency, schedule.currency
+                ),
+            });
         }
 
-        let taxable_income = entity.taxable_income();
-        let mut total_tax = Decimal::ZERO;
-        let mut remaining_income = taxable_income;
+        Ok(())
+    }
+
+    /// Calculates the marginal tax rate for an entity.
+    ///
+    /// This is the rate of the bracket whose bounds contain the entity's
+    /// taxable income, as located by [`TaxSchedule::bracket_for`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose rate should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The marginal rate as a decimal (e.g., 0.25 for 25%), or zero if no
+    /// bracket contains the taxable income.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_marginal_rate(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        Ok(schedule
+            .bracket_for(entity.taxable_income())
+            .map_or(Decimal::ZERO, |bracket| bracket.rate))
+    }
 
+    /// Calculates the marginal rate before and after an entity's deductions.
+    ///
+    /// Comparing the two shows whether deductions moved the entity into a
+    /// lower bracket. Both rates are located as in
+    /// [`IncomeTaxCalculator::calculate_marginal_rate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose rates should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// A `(without_deductions, with_deductions)` pair of marginal rates, where
+    /// the first is the rate at the entity's gross recognized income and the
+    /// second the rate at its taxable income.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn marginal_rate_change_from_deductions(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<(Decimal, Decimal), TaxError> {
+        let with_deductions = Self::calculate_marginal_rate(entity, schedule)?;
+        let without_deductions = schedule
+            .bracket_for(entity.recognized_income())
+            .map_or(Decimal::ZERO, |bracket| bracket.rate);
+
+        Ok((without_deductions, with_deductions))
+    }
+
+    /// Finds the gross incomes at which an entity's marginal rate changes.
+    ///
+    /// Bracket thresholds apply to taxable income, so the entity's deductions
+    /// shift each threshold upward in gross terms: fixed and per-unit
+    /// deductions add their amount, and percentage-of-income deductions scale
+    /// the threshold by `1 / (1 - total rate)`. Other gross income, such as
+    /// recognized deferred income, is assumed unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose deductions shift the thresholds
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// `(gross_income, new_marginal_rate)` pairs in ascending order, where
+    /// each rate applies to gross income above the paired amount. The first
+    /// pair is where taxable income becomes positive, and a gap between
+    /// brackets appears as a change to a zero rate. Empty when
+    /// percentage-of-income deductions total 100% or more, since taxable
+    /// income then never rises.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn rate_transition_incomes(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Vec<(Decimal, Decimal)>, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        let (fixed, percentage) = entity.deductions.iter().fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(fixed, percentage), deduction| match deduction.basis {
+                DeductionBasis::PercentageOfIncome(rate) => (fixed, percentage + rate),
+                _ => (fixed + deduction.amount_for(Decimal::ZERO), percentage),
+            },
+        );
+        if percentage >= Decimal::ONE {
+            return Ok(Vec::new());
+        }
+        let deferred = entity.recognized_income() - entity.income;
+        let gross_at =
+            |taxable: Decimal| (taxable + fixed) / (Decimal::ONE - percentage) - deferred;
+
+        let mut transitions = Vec::new();
+        let mut rate = Decimal::ZERO;
+        let mut covered_to = Some(Decimal::ZERO);
         for bracket in &schedule.brackets {
-            let bracket_income = match bracket.upper_bound {
-                Some(upper) => {
-                    if remaining_income <= Decimal::ZERO {
-                        break;
-                    }
-                    remaining_income.min(upper - bracket.lower_bound)
+            let start = schedule.taxed_above(bracket).max(Decimal::ZERO);
+            match covered_to {
+                // Past an open-ended bracket, later brackets never apply
+                None => break,
+                Some(end) if start > end && rate != Decimal::ZERO => {
+                    rate = Decimal::ZERO;
+                    transitions.push((gross_at(end), rate));
                 }
-                None => remaining_income,
-            };
+                Some(_) => {}
+            }
+            if bracket.rate != rate {
+                rate = bracket.rate;
+                transitions.push((gross_at(start), rate));
+            }
+            covered_to = bracket.upper_bound;
+        }
+
+        Ok(transitions)
+    }
+
+    /// Calculates the effective tax rate on taxable income.
+    ///
+    /// This is total tax divided by taxable income. For the rate on gross
+    /// income before deductions, see [`IncomeTaxCalculator::effective_rate_on_gross`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose rate should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The effective rate as a decimal (e.g., 0.15 for 15%), or zero when
+    /// taxable income is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_effective_rate(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        let tax = Self::calculate_tax(entity, schedule)?;
+        let taxable_income = entity.taxable_income();
+        if taxable_income <= Decimal::ZERO {
+            return Ok(Decimal::ZERO);
+        }
+
+        Ok(tax / taxable_income)
+    }
+
+    /// Calculates the flat rate that would raise the same tax as the schedule.
+    ///
+    /// Applying the returned rate to the entity's whole taxable income yields
+    /// the progressive tax (to within Decimal's 28 significant digits when the
+    /// rate does not terminate), which makes it a convenient way to say "your
+    /// tax is equivalent to a flat X%". Numerically it is the effective rate
+    /// (see [`IncomeTaxCalculator::calculate_effective_rate`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose rate should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The equivalent flat rate, or zero when taxable income is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn equivalent_flat_rate(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        Self::calculate_effective_rate(entity, schedule)
+    }
+
+    /// Calculates the flat rate equivalent to a schedule at a given taxable income.
+    ///
+    /// The schedule-level counterpart of
+    /// [`IncomeTaxCalculator::equivalent_flat_rate`], for comparing schedules
+    /// without constructing an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `taxable_income` - The taxable income to evaluate the schedule at
+    ///
+    /// # Returns
+    ///
+    /// The equivalent flat rate, or zero when `taxable_income` is not positive.
+    pub fn schedule_flat_rate(schedule: &TaxSchedule, taxable_income: Decimal) -> Decimal {
+        if taxable_income <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let total_tax = Self::tax_on(taxable_income, schedule);
+
+        total_tax / taxable_income
+    }
+
+    /// Calculates the effective tax rate on gross income.
+    ///
+    /// This is the statutory view of the tax burden: total tax divided by the
+    /// entity's gross recognized income, before deductions. Because deductions
+    /// shrink the tax without shrinking the denominator, the result is lower
+    /// than [`IncomeTaxCalculator::calculate_effective_rate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The tax as a fraction of gross income (e.g., 0.15 for 15%), or zero
+    /// when gross income is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn effective_rate_on_gross(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        let tax = Self::calculate_tax(entity, schedule)?;
+        let gross_income = entity.recognized_income();
+        if gross_income <= Decimal::ZERO {
+            return Ok(Decimal::ZERO);
+        }
+
+        Ok(tax / gross_income)
+    }
+
+    /// Calculates the taxable yield that matches a tax-exempt yield after tax.
+    ///
+    /// Used to compare tax-exempt investments, such as municipal bonds, with
+    /// taxable ones: a taxable investment must yield more than the result to
+    /// beat the tax-exempt one.
+    ///
+    /// # Arguments
+    ///
+    /// * `tax_exempt_yield` - The tax-exempt yield as a decimal (e.g., 0.03 for 3%)
+    /// * `marginal_rate` - The investor's marginal rate as a decimal
+    ///
+    /// # Returns
+    ///
+    /// `tax_exempt_yield / (1 - marginal_rate)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `marginal_rate` is exactly 1, since no taxable yield keeps
+    /// anything after tax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::IncomeTaxCalculator;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let taxable = IncomeTaxCalculator::tax_equivalent_yield(dec!(0.03), dec!(0.24));
+    /// assert_eq!(taxable.round_dp(4), dec!(0.0395));
+    /// ```
+    pub fn tax_equivalent_yield(tax_exempt_yield: Decimal, marginal_rate: Decimal) -> Decimal {
+        tax_exempt_yield / (Decimal::ONE - marginal_rate)
+    }
+
+    /// Calculates the tax-equivalent yield at an entity's marginal rate.
+    ///
+    /// The marginal rate is found with
+    /// [`IncomeTaxCalculator::calculate_marginal_rate`] and applied as in
+    /// [`IncomeTaxCalculator::tax_equivalent_yield`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The investor whose marginal rate applies
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `tax_exempt_yield` - The tax-exempt yield as a decimal (e.g., 0.03 for 3%)
+    ///
+    /// # Returns
+    ///
+    /// The taxable yield with the same after-tax return.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's, or `TaxError::InvalidBrackets`
+    /// if the marginal rate is 100% or more.
+    pub fn tax_equivalent_yield_for(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        tax_exempt_yield: Decimal,
+    ) -> Result<Decimal, TaxError> {
+        let marginal_rate = Self::calculate_marginal_rate(entity, schedule)?;
+        if marginal_rate >= Decimal::ONE {
+            return Err(TaxError::InvalidBrackets(format!(
+                "marginal rate {} leaves no after-tax yield",
+                marginal_rate
+            )));
+        }
+
+        Ok(Self::tax_equivalent_yield(tax_exempt_yield, marginal_rate))
+    }
+
+    /// Calculates how much of the entity's tax comes from each bracket.
+    ///
+    /// Brackets the taxable income doesn't reach contribute nothing and are
+    /// excluded, so every entry has a positive `taxed_income`. Entries are in
+    /// ascending bracket order. With `RoundingMode::Total` each `tax` is exact
+    /// and the entries sum to [`IncomeTaxCalculator::calculate_tax`]; with
+    /// `RoundingMode::PerBracket` each `tax` is rounded to the nearest dollar.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `rounding` - Whether each bracket's tax is rounded
+    ///
+    /// # Returns
+    ///
+    /// One entry per bracket that taxes part of the entity's income.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_tax_breakdown(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        rounding: RoundingMode,
+    ) -> Result<Vec<BracketTax>, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
 
-            if bracket_income > Decimal::ZERO {
-                total_tax += bracket_income * bracket.rate;
-                remaining_income -= bracket_income;
+        let mut breakdown = Self::breakdown(entity.taxable_income(), schedule);
+        if rounding == RoundingMode::PerBracket {
+            for bracket_tax in &mut breakdown {
+                bracket_tax.tax = round_to_dollar(bracket_tax.tax);
             }
         }
 
-        Ok(total_tax)
+        Ok(breakdown)
+    }
+
+    /// Calculates the total tax rounded to the nearest dollar.
+    ///
+    /// Jurisdictions that round at each bracket can owe a few dollars more or
+    /// less than rounding the exact total would give; `rounding` selects which
+    /// convention applies. Halves are rounded away from zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `rounding` - Whether to round each bracket's tax or only the total
+    ///
+    /// # Returns
+    ///
+    /// The tax owed in whole dollars.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_rounded_tax(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        rounding: RoundingMode,
+    ) -> Result<Decimal, TaxError> {
+        let total_tax = Self::calculate_tax_breakdown(entity, schedule, rounding)?
+            .iter()
+            .fold(Decimal::ZERO, |acc, bracket_tax| acc + bracket_tax.tax);
+
+        Ok(round_to_dollar(total_tax))
+    }
+
+    /// Calculates the tax owed after applying the entity's credits.
+    ///
+    /// Non-refundable credits are subtracted first and can only reduce tax to
+    /// zero. Refundable credits are then subtracted in full, so the result
+    /// is negative when they exceed the remaining tax (a refund).
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The tax owed after credits, or the refund due as a negative amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_tax_after_credits(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        let tax = Self::calculate_tax(entity, schedule)?;
+
+        let (refundable, non_refundable) = entity.credits.iter().fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(refundable, non_refundable), credit| {
+                if credit.refundable {
+                    (refundable + credit.amount, non_refundable)
+                } else {
+                    (refundable, non_refundable + credit.amount)
+                }
+            },
+        );
+
+        Ok((tax - non_refundable).max(Decimal::ZERO) - refundable)
+    }
+
+    /// Compares the tax owed with what has already been paid toward it.
+    ///
+    /// Tax owed is as calculated by
+    /// [`IncomeTaxCalculator::calculate_tax_after_credits`]. Payments are the
+    /// tax withheld plus the entity's estimated payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `withheld` - Tax withheld from wages and other payments during the year
+    ///
+    /// # Returns
+    ///
+    /// The tax owed, total paid and balance. A positive balance is owed; a
+    /// negative balance is refunded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::{IncomeTaxCalculator, TaxEntity, TaxEntityType, TaxSchedule};
+    /// # use rust_decimal_macros::dec;
+    ///
+    /// let entity = TaxEntity::new(TaxEntityType::Individual, dec!(75000), 2024);
+    /// # let schedule = TaxSchedule::new(2024, vec![]);
+    /// let reconciliation = IncomeTaxCalculator::reconcile(&entity, &schedule, dec!(9000))?;
+    /// if reconciliation.is_refund() {
+    ///     println!("Refund due: {}", -reconciliation.balance);
+    /// }
+    /// # Ok::<(), tax_engine::TaxError>(())
+    /// ```
+    pub fn reconcile(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        withheld: Decimal,
+    ) -> Result<Reconciliation, TaxError> {
+        let tax_owed = Self::calculate_tax_after_credits(entity, schedule)?;
+        let total_paid = withheld + entity.total_estimated_payments();
+
+        Ok(Reconciliation {
+            tax_owed,
+            total_paid,
+            balance: tax_owed - total_paid,
+        })
+    }
+
+    /// Checks the computed tax against an expected figure, such as one from an
+    /// IRS worksheet or example.
+    ///
+    /// The tax is rounded to the nearest dollar as by
+    /// [`IncomeTaxCalculator::calculate_rounded_tax`] with `RoundingMode::Total`,
+    /// matching the whole-dollar amounts published on tax forms, before it is
+    /// compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `expected` - The expected tax
+    /// * `tolerance` - The largest acceptable difference in either direction
+    ///
+    /// # Returns
+    ///
+    /// Whether the rounded tax is within `tolerance` of `expected`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn verify(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        expected: Decimal,
+        tolerance: Decimal,
+    ) -> Result<bool, TaxError> {
+        let tax = Self::calculate_rounded_tax(entity, schedule, RoundingMode::Total)?;
+
+        Ok((tax - expected).abs() <= tolerance.abs())
+    }
+
+    /// Calculates tax after subtracting a per-dependent exemption from taxable income.
+    ///
+    /// The exemption total (`dependents * exemption_per_dependent`) is removed
+    /// before any bracket is applied, and the reduced income is floored at zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `exemption_per_dependent` - Exemption amount granted for each dependent
+    ///
+    /// # Returns
+    ///
+    /// The calculated tax amount or an error if calculation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_tax_with_exemptions(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        exemption_per_dependent: Decimal,
+    ) -> Result<Decimal, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        let exemptions = Decimal::from(entity.dependents) * exemption_per_dependent;
+        let taxable_income = (entity.taxable_income() - exemptions).max(Decimal::ZERO);
+
+        let total_tax = Self::tax_on(taxable_income, schedule);
+
+        Ok(total_tax)
+    }
+
+    /// Calculates tax the way the IRS tax tables do.
+    ///
+    /// For taxable income below [`TAX_TABLE_THRESHOLD`], income is replaced by
+    /// the midpoint of its $50 table row (e.g. $50,000 to $50,050 uses $50,025)
+    /// and the resulting tax is rounded to whole dollars, matching the printed
+    /// tables. Income at or above the threshold uses the exact formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The tax-table amount or an error if calculation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_tax_table_method(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        Self::calculate_tax_table_method_with_threshold(entity, schedule, TAX_TABLE_THRESHOLD)
+    }
+
+    /// Calculates tax using the tax-table method below a custom income threshold.
+    ///
+    /// Behaves like [`IncomeTaxCalculator::calculate_tax_table_method`] but
+    /// lets callers choose where the tables stop applying.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `threshold` - Taxable income below which the table method is used
+    ///
+    /// # Returns
+    ///
+    /// The calculated tax amount or an error if calculation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_tax_table_method_with_threshold(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        threshold: Decimal,
+    ) -> Result<Decimal, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        let taxable_income = entity.taxable_income();
+        if taxable_income <= Decimal::ZERO || taxable_income >= threshold {
+            return Self::calculate_tax(entity, schedule);
+        }
+
+        let row_start = (taxable_income / TAX_TABLE_ROW_WIDTH).floor() * TAX_TABLE_ROW_WIDTH;
+        let midpoint = row_start + TAX_TABLE_ROW_WIDTH / Decimal::TWO;
+
+        let total_tax = Self::tax_on(midpoint, schedule);
+
+        Ok(round_to_dollar(total_tax))
+    }
+
+    /// Compares the tax owed in the entity's tax year with the following year.
+    ///
+    /// The entity is recalculated under `next_schedule` with the same
+    /// recognized income (see [`TaxEntity::for_year`]) to show whether moving
+    /// its income into the next year would lower the tax owed.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule for the entity's tax year
+    /// * `next_schedule` - The tax schedule for the following year
+    ///
+    /// # Returns
+    ///
+    /// The tax owed in both years.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if `schedule` is not for the entity's
+    /// tax year, `next_schedule` is not for the year after it or there is no
+    /// following year, or if either schedule's filing status or currency
+    /// differs from the entity's.
+    pub fn compare_with_next_year(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        next_schedule: &TaxSchedule,
+    ) -> Result<YearComparison, TaxError> {
+        let current_tax = Self::calculate_tax(entity, schedule)?;
+        let next_year_tax = Self::calculate_tax(&entity.for_next_year()?, next_schedule)?;
+
+        Ok(YearComparison {
+            current_year: entity.tax_year,
+            current_tax,
+            next_year_tax,
+        })
+    }
+
+    /// Calculates the tax owed if deductions in a category are removed or reduced.
+    ///
+    /// Up to `amount` of the entity's deductions in `category` is disallowed,
+    /// so passing less than the category total models a partial removal.
+    /// Amounts above the category total are capped at that total.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `category` - The category of deduction being removed
+    /// * `amount` - How much of the category's deductions to remove
+    ///
+    /// # Returns
+    ///
+    /// The total tax owed after the deduction is removed or reduced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn tax_if_deduction_removed(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        category: DeductionType,
+        amount: Decimal,
+    ) -> Result<Decimal, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        let recognized_income = entity.recognized_income();
+        let category_total = entity
+            .deductions
+            .iter()
+            .filter(|d| d.category == category)
+            .fold(Decimal::ZERO, |acc, d| {
+                acc + d.amount_for(recognized_income)
+            });
+        let removed = amount.max(Decimal::ZERO).min(category_total);
+
+        let total_tax = Self::tax_on(entity.taxable_income() + removed, schedule);
+
+        Ok(total_tax)
+    }
+
+    /// Calculates tax where lower-bracket benefits are recaptured above a threshold.
+    ///
+    /// Below or at `recapture_threshold` tax is progressive as usual. Once
+    /// taxable income exceeds the threshold, the rate of the highest bracket the
+    /// income reaches is applied to the entire taxable income, so the lower
+    /// rates on earlier dollars no longer apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    /// * `recapture_threshold` - Taxable income above which recapture applies
+    ///
+    /// # Returns
+    ///
+    /// The calculated tax amount or an error if calculation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn calculate_tax_with_recapture(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+        recapture_threshold: Decimal,
+    ) -> Result<Decimal, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        let taxable_income = entity.taxable_income();
+        if taxable_income <= recapture_threshold {
+            return Self::calculate_tax(entity, schedule);
+        }
+
+        let top_rate = schedule
+            .top_bracket_reached(taxable_income)
+            .map_or(Decimal::ZERO, |bracket| bracket.rate);
+
+        Ok(taxable_income * top_rate)
+    }
+
+    /// Calculates the tax owed within the highest bracket the entity's income reaches.
+    ///
+    /// This isolates the contribution of the top slice of income, which is useful
+    /// for showing how much of the total tax comes from the marginal bracket.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose tax should be calculated
+    /// * `schedule` - The tax schedule containing applicable tax brackets
+    ///
+    /// # Returns
+    ///
+    /// The tax owed in the top bracket reached, or zero if there is no taxable income.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year, filing status
+    /// or currency doesn't match the schedule's.
+    pub fn tax_in_top_bracket(
+        entity: &TaxEntity,
+        schedule: &TaxSchedule,
+    ) -> Result<Decimal, TaxError> {
+        Self::check_compatibility(entity, schedule)?;
+
+        let top_tax = Self::breakdown(entity.taxable_income(), schedule)
+            .last()
+            .map_or(Decimal::ZERO, |bracket_tax| bracket_tax.tax);
+
+        Ok(top_tax)
+    }
+
+    /// Sums the tax owed on a taxable income across all brackets.
+    pub(crate) fn tax_on(taxable_income: Decimal, schedule: &TaxSchedule) -> Decimal {
+        Self::breakdown(taxable_income, schedule)
+            .iter()
+            .fold(Decimal::ZERO, |acc, bracket_tax| acc + bracket_tax.tax)
+    }
+
+    /// Splits taxable income across the schedule's brackets.
+    ///
+    /// Each bracket taxes the income between the point its rate starts to
+    /// apply (see [`TaxSchedule::taxed_above`]) and its upper bound. Returns
+    /// an entry for each bracket that receives a positive portion of income,
+    /// in ascending order.
+    ///
+    /// Income not covered by any bracket is deliberately left out: when the
+    /// first bracket starts above zero, the income below it is treated as a
+    /// tax-free threshold rather than taxed at the first bracket's rate.
+    fn breakdown(taxable_income: Decimal, schedule: &TaxSchedule) -> Vec<BracketTax> {
+        schedule
+            .brackets
+            .iter()
+            .filter_map(|bracket| {
+                let start = schedule.taxed_above(bracket);
+                let end = match bracket.upper_bound {
+                    Some(upper) => taxable_income.min(upper),
+                    None => taxable_income,
+                };
+                let taxed_income = end - start;
+
+                (taxed_income > Decimal::ZERO).then(|| BracketTax {
+                    lower_bound: bracket.lower_bound,
+                    upper_bound: bracket.upper_bound,
+                    rate: bracket.rate,
+                    taxed_income,
+                    tax: taxed_income * bracket.rate,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Rounds an amount to the nearest dollar, rounding halves away from zero.
+fn round_to_dollar(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        BoundInclusivity, CreditType, Currency, FilingStatus, TaxBracket, TaxEntityType,
+    };
+    use rust_decimal_macros::dec;
+
+    fn two_bracket_schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.15),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.25),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_tax_in_top_bracket() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        let schedule = two_bracket_schedule();
+
+        let top_tax = IncomeTaxCalculator::tax_in_top_bracket(&entity, &schedule).unwrap();
+        let total_tax = IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap();
+
+        // 30,000 of income falls in the 25% bracket
+        assert_eq!(top_tax, dec!(7500));
+        assert_eq!(total_tax - top_tax, dec!(7500));
+    }
+
+    #[test]
+    fn test_non_positive_taxable_income_owes_nothing() {
+        // A negative rate would turn negative taxable income into a negative tax
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(-100000),
+                    upper_bound: Some(dec!(0)),
+                    rate: dec!(-0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: None,
+                    rate: dec!(0.15),
+                },
+            ],
+        );
+
+        for deductions in [dec!(50000), dec!(50000.01), dec!(1000000)] {
+            let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+            entity.add_deduction(deductions, DeductionType::Business);
+            assert!(entity.taxable_income() <= dec!(0));
+            assert_eq!(
+                IncomeTaxCalculator::calculate_tax(&entity, &two_bracket_schedule()).unwrap(),
+                dec!(0)
+            );
+            assert_eq!(
+                IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap(),
+                dec!(0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_checks_each_entity() {
+        let schedule = two_bracket_schedule();
+        let entities = vec![
+            TaxEntity::new(TaxEntityType::Individual, dec!(40000), 2024),
+            TaxEntity::new(TaxEntityType::Individual, dec!(40000), 2023),
+            TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024),
+        ];
+
+        let results = IncomeTaxCalculator::calculate_tax_batch(&entities, &schedule);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &dec!(6000));
+        assert!(matches!(results[1], Err(TaxError::Incompatible { .. })));
+        assert_eq!(results[2].as_ref().unwrap(), &dec!(15000));
+
+        #[cfg(feature = "rayon")]
+        {
+            let parallel = IncomeTaxCalculator::calculate_tax_batch_parallel(&entities, &schedule);
+            assert_eq!(format!("{:?}", parallel), format!("{:?}", results));
+        }
+    }
+
+    #[test]
+    fn test_combined_sums_each_schedule() {
+        let federal = two_bracket_schedule();
+        let state = TaxSchedule::new(
+            2024,
+            vec![TaxBracket {
+                lower_bound: dec!(0),
+                upper_bound: None,
+                rate: dec!(0.05),
+            }],
+        );
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+
+        let combined =
+            IncomeTaxCalculator::calculate_combined(&entity, &[&federal, &state]).unwrap();
+        assert_eq!(combined, dec!(15000) + dec!(4000));
+        assert_eq!(
+            IncomeTaxCalculator::calculate_combined(&entity, &[]).unwrap(),
+            dec!(0)
+        );
+
+        let stale = TaxSchedule::new(2023, state.brackets.clone());
+        let result = IncomeTaxCalculator::calculate_combined(&entity, &[&federal, &stale]);
+        assert!(matches!(result, Err(TaxError::Incompatible { .. })));
+    }
+
+    #[test]
+    fn test_reconcile_refund_balance_due_and_exact_payment() {
+        let schedule = two_bracket_schedule();
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        entity.add_estimated_payment(dec!(2000));
+        entity.add_estimated_payment(dec!(3000));
+
+        // 15,000 owed against 5,000 of estimated payments
+        let refund = IncomeTaxCalculator::reconcile(&entity, &schedule, dec!(12000)).unwrap();
+        assert_eq!(refund.tax_owed, dec!(15000));
+        assert_eq!(refund.total_paid, dec!(17000));
+        assert_eq!(refund.balance, dec!(-2000));
+        assert!(refund.is_refund());
+
+        let due = IncomeTaxCalculator::reconcile(&entity, &schedule, dec!(6000)).unwrap();
+        assert_eq!(due.balance, dec!(4000));
+        assert!(!due.is_refund());
+
+        let exact = IncomeTaxCalculator::reconcile(&entity, &schedule, dec!(10000)).unwrap();
+        assert_eq!(exact.balance, dec!(0));
+        assert!(!exact.is_refund());
+    }
+
+    #[test]
+    fn test_tax_at_exact_threshold() {
+        let schedule = two_bracket_schedule();
+
+        let at_threshold = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&at_threshold, &schedule).unwrap(),
+            dec!(7500)
+        );
+
+        let one_cent_over = TaxEntity::new(TaxEntityType::Individual, dec!(50000.01), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&one_cent_over, &schedule).unwrap(),
+            dec!(7500.0025)
+        );
+    }
+
+    #[test]
+    fn test_both_inclusive_bounds_tax_each_dollar_once() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.15),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50001),
+                    upper_bound: None,
+                    rate: dec!(0.25),
+                },
+            ],
+        )
+        .with_bound_inclusivity(BoundInclusivity::BothInclusive);
+
+        let at_threshold = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&at_threshold, &schedule).unwrap(),
+            dec!(7500)
+        );
+
+        let next_dollar = TaxEntity::new(TaxEntityType::Individual, dec!(50001), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&next_dollar, &schedule).unwrap(),
+            dec!(7500.25)
+        );
+
+        // Same totals as the shared-threshold form of the schedule
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap(),
+            IncomeTaxCalculator::calculate_tax(&entity, &two_bracket_schedule()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dependent_exemptions_reduce_tax() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(60000), 2024);
+        let schedule = two_bracket_schedule();
+
+        let without_dependents =
+            IncomeTaxCalculator::calculate_tax_with_exemptions(&entity, &schedule, dec!(4000))
+                .unwrap();
+        assert_eq!(without_dependents, dec!(10000));
+
+        entity.dependents = 2;
+        let with_dependents =
+            IncomeTaxCalculator::calculate_tax_with_exemptions(&entity, &schedule, dec!(4000))
+                .unwrap();
+        // Taxable income drops from 60,000 to 52,000
+        assert_eq!(with_dependents, dec!(8000));
+    }
+
+    #[test]
+    fn test_dependent_exemptions_floor_at_zero() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(5000), 2024);
+        entity.dependents = 3;
+
+        let tax = IncomeTaxCalculator::calculate_tax_with_exemptions(
+            &entity,
+            &two_bracket_schedule(),
+            dec!(4000),
+        )
+        .unwrap();
+        assert_eq!(tax, dec!(0));
+    }
+
+    #[test]
+    fn test_negative_rate_band_produces_credit() {
+        // EITC-like phase-in: a 34% credit on the first 10,000 of income
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(10000)),
+                    rate: dec!(-0.34),
+                },
+                TaxBracket {
+                    lower_bound: dec!(10000),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.20),
+                },
+            ],
+        );
+        assert!(schedule.validate().is_err());
+        let schedule = schedule.with_negative_rates_allowed(true);
+        assert!(schedule.validate().is_ok());
+
+        let low_income = TaxEntity::new(TaxEntityType::Individual, dec!(8000), 2024);
+        let tax = IncomeTaxCalculator::calculate_tax(&low_income, &schedule).unwrap();
+        assert_eq!(tax, dec!(-2720));
+
+        let high_income = TaxEntity::new(TaxEntityType::Individual, dec!(60000), 2024);
+        let tax = IncomeTaxCalculator::calculate_tax(&high_income, &schedule).unwrap();
+        assert_eq!(tax, dec!(-1400));
+    }
+
+    #[test]
+    fn test_tax_in_top_bracket_within_first_bracket() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(20000), 2024);
+        let schedule = two_bracket_schedule();
+
+        let top_tax = IncomeTaxCalculator::tax_in_top_bracket(&entity, &schedule).unwrap();
+        assert_eq!(top_tax, dec!(3000));
+    }
+
+    #[test]
+    fn test_tax_table_method_matches_published_table() {
+        // 2024 single filer brackets up to the 24% rate
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(11600)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(11600),
+                    upper_bound: Some(dec!(47150)),
+                    rate: dec!(0.12),
+                },
+                TaxBracket {
+                    lower_bound: dec!(47150),
+                    upper_bound: Some(dec!(100525)),
+                    rate: dec!(0.22),
+                },
+                TaxBracket {
+                    lower_bound: dec!(100525),
+                    upper_bound: None,
+                    rate: dec!(0.24),
+                },
+            ],
+        );
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+
+        // The 2024 tax table lists $6,059 for the $50,000 - $50,050 row
+        let table_tax =
+            IncomeTaxCalculator::calculate_tax_table_method(&entity, &schedule).unwrap();
+        assert_eq!(table_tax, dec!(6059));
+
+        let exact_tax = IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap();
+        assert_eq!(exact_tax, dec!(6053.00));
+
+        // Above the threshold the exact formula applies
+        let high_income = TaxEntity::new(TaxEntityType::Individual, dec!(100010), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax_table_method_with_threshold(
+                &high_income,
+                &schedule,
+                dec!(100000)
+            )
+            .unwrap(),
+            IncomeTaxCalculator::calculate_tax(&high_income, &schedule).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tax_if_half_of_deduction_removed() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(60000), 2024);
+        entity.add_deduction(dec!(20000), DeductionType::Charitable);
+        entity.add_deduction(dec!(5000), DeductionType::Personal);
+        let schedule = two_bracket_schedule();
+
+        let current = IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap();
+        let reduced = IncomeTaxCalculator::tax_if_deduction_removed(
+            &entity,
+            &schedule,
+            DeductionType::Charitable,
+            dec!(10000),
+        )
+        .unwrap();
+
+        // Taxable income rises from 35,000 to 45,000, all within the 15% bracket
+        assert_eq!(current, dec!(5250));
+        assert_eq!(reduced - current, dec!(1500));
+
+        // Removing more than was claimed only removes what exists
+        let removed_all = IncomeTaxCalculator::tax_if_deduction_removed(
+            &entity,
+            &schedule,
+            DeductionType::Charitable,
+            dec!(50000),
+        )
+        .unwrap();
+        assert_eq!(removed_all, dec!(7500) + dec!(1250));
+    }
+
+    #[test]
+    fn test_shifting_to_higher_rate_year_increases_tax() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        let schedule = two_bracket_schedule();
+        let next_schedule = TaxSchedule::new(
+            2025,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.18),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.28),
+                },
+            ],
+        );
+
+        let comparison =
+            IncomeTaxCalculator::compare_with_next_year(&entity, &schedule, &next_schedule)
+                .unwrap();
+
+        assert_eq!(comparison.current_tax, dec!(15000));
+        assert_eq!(comparison.next_year_tax, dec!(17400));
+        assert_eq!(comparison.lower_year(), 2024);
+        assert_eq!(comparison.savings_from_shifting(), dec!(-2400));
+
+        assert!(matches!(
+            IncomeTaxCalculator::compare_with_next_year(&entity, &schedule, &schedule),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+
+    #[test]
+    fn test_next_year_comparison_keeps_recognized_deferred_income() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        entity.add_deferred_income(dec!(30000), 2024);
+        let schedule = two_bracket_schedule();
+        let next_schedule = TaxSchedule::new(2025, schedule.brackets.clone());
+
+        // The same 80,000 is taxed in both years, so shifting saves nothing
+        let comparison =
+            IncomeTaxCalculator::compare_with_next_year(&entity, &schedule, &next_schedule)
+                .unwrap();
+        assert_eq!(comparison.current_tax, dec!(15000));
+        assert_eq!(comparison.next_year_tax, dec!(15000));
+
+        let last_year = TaxEntity::new(TaxEntityType::Individual, dec!(50000), u16::MAX);
+        let last_schedule = TaxSchedule::new(u16::MAX, schedule.brackets.clone());
+        assert!(matches!(
+            IncomeTaxCalculator::compare_with_next_year(&last_year, &last_schedule, &last_schedule),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_calculate_tax_emits_span() {
+        use std::collections::HashMap;
+        use std::fmt;
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        type RecordedSpans = Arc<Mutex<Vec<(String, HashMap<String, String>)>>>;
+
+        /// Minimal subscriber that records every span's name and fields.
+        struct SpanRecorder(RecordedSpans);
+
+        struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+        impl Visit for FieldVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{value:?}"));
+            }
+        }
+
+        impl Subscriber for SpanRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+                let mut fields = HashMap::new();
+                attributes.record(&mut FieldVisitor(&mut fields));
+                let mut spans = self.0.lock().unwrap();
+                spans.push((attributes.metadata().name().to_string(), fields));
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.0.lock().unwrap();
+                let (_, fields) = &mut spans[span.into_u64() as usize - 1];
+                values.record(&mut FieldVisitor(fields));
+            }
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, _event: &Event<'_>) {}
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
+        }
+
+        let spans = RecordedSpans::default();
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        let schedule = two_bracket_schedule();
+
+        tracing::subscriber::with_default(SpanRecorder(spans.clone()), || {
+            IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap();
+        });
+
+        let spans = spans.lock().unwrap();
+        let (name, fields) = &spans[0];
+        assert_eq!(name, "calculate_tax");
+        assert_eq!(fields["tax_year"], "2024");
+        assert_eq!(fields["bracket_count"], "2");
+        assert!(fields.contains_key("duration_us"));
+    }
+
+    #[test]
+    fn test_effective_rate_on_gross_below_taxable_rate() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        entity.add_deduction(dec!(40000), DeductionType::Business);
+        let schedule = two_bracket_schedule();
+
+        let tax = IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap();
+        let gross_rate = IncomeTaxCalculator::effective_rate_on_gross(&entity, &schedule).unwrap();
+        let taxable_rate =
+            IncomeTaxCalculator::calculate_effective_rate(&entity, &schedule).unwrap();
+        assert_eq!(taxable_rate, tax / entity.taxable_income());
+
+        // 10,000 tax on 100,000 gross versus 60,000 taxable
+        assert_eq!(gross_rate, dec!(0.10));
+        assert_eq!(taxable_rate.round_dp(4), dec!(0.1667));
+        assert!(gross_rate < taxable_rate);
+
+        let no_income = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::effective_rate_on_gross(&no_income, &schedule).unwrap(),
+            dec!(0)
+        );
+    }
+
+    #[test]
+    fn test_recapture_above_threshold() {
+        let schedule = two_bracket_schedule();
+
+        let at_threshold = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax_with_recapture(
+                &at_threshold,
+                &schedule,
+                dec!(100000)
+            )
+            .unwrap(),
+            dec!(20000)
+        );
+
+        // One more dollar loses the 15% rate on the first 50,000
+        let above = TaxEntity::new(TaxEntityType::Individual, dec!(100001), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax_with_recapture(&above, &schedule, dec!(100000))
+                .unwrap(),
+            dec!(25000.25)
+        );
+    }
+
+    #[test]
+    fn test_income_below_first_bracket_is_untaxed() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(12000),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.20),
+                },
+            ],
+        );
+
+        let below = TaxEntity::new(TaxEntityType::Individual, dec!(12000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&below, &schedule).unwrap(),
+            dec!(0)
+        );
+
+        // Only the 8,000 above the threshold is taxed
+        let above = TaxEntity::new(TaxEntityType::Individual, dec!(20000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&above, &schedule).unwrap(),
+            dec!(800)
+        );
+        assert_eq!(
+            IncomeTaxCalculator::tax_in_top_bracket(&above, &schedule).unwrap(),
+            dec!(800)
+        );
+    }
+
+    #[test]
+    fn test_income_in_gap_between_brackets_is_untaxed() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(10000)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(20000),
+                    upper_bound: None,
+                    rate: dec!(0.30),
+                },
+            ],
+        );
+
+        let in_gap = TaxEntity::new(TaxEntityType::Individual, dec!(15000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&in_gap, &schedule).unwrap(),
+            dec!(1000)
+        );
+
+        // Each bracket taxes only its own window: 10,000 at 10% and 5,000 at 30%
+        let above = TaxEntity::new(TaxEntityType::Individual, dec!(25000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&above, &schedule).unwrap(),
+            dec!(2500)
+        );
+    }
+
+    #[test]
+    fn test_compatibility_reports_mismatched_attribute() {
+        let schedule = two_bracket_schedule();
+
+        let next_year = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2025);
+        let error = IncomeTaxCalculator::calculate_tax(&next_year, &schedule).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Entity and schedule are incompatible: tax year mismatch: entity 2025, schedule 2024"
+        );
+
+        let mut in_euros = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        in_euros.currency = Currency::EUR;
+        let error = IncomeTaxCalculator::calculate_tax(&in_euros, &schedule).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Entity and schedule are incompatible: currency mismatch: entity uses EUR, schedule uses USD"
+        );
+
+        let euro_schedule = schedule.with_currency(Currency::EUR);
+        assert!(IncomeTaxCalculator::calculate_tax(&in_euros, &euro_schedule).is_ok());
+    }
+
+    #[test]
+    fn test_compatibility_checks_filing_status() {
+        let joint = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024)
+            .with_filing_status(FilingStatus::MarriedFilingJointly);
+
+        // Schedules without a filing status apply to everyone
+        assert!(IncomeTaxCalculator::calculate_tax(&joint, &two_bracket_schedule()).is_ok());
+
+        let single_schedule = two_bracket_schedule().with_filing_status(FilingStatus::Single);
+        let error = IncomeTaxCalculator::calculate_tax(&joint, &single_schedule).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Entity and schedule are incompatible: filing status mismatch: \
+             entity files as MarriedFilingJointly, schedule is for Single"
+        );
+
+        let joint_schedule =
+            two_bracket_schedule().with_filing_status(FilingStatus::MarriedFilingJointly);
+        assert!(IncomeTaxCalculator::calculate_tax(&joint, &joint_schedule).is_ok());
+    }
+
+    #[test]
+    fn test_credits_refundable_and_non_refundable() {
+        let schedule = two_bracket_schedule();
+        // 3,000 of tax at 15%
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(20000), 2024);
+        entity.add_credit(dec!(1000), CreditType::Education, false);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax_after_credits(&entity, &schedule).unwrap(),
+            dec!(2000)
+        );
+
+        // Non-refundable credits stop at zero
+        entity.add_credit(dec!(4000), CreditType::Foreign, false);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax_after_credits(&entity, &schedule).unwrap(),
+            dec!(0)
+        );
+
+        // Refundable credits apply after that, producing a refund
+        entity.add_credit(dec!(1500), CreditType::EarnedIncome, true);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax_after_credits(&entity, &schedule).unwrap(),
+            dec!(-1500)
+        );
+    }
+
+    #[test]
+    fn test_rate_transitions_offset_by_deductions() {
+        let schedule = two_bracket_schedule();
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::rate_transition_incomes(&entity, &schedule).unwrap(),
+            vec![(dec!(0), dec!(0.15)), (dec!(50000), dec!(0.25))]
+        );
+
+        let mut deducting = entity.clone();
+        deducting.add_deduction(dec!(12000), DeductionType::Business);
+        let transitions =
+            IncomeTaxCalculator::rate_transition_incomes(&deducting, &schedule).unwrap();
+        assert_eq!(
+            transitions,
+            vec![(dec!(12000), dec!(0.15)), (dec!(62000), dec!(0.25))]
+        );
+
+        // Just above the shifted threshold the entity is in the new bracket
+        deducting.income = dec!(62000.01);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_marginal_rate(&deducting, &schedule).unwrap(),
+            dec!(0.25)
+        );
+
+        // A 20% deduction scales the threshold rather than shifting it
+        let mut percentage = entity.clone();
+        percentage.add_percentage_deduction(dec!(0.20), DeductionType::Business);
+        assert_eq!(
+            IncomeTaxCalculator::rate_transition_incomes(&percentage, &schedule).unwrap()[1],
+            (dec!(62500), dec!(0.25))
+        );
+    }
+
+    #[test]
+    fn test_verify_against_irs_worksheet() {
+        // 2024 single filer brackets up to the 22% bracket
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(11600)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(11600),
+                    upper_bound: Some(dec!(47150)),
+                    rate: dec!(0.12),
+                },
+                TaxBracket {
+                    lower_bound: dec!(47150),
+                    upper_bound: Some(dec!(100525)),
+                    rate: dec!(0.22),
+                },
+            ],
+        );
+        // The 2024 Tax Computation Worksheet gives 100,000 x 22% - 4,947 = 17,053
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+
+        assert!(IncomeTaxCalculator::verify(&entity, &schedule, dec!(17053), dec!(1)).unwrap());
+        assert!(IncomeTaxCalculator::verify(&entity, &schedule, dec!(17054), dec!(1)).unwrap());
+        assert!(!IncomeTaxCalculator::verify(&entity, &schedule, dec!(17060), dec!(1)).unwrap());
+    }
+
+    #[test]
+    fn test_deduction_drops_marginal_bracket() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(11600)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(11600),
+                    upper_bound: Some(dec!(47150)),
+                    rate: dec!(0.12),
+                },
+                TaxBracket {
+                    lower_bound: dec!(47150),
+                    upper_bound: None,
+                    rate: dec!(0.22),
+                },
+            ],
+        );
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(60000), 2024);
+        entity.add_deduction(dec!(15000), DeductionType::Business);
+
+        assert_eq!(
+            IncomeTaxCalculator::marginal_rate_change_from_deductions(&entity, &schedule).unwrap(),
+            (dec!(0.22), dec!(0.12))
+        );
+    }
+
+    #[test]
+    fn test_marginal_and_effective_rates() {
+        let schedule = two_bracket_schedule();
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+
+        assert_eq!(
+            IncomeTaxCalculator::calculate_marginal_rate(&entity, &schedule).unwrap(),
+            dec!(0.25)
+        );
+        // 15,000 tax on 80,000 taxable income
+        assert_eq!(
+            IncomeTaxCalculator::calculate_effective_rate(&entity, &schedule).unwrap(),
+            dec!(0.1875)
+        );
+
+        let no_income = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_marginal_rate(&no_income, &schedule).unwrap(),
+            dec!(0.15)
+        );
+        assert_eq!(
+            IncomeTaxCalculator::calculate_effective_rate(&no_income, &schedule).unwrap(),
+            dec!(0)
+        );
+
+        let next_year = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2025);
+        assert!(matches!(
+            IncomeTaxCalculator::calculate_marginal_rate(&next_year, &schedule),
+            Err(TaxError::Incompatible { .. })
+        ));
+        assert!(matches!(
+            IncomeTaxCalculator::calculate_effective_rate(&next_year, &schedule),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+
+    #[test]
+    fn test_equivalent_flat_rate_reproduces_tax() {
+        let schedule = two_bracket_schedule();
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(93000), 2024);
+        entity.add_deduction(dec!(3000), DeductionType::Personal);
+
+        let tax = IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap();
+        let flat_rate = IncomeTaxCalculator::equivalent_flat_rate(&entity, &schedule).unwrap();
+        // 17,500 / 90,000 does not terminate, so compare to the cent
+        assert_eq!((flat_rate * entity.taxable_income()).round_dp(2), tax);
+
+        assert_eq!(
+            IncomeTaxCalculator::schedule_flat_rate(&schedule, entity.taxable_income()),
+            flat_rate
+        );
+        assert_eq!(
+            IncomeTaxCalculator::schedule_flat_rate(&schedule, dec!(0)),
+            dec!(0)
+        );
+    }
+
+    #[test]
+    fn test_breakdown_sums_to_total() {
+        let schedule = two_bracket_schedule();
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+
+        let breakdown =
+            IncomeTaxCalculator::calculate_tax_breakdown(&entity, &schedule, RoundingMode::Total)
+                .unwrap();
+        assert_eq!(
+            breakdown,
+            vec![
+                BracketTax {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.15),
+                    taxed_income: dec!(50000),
+                    tax: dec!(7500),
+                },
+                BracketTax {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.25),
+                    taxed_income: dec!(30000),
+                    tax: dec!(7500),
+                },
+            ]
+        );
+        let total = breakdown.iter().map(|b| b.tax).sum::<Decimal>();
+        assert_eq!(
+            total,
+            IncomeTaxCalculator::calculate_tax(&entity, &schedule).unwrap()
+        );
+
+        // The 25% bracket is not reached, so it is left out
+        let lower = TaxEntity::new(TaxEntityType::Individual, dec!(20000), 2024);
+        let breakdown =
+            IncomeTaxCalculator::calculate_tax_breakdown(&lower, &schedule, RoundingMode::Total)
+                .unwrap();
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown[0].tax, dec!(3000));
+    }
+
+    #[test]
+    fn test_per_bracket_rounding_differs_from_total_rounding() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(1003)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(1003),
+                    upper_bound: None,
+                    rate: dec!(0.20),
+                },
+            ],
+        );
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(1005), 2024);
+
+        // 100.30 + 0.40: each part rounds down, but the 100.70 total rounds up
+        let breakdown = IncomeTaxCalculator::calculate_tax_breakdown(
+            &entity,
+            &schedule,
+            RoundingMode::PerBracket,
+        )
+        .unwrap();
+        assert_eq!(breakdown[0].tax, dec!(100));
+        assert_eq!(breakdown[1].tax, dec!(0));
+        assert_eq!(
+            IncomeTaxCalculator::calculate_rounded_tax(
+                &entity,
+                &schedule,
+                RoundingMode::PerBracket
+            )
+            .unwrap(),
+            dec!(100)
+        );
+        assert_eq!(
+            IncomeTaxCalculator::calculate_rounded_tax(&entity, &schedule, RoundingMode::Total)
+                .unwrap(),
+            dec!(101)
+        );
+    }
+
+    #[test]
+    fn test_tax_equivalent_yield() {
+        let taxable = IncomeTaxCalculator::tax_equivalent_yield(dec!(0.03), dec!(0.24));
+        assert_eq!(taxable.round_dp(4), dec!(0.0395));
+
+        // 80,000 of taxable income falls in the 25% bracket
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        let for_entity = IncomeTaxCalculator::tax_equivalent_yield_for(
+            &entity,
+            &two_bracket_schedule(),
+            dec!(0.03),
+        )
+        .unwrap();
+        assert_eq!(for_entity, dec!(0.04));
     }
 }
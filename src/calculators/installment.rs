@@ -0,0 +1,105 @@
+//! Installment agreements for paying a tax balance over time.
+//!
+//! A balance paid in equal monthly installments accrues interest on the
+//! unpaid amount each month, so the payment is found with the standard
+//! amortization formula.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Months in a year, used to convert an annual interest rate to a monthly one.
+const MONTHS_PER_YEAR: Decimal = Decimal::from_parts(12, 0, 0, false, 0);
+
+/// The payment schedule for a balance repaid in equal monthly installments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallmentPlan {
+    /// The amount due each month, rounded to cents
+    pub monthly_payment: Decimal,
+    /// Number of monthly installments
+    pub installments: u16,
+    /// Interest paid over the life of the plan
+    pub total_interest: Decimal,
+}
+
+impl InstallmentPlan {
+    /// Total of all installments, including interest.
+    pub fn total_paid(&self) -> Decimal {
+        self.monthly_payment * Decimal::from(self.installments)
+    }
+}
+
+/// Calculator for tax balances paid by installment.
+pub struct InstallmentCalculator;
+
+impl InstallmentCalculator {
+    /// Calculates the monthly payment and total interest for an installment agreement.
+    ///
+    /// Interest compounds monthly at `annual_rate / 12`. The payment is
+    /// `balance * r / (1 - (1 + r)^-n)`, or `balance / n` when the rate is zero,
+    /// rounded half away from zero to cents. Total interest is the difference
+    /// between the rounded payments and the balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `balance` - The tax balance owed
+    /// * `annual_rate` - The annual interest rate as a decimal (e.g., 0.08 for 8%)
+    /// * `installments` - Number of monthly payments
+    ///
+    /// # Panics
+    ///
+    /// Panics if `installments` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::InstallmentCalculator;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let plan = InstallmentCalculator::amortize(dec!(1200), dec!(0), 12);
+    /// assert_eq!(plan.monthly_payment, dec!(100));
+    /// ```
+    pub fn amortize(balance: Decimal, annual_rate: Decimal, installments: u16) -> InstallmentPlan {
+        assert!(installments > 0, "installments must be positive");
+
+        let periods = Decimal::from(installments);
+        let monthly_rate = annual_rate / MONTHS_PER_YEAR;
+
+        let payment = if monthly_rate.is_zero() {
+            balance / periods
+        } else {
+            let growth =
+                (0..installments).fold(Decimal::ONE, |acc, _| acc * (Decimal::ONE + monthly_rate));
+            balance * monthly_rate * growth / (growth - Decimal::ONE)
+        };
+        let monthly_payment =
+            payment.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+
+        InstallmentPlan {
+            monthly_payment,
+            installments,
+            total_interest: monthly_payment * periods - balance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_amortize_known_loan() {
+        let plan = InstallmentCalculator::amortize(dec!(10000), dec!(0.08), 24);
+
+        assert_eq!(plan.monthly_payment, dec!(452.27));
+        assert_eq!(plan.total_interest, dec!(854.48));
+        assert_eq!(plan.total_paid(), dec!(10854.48));
+    }
+
+    #[test]
+    fn test_amortize_without_interest() {
+        let plan = InstallmentCalculator::amortize(dec!(3000), dec!(0), 6);
+
+        assert_eq!(plan.monthly_payment, dec!(500));
+        assert_eq!(plan.total_interest, dec!(0));
+    }
+}
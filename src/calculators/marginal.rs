@@ -0,0 +1,232 @@
+//! Marginal rate calculations across stacked taxes.
+//!
+//! This module combines the marginal rates of several independent taxes
+//! (federal income tax, state income tax and payroll taxes) into the single
+//! rate that applies to the next dollar of income.
+
+use crate::calculators::payroll::PayrollParameters;
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{TaxBracket, TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+
+/// Marginal rates before and after a year of nominal income growth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketCreep {
+    /// Marginal rate on the entity's taxable income in its own tax year
+    pub current_rate: Decimal,
+    /// Taxable income in the following year after nominal growth
+    pub next_income: Decimal,
+    /// Marginal rate on the grown income under the following year's schedule
+    pub next_rate: Decimal,
+    /// Whether the marginal rate rose because the grown income moved into a
+    /// higher bracket, rather than because a bracket's rate changed
+    pub bracket_creep: bool,
+}
+
+/// Calculator for marginal rates that span multiple taxes.
+pub struct MarginalRateCalculator;
+
+impl MarginalRateCalculator {
+    /// Calculates the combined marginal rate on the entity's next dollar of income.
+    ///
+    /// Income tax rates are taken from the bracket containing the entity's
+    /// taxable income in each schedule. Payroll rates are based on gross
+    /// income, since payroll taxes are not reduced by income tax deductions.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity whose marginal rate should be calculated
+    /// * `federal` - The federal income tax schedule
+    /// * `state` - The state income tax schedule
+    /// * `payroll` - Payroll tax rates and thresholds
+    ///
+    /// # Returns
+    ///
+    /// The sum of the federal, state and payroll marginal rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if either schedule's tax year, filing
+    /// status or currency differs from the entity's.
+    pub fn combined_marginal_rate(
+        entity: &TaxEntity,
+        federal: &TaxSchedule,
+        state: &TaxSchedule,
+        payroll: &PayrollParameters,
+    ) -> Result<Decimal, TaxError> {
+        IncomeTaxCalculator::check_compatibility(entity, federal)?;
+        IncomeTaxCalculator::check_compatibility(entity, state)?;
+
+        let taxable_income = entity.taxable_income();
+
+        Ok(Self::schedule_rate(taxable_income, federal)
+            + Self::schedule_rate(taxable_income, state)
+            + payroll.marginal_rate(entity.income))
+    }
+
+    /// Reports whether a marginal rate increase is caused by bracket creep.
+    ///
+    /// The entity's taxable income is grown by `income_growth` (for example the
+    /// inflation rate, which keeps real income flat) and placed in the
+    /// following year's schedule. Bracket creep is reported when the marginal
+    /// rate rises because the grown income lands in a higher-positioned bracket
+    /// than before, which happens when thresholds are indexed by less than the
+    /// income grew. A rate increase within the same bracket position is a
+    /// statutory rate change and is not counted as creep.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity, in the earlier tax year
+    /// * `current` - The schedule for the entity's tax year
+    /// * `next` - The schedule for the following year
+    /// * `income_growth` - Nominal income growth as a decimal (e.g., 0.03 for 3%)
+    ///
+    /// # Returns
+    ///
+    /// The marginal rates in both years and whether the change is bracket creep.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if `current` is not for the entity's tax
+    /// year or `next` is not for the year after it, or if either schedule's
+    /// filing status or currency differs from the entity's.
+    pub fn bracket_creep(
+        entity: &TaxEntity,
+        current: &TaxSchedule,
+        next: &TaxSchedule,
+        income_growth: Decimal,
+    ) -> Result<BracketCreep, TaxError> {
+        IncomeTaxCalculator::check_compatibility(entity, current)?;
+        IncomeTaxCalculator::check_compatibility(&entity.for_next_year()?, next)?;
+
+        let income = entity.taxable_income();
+        let next_income = income * (Decimal::ONE + income_growth);
+
+        let current_bracket = Self::next_dollar_bracket(income, current);
+        let next_bracket = Self::next_dollar_bracket(next_income, next);
+        let current_rate = current_bracket.map_or(Decimal::ZERO, |(_, bracket)| bracket.rate);
+        let next_rate = next_bracket.map_or(Decimal::ZERO, |(_, bracket)| bracket.rate);
+
+        let moved_up = match (current_bracket, next_bracket) {
+            (Some((before, _)), Some((after, _))) => after > before,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+
+        Ok(BracketCreep {
+            current_rate,
+            next_income,
+            next_rate,
+            bracket_creep: moved_up && next_rate > current_rate,
+        })
+    }
+
+    /// Returns the rate of the bracket that the next dollar above `income` falls in.
+    fn schedule_rate(income: Decimal, schedule: &TaxSchedule) -> Decimal {
+        Self::next_dollar_bracket(income, schedule)
+            .map_or(Decimal::ZERO, |(_, bracket)| bracket.rate)
+    }
+
+    /// Returns the bracket the next dollar above `income` falls in, with its position.
+    fn next_dollar_bracket(
+        income: Decimal,
+        schedule: &TaxSchedule,
+    ) -> Option<(usize, &TaxBracket)> {
+        schedule.brackets.iter().enumerate().find(|(_, bracket)| {
+            income >= bracket.lower_bound && bracket.upper_bound.is_none_or(|upper| income < upper)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TaxEntityType;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_combined_marginal_rate() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(180000), 2024);
+        let federal = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(100000)),
+                    rate: dec!(0.12),
+                },
+                TaxBracket {
+                    lower_bound: dec!(100000),
+                    upper_bound: Some(dec!(200000)),
+                    rate: dec!(0.22),
+                },
+                TaxBracket {
+                    lower_bound: dec!(200000),
+                    upper_bound: None,
+                    rate: dec!(0.32),
+                },
+            ],
+        );
+        let state = TaxSchedule::new(
+            2024,
+            vec![TaxBracket {
+                lower_bound: dec!(0),
+                upper_bound: None,
+                rate: dec!(0.05),
+            }],
+        );
+        let payroll = PayrollParameters {
+            social_security_rate: dec!(0.062),
+            social_security_wage_base: dec!(168600),
+            medicare_rate: dec!(0.0145),
+            additional_medicare_rate: dec!(0.009),
+            additional_medicare_threshold: dec!(200000),
+        };
+
+        let rate =
+            MarginalRateCalculator::combined_marginal_rate(&entity, &federal, &state, &payroll)
+                .unwrap();
+        assert_eq!(rate, dec!(0.2845));
+    }
+
+    fn two_rate_schedule(tax_year: u16, threshold: Decimal) -> TaxSchedule {
+        TaxSchedule::new(
+            tax_year,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(threshold),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: threshold,
+                    upper_bound: None,
+                    rate: dec!(0.20),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_bracket_creep_when_thresholds_lag_inflation() {
+        let entity = TaxEntity::new(TaxEntityType::Individual, dec!(49000), 2024);
+        let current = two_rate_schedule(2024, dec!(50000));
+
+        // Income keeps pace with 5% inflation but the threshold only rises 1%
+        let lagging = two_rate_schedule(2025, dec!(50500));
+        let creep =
+            MarginalRateCalculator::bracket_creep(&entity, &current, &lagging, dec!(0.05)).unwrap();
+        assert_eq!(creep.next_income, dec!(51450));
+        assert_eq!(creep.current_rate, dec!(0.10));
+        assert_eq!(creep.next_rate, dec!(0.20));
+        assert!(creep.bracket_creep);
+
+        // Fully indexed thresholds keep the same marginal rate
+        let indexed = two_rate_schedule(2025, dec!(52500));
+        let no_creep =
+            MarginalRateCalculator::bracket_creep(&entity, &current, &indexed, dec!(0.05)).unwrap();
+        assert_eq!(no_creep.next_rate, dec!(0.10));
+        assert!(!no_creep.bracket_creep);
+    }
+}
@@ -3,6 +3,39 @@
 //! This module provides various tax calculators that implement specific
 //! calculation rules and algorithms for different tax scenarios.
 
+mod amt;
+mod burden;
+mod capital_gains;
+mod corporate;
+mod deduction_choice;
+mod estimated;
 mod income_tax;
+mod installment;
+mod marginal;
+mod pass_through;
+pub mod payroll;
+mod residency;
+mod spousal;
 
-pub use income_tax::IncomeTaxCalculator;
+pub use amt::{AmtCalculator, TaxLiability, TaxRegime, AMT_EXEMPTION_PHASE_OUT_RATE};
+pub use burden::{TaxBurden, TaxBurdenCalculator};
+pub use capital_gains::{CapitalGainsCalculator, CapitalGainsTax};
+pub use corporate::{
+    CorporateTaxCalculator, MarginalRelief, US_FEDERAL_CORPORATE_RATE,
+    US_FEDERAL_FLAT_CORPORATE_RATE_SINCE,
+};
+pub use deduction_choice::{DeductionMethod, DeductionOptimizer, DeductionPlan};
+pub use estimated::{
+    EstimatedTaxCalculator, EstimatedTaxPlan, SafeHarbor, CURRENT_YEAR_SAFE_HARBOR_RATE,
+    HIGH_INCOME_AGI_THRESHOLD,
+};
+pub use income_tax::{
+    BracketTax, IncomeTaxCalculator, Reconciliation, RoundingMode, YearComparison,
+    TAX_TABLE_THRESHOLD,
+};
+pub use installment::{InstallmentCalculator, InstallmentPlan};
+pub use marginal::{BracketCreep, MarginalRateCalculator};
+pub use pass_through::{PassThroughCalculator, OWNERSHIP_TOLERANCE};
+pub use payroll::{PayrollParameters, PayrollTax, PayrollTaxCalculator};
+pub use residency::{IncomeAllocation, JurisdictionTax, PartYearResidencyCalculator};
+pub use spousal::MarriedFilingSeparatelyCalculator;
@@ -0,0 +1,188 @@
+//! Income splitting for pass-through entities.
+//!
+//! A partnership pays no income tax itself. Its taxable income is divided
+//! among the partners by ownership share, and each partner pays tax on their
+//! share under their own schedule.
+
+use crate::errors::TaxError;
+use crate::models::{TaxEntity, TaxEntityType};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// How far ownership shares may total from 1 and still be accepted, to allow
+/// for shares such as thirds that can't be written exactly.
+pub const OWNERSHIP_TOLERANCE: Decimal = dec!(0.0001);
+
+/// Calculator for distributing a partnership's income to its partners.
+pub struct PassThroughCalculator;
+
+impl PassThroughCalculator {
+    /// Divides a partnership's taxable income among its partners.
+    ///
+    /// Each partner's amount is the partnership's taxable income times their
+    /// ownership share, so a loss is divided in the same proportions. The last
+    /// partner receives whatever remains, so the amounts always total the
+    /// partnership's taxable income even when the shares are within
+    /// [`OWNERSHIP_TOLERANCE`] of 1 rather than exactly 1. The amounts can be
+    /// added to each partner's own `TaxEntity` and taxed with
+    /// [`IncomeTaxCalculator`](crate::calculators::IncomeTaxCalculator) under
+    /// the partner's schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `partnership` - The partnership whose income is distributed
+    /// * `ownership` - Each partner's share as a decimal (e.g., 0.25 for 25%)
+    ///
+    /// # Returns
+    ///
+    /// Each partner's share of taxable income, in the order of `ownership`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity is not a partnership,
+    /// and `TaxError::InvalidOwnership` if a share is negative or the shares
+    /// don't total 1 within [`OWNERSHIP_TOLERANCE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::PassThroughCalculator;
+    /// use tax_engine::{TaxEntity, TaxEntityType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let partnership = TaxEntity::new(TaxEntityType::Partnership, dec!(200000), 2024);
+    /// let shares = PassThroughCalculator::distribute(&partnership, &[dec!(0.6), dec!(0.4)])?;
+    /// assert_eq!(shares, vec![dec!(120000), dec!(80000)]);
+    /// # Ok::<(), tax_engine::TaxError>(())
+    /// ```
+    pub fn distribute(
+        partnership: &TaxEntity,
+        ownership: &[Decimal],
+    ) -> Result<Vec<Decimal>, TaxError> {
+        if partnership.entity_type != TaxEntityType::Partnership {
+            return Err(TaxError::Incompatible {
+                reason: format!(
+                    "pass-through distribution applies to partnerships, not {:?}",
+                    partnership.entity_type
+                ),
+            });
+        }
+        if let Some(share) = ownership.iter().find(|share| **share < Decimal::ZERO) {
+            return Err(TaxError::InvalidOwnership(format!(
+                "share {} is negative",
+                share
+            )));
+        }
+        let total = ownership
+            .iter()
+            .fold(Decimal::ZERO, |acc, share| acc + share);
+        if (total - Decimal::ONE).abs() > OWNERSHIP_TOLERANCE {
+            return Err(TaxError::InvalidOwnership(format!(
+                "shares total {} rather than 1",
+                total
+            )));
+        }
+
+        let taxable_income = partnership.taxable_income();
+        let mut amounts: Vec<Decimal> = ownership
+            .iter()
+            .map(|share| taxable_income * share)
+            .collect();
+        // Shares total 1, so there is at least one partner
+        let (last, others) = amounts.split_last_mut().expect("at least one partner");
+        *last = taxable_income
+            - others
+                .iter()
+                .fold(Decimal::ZERO, |acc, amount| acc + amount);
+
+        Ok(amounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculators::IncomeTaxCalculator;
+    use crate::models::{DeductionType, TaxBracket, TaxSchedule};
+
+    fn partnership(income: Decimal) -> TaxEntity {
+        let mut partnership = TaxEntity::new(TaxEntityType::Partnership, income, 2024);
+        partnership.add_deduction(dec!(20000), DeductionType::Business);
+        partnership
+    }
+
+    #[test]
+    fn test_two_partners_equal_and_unequal() {
+        let partnership = partnership(dec!(120000));
+
+        let equal = PassThroughCalculator::distribute(&partnership, &[dec!(0.5), dec!(0.5)]);
+        assert_eq!(equal.unwrap(), vec![dec!(50000), dec!(50000)]);
+
+        let unequal = PassThroughCalculator::distribute(&partnership, &[dec!(0.7), dec!(0.3)]);
+        assert_eq!(unequal.unwrap(), vec![dec!(70000), dec!(30000)]);
+    }
+
+    #[test]
+    fn test_three_partners_taxed_on_own_schedules() {
+        let partnership = partnership(dec!(220000));
+        let shares =
+            PassThroughCalculator::distribute(&partnership, &[dec!(0.5), dec!(0.3), dec!(0.2)])
+                .unwrap();
+        assert_eq!(shares, vec![dec!(100000), dec!(60000), dec!(40000)]);
+
+        // The first partner also has wages, and pays tax on both
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(100000)),
+                    rate: dec!(0.20),
+                },
+                TaxBracket {
+                    lower_bound: dec!(100000),
+                    upper_bound: None,
+                    rate: dec!(0.30),
+                },
+            ],
+        );
+        let partner = TaxEntity::new(TaxEntityType::Individual, dec!(50000) + shares[0], 2024);
+        assert_eq!(
+            IncomeTaxCalculator::calculate_tax(&partner, &schedule).unwrap(),
+            dec!(35000)
+        );
+
+        // Shares within tolerance of 1 still distribute all of the income
+        let third = dec!(0.3333);
+        let thirds =
+            PassThroughCalculator::distribute(&partnership, &[third, third, third]).unwrap();
+        assert_eq!(thirds, vec![dec!(66660), dec!(66660), dec!(66680)]);
+        assert_eq!(
+            thirds
+                .iter()
+                .fold(Decimal::ZERO, |acc, amount| acc + amount),
+            partnership.taxable_income()
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_shares_and_non_partnerships() {
+        let partnership = partnership(dec!(120000));
+        for shares in [
+            vec![dec!(0.5), dec!(0.4)],
+            vec![dec!(0.6), dec!(0.6)],
+            vec![dec!(1.2), dec!(-0.2)],
+        ] {
+            assert!(matches!(
+                PassThroughCalculator::distribute(&partnership, &shares),
+                Err(TaxError::InvalidOwnership(_))
+            ));
+        }
+
+        let company = TaxEntity::new(TaxEntityType::Corporation, dec!(120000), 2024);
+        assert!(matches!(
+            PassThroughCalculator::distribute(&company, &[dec!(1)]),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+//! Payroll tax parameters and calculations.
+//!
+//! Payroll taxes (Social Security and Medicare) are flat-rate taxes on earned
+//! income rather than progressive brackets, so they are modeled separately
+//! from income tax schedules.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Share of net self-employment income subject to self-employment tax.
+///
+/// Employees don't pay payroll tax on their employer's half, so the
+/// self-employed exclude the equivalent 7.65% of their earnings.
+pub const SELF_EMPLOYMENT_EARNINGS_FACTOR: Decimal = dec!(0.9235);
+
+/// Rates and thresholds for employee-side payroll taxes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayrollParameters {
+    /// Social Security rate as a decimal (e.g., 0.062 for 6.2%)
+    pub social_security_rate: Decimal,
+    /// Earned income above which Social Security no longer applies
+    pub social_security_wage_base: Decimal,
+    /// Medicare rate as a decimal (e.g., 0.0145 for 1.45%)
+    pub medicare_rate: Decimal,
+    /// Additional Medicare surtax rate applied above the threshold
+    pub additional_medicare_rate: Decimal,
+    /// Earned income above which the additional Medicare surtax applies
+    pub additional_medicare_threshold: Decimal,
+}
+
+impl PayrollParameters {
+    /// Returns the payroll tax rate on the next dollar of earned income.
+    ///
+    /// # Arguments
+    ///
+    /// * `earned_income` - Earned income already received
+    ///
+    /// # Returns
+    ///
+    /// The sum of every payroll rate that still applies at this income level.
+    pub fn marginal_rate(&self, earned_income: Decimal) -> Decimal {
+        let mut rate = self.medicare_rate;
+
+        if earned_income < self.social_security_wage_base {
+            rate += self.social_security_rate;
+        }
+        if earned_income >= self.additional_medicare_threshold {
+            rate += self.additional_medicare_rate;
+        }
+
+        rate
+    }
+}
+
+/// Payroll tax owed, split by component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayrollTax {
+    /// Social Security tax on earnings up to the wage base
+    pub social_security: Decimal,
+    /// Medicare tax on all earnings
+    pub medicare: Decimal,
+    /// Additional Medicare surtax on earnings above the threshold
+    pub additional_medicare: Decimal,
+}
+
+impl PayrollTax {
+    /// Combined Social Security, Medicare and additional Medicare tax.
+    pub fn total_tax(&self) -> Decimal {
+        self.social_security + self.medicare + self.additional_medicare
+    }
+}
+
+/// Calculator for flat-rate payroll taxes.
+pub struct PayrollTaxCalculator;
+
+impl PayrollTaxCalculator {
+    /// Calculates self-employment tax on net self-employment income.
+    ///
+    /// Only [`SELF_EMPLOYMENT_EARNINGS_FACTOR`] of the income counts as
+    /// earnings. A self-employed person pays both the employee and employer
+    /// shares, so the Social Security and Medicare rates in `params` are
+    /// doubled; the additional Medicare surtax has no employer share and is
+    /// charged once.
+    ///
+    /// # Arguments
+    ///
+    /// * `self_employment_income` - Net profit from self-employment
+    /// * `params` - Employee-side rates, wage base and surtax threshold for the year
+    ///
+    /// # Returns
+    ///
+    /// The tax owed by component. Zero or negative income owes nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::calculators::payroll::PayrollTaxCalculator;
+    /// use tax_engine::PayrollParameters;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let params = PayrollParameters {
+    ///     social_security_rate: dec!(0.062),
+    ///     social_security_wage_base: dec!(168600),
+    ///     medicare_rate: dec!(0.0145),
+    ///     additional_medicare_rate: dec!(0.009),
+    ///     additional_medicare_threshold: dec!(200000),
+    /// };
+    ///
+    /// let tax = PayrollTaxCalculator::self_employment_tax(dec!(100000), &params);
+    /// assert_eq!(tax.total_tax(), dec!(14129.55));
+    /// ```
+    pub fn self_employment_tax(
+        self_employment_income: Decimal,
+        params: &PayrollParameters,
+    ) -> PayrollTax {
+        let earnings =
+            (self_employment_income * SELF_EMPLOYMENT_EARNINGS_FACTOR).max(Decimal::ZERO);
+        let surtax_earnings = (earnings - params.additional_medicare_threshold).max(Decimal::ZERO);
+
+        PayrollTax {
+            social_security: earnings.min(params.social_security_wage_base)
+                * params.social_security_rate
+                * Decimal::TWO,
+            medicare: earnings * params.medicare_rate * Decimal::TWO,
+            additional_medicare: surtax_earnings * params.additional_medicare_rate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters() -> PayrollParameters {
+        PayrollParameters {
+            social_security_rate: dec!(0.062),
+            social_security_wage_base: dec!(168600),
+            medicare_rate: dec!(0.0145),
+            additional_medicare_rate: dec!(0.009),
+            additional_medicare_threshold: dec!(200000),
+        }
+    }
+
+    #[test]
+    fn test_marginal_rate_by_income_level() {
+        let params = parameters();
+
+        assert_eq!(params.marginal_rate(dec!(50000)), dec!(0.0765));
+        assert_eq!(params.marginal_rate(dec!(168600)), dec!(0.0145));
+        assert_eq!(params.marginal_rate(dec!(250000)), dec!(0.0235));
+    }
+
+    #[test]
+    fn test_self_employment_tax_below_wage_base() {
+        let tax = PayrollTaxCalculator::self_employment_tax(dec!(100000), &parameters());
+
+        // 92,350 of earnings, all under the wage base
+        assert_eq!(tax.social_security, dec!(11451.40));
+        assert_eq!(tax.medicare, dec!(2678.15));
+        assert_eq!(tax.additional_medicare, dec!(0));
+        assert_eq!(tax.total_tax(), dec!(14129.55));
+    }
+
+    #[test]
+    fn test_self_employment_tax_at_wage_base() {
+        let params = PayrollParameters {
+            social_security_wage_base: dec!(92350),
+            ..parameters()
+        };
+
+        let at_base = PayrollTaxCalculator::self_employment_tax(dec!(100000), &params);
+        assert_eq!(at_base.social_security, dec!(11451.40));
+
+        // Further earnings only owe Medicare
+        let above = PayrollTaxCalculator::self_employment_tax(dec!(110000), &params);
+        assert_eq!(above.social_security, at_base.social_security);
+        assert_eq!(above.medicare - at_base.medicare, dec!(9235) * dec!(0.029));
+    }
+
+    #[test]
+    fn test_self_employment_tax_above_wage_base_and_surtax_threshold() {
+        let tax = PayrollTaxCalculator::self_employment_tax(dec!(300000), &parameters());
+
+        // 277,050 of earnings: Social Security stops at 168,600 and the surtax
+        // applies to the 77,050 above 200,000
+        assert_eq!(tax.social_security, dec!(20906.40));
+        assert_eq!(tax.medicare, dec!(8034.45));
+        assert_eq!(tax.additional_medicare, dec!(693.45));
+        assert_eq!(tax.total_tax(), dec!(29634.30));
+
+        let loss = PayrollTaxCalculator::self_employment_tax(dec!(-5000), &parameters());
+        assert_eq!(loss.total_tax(), dec!(0));
+    }
+}
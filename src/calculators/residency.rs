@@ -0,0 +1,174 @@
+//! Tax for taxpayers resident in more than one jurisdiction during a year.
+//!
+//! A taxpayer who moves mid-year owes each jurisdiction tax only on the
+//! income earned while resident there, so income is allocated between
+//! jurisdictions before any schedule is applied.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{Jurisdiction, TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+
+/// Income earned while resident in one jurisdiction, with that jurisdiction's schedule.
+#[derive(Debug, Clone)]
+pub struct IncomeAllocation<'a> {
+    /// The jurisdiction the income is allocated to
+    pub jurisdiction: Jurisdiction,
+    /// Gross income earned while resident there
+    pub income: Decimal,
+    /// The jurisdiction's tax schedule
+    pub schedule: &'a TaxSchedule,
+}
+
+/// Tax owed to one jurisdiction on its allocated income.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JurisdictionTax {
+    /// The jurisdiction owed the tax
+    pub jurisdiction: Jurisdiction,
+    /// Gross income allocated to the jurisdiction
+    pub income: Decimal,
+    /// Tax owed on that income
+    pub tax: Decimal,
+}
+
+/// Calculator for part-year residents of several jurisdictions.
+pub struct PartYearResidencyCalculator;
+
+impl PartYearResidencyCalculator {
+    /// Calculates each jurisdiction's tax on the income allocated to it.
+    ///
+    /// Each allocation is taxed on its own under its jurisdiction's schedule.
+    /// The entity's deductions are apportioned between allocations in
+    /// proportion to their share of the total allocated income. Each
+    /// allocation is taxed with the entity's filing status and currency.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The tax entity; its income is ignored in favor of the allocations
+    /// * `allocations` - Income earned in each jurisdiction, with its schedule
+    ///
+    /// # Returns
+    ///
+    /// The tax owed to each jurisdiction, in the order the allocations were given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if any allocation's schedule is for a
+    /// different tax year, filing status or currency than the entity.
+    pub fn calculate(
+        entity: &TaxEntity,
+        allocations: &[IncomeAllocation<'_>],
+    ) -> Result<Vec<JurisdictionTax>, TaxError> {
+        let total_income = allocations
+            .iter()
+            .fold(Decimal::ZERO, |acc, allocation| acc + allocation.income);
+        let total_deductions = entity.total_deductions();
+
+        allocations
+            .iter()
+            .map(|allocation| {
+                let deductions = if total_income > Decimal::ZERO {
+                    total_deductions * allocation.income / total_income
+                } else {
+                    Decimal::ZERO
+                };
+
+                // Keep the entity's filing status, currency and other
+                // attributes so the schedule's compatibility check sees them
+                let resident = TaxEntity {
+                    income: allocation.income - deductions,
+                    deductions: Vec::new(),
+                    deferred_income: Vec::new(),
+                    ..entity.clone()
+                };
+
+                Ok(JurisdictionTax {
+                    jurisdiction: allocation.jurisdiction.clone(),
+                    income: allocation.income,
+                    tax: IncomeTaxCalculator::calculate_tax(&resident, allocation.schedule)?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        CanadianProvince, Currency, DeductionType, FilingStatus, TaxEntityType, USState,
+    };
+    use crate::testing::flat_schedule;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_each_jurisdiction_taxes_only_its_allocation() {
+        let california = flat_schedule(2024, dec!(0.09));
+        let new_york = flat_schedule(2024, dec!(0.06));
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024);
+        entity.add_deduction(dec!(10000), DeductionType::Personal);
+
+        let taxes = PartYearResidencyCalculator::calculate(
+            &entity,
+            &[
+                IncomeAllocation {
+                    jurisdiction: Jurisdiction::USState(USState::California),
+                    income: dec!(30000),
+                    schedule: &california,
+                },
+                IncomeAllocation {
+                    jurisdiction: Jurisdiction::USState(USState::NewYork),
+                    income: dec!(70000),
+                    schedule: &new_york,
+                },
+            ],
+        )
+        .unwrap();
+
+        // Deductions split 30/70: California taxes 27,000 and New York 63,000
+        assert_eq!(
+            taxes[0].jurisdiction,
+            Jurisdiction::USState(USState::California)
+        );
+        assert_eq!(taxes[0].tax, dec!(2430));
+        assert_eq!(
+            taxes[1].jurisdiction,
+            Jurisdiction::USState(USState::NewYork)
+        );
+        assert_eq!(taxes[1].tax, dec!(3780));
+    }
+
+    #[test]
+    fn test_allocations_keep_filing_status_and_currency() {
+        let joint_schedule =
+            flat_schedule(2024, dec!(0.05)).with_filing_status(FilingStatus::MarriedFilingJointly);
+        let joint = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024)
+            .with_filing_status(FilingStatus::MarriedFilingJointly);
+
+        let taxes = PartYearResidencyCalculator::calculate(
+            &joint,
+            &[IncomeAllocation {
+                jurisdiction: Jurisdiction::USState(USState::Oregon),
+                income: dec!(40000),
+                schedule: &joint_schedule,
+            }],
+        )
+        .unwrap();
+        assert_eq!(taxes[0].tax, dec!(2000));
+
+        let ontario = flat_schedule(2024, dec!(0.10)).with_currency(Currency::CAD);
+        let mut canadian = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024);
+        canadian.currency = Currency::CAD;
+
+        let taxes = PartYearResidencyCalculator::calculate(
+            &canadian,
+            &[IncomeAllocation {
+                jurisdiction: Jurisdiction::CanadianProvince(CanadianProvince::Ontario),
+                income: dec!(60000),
+                schedule: &ontario,
+            }],
+        )
+        .unwrap();
+        assert_eq!(taxes[0].tax, dec!(6000));
+    }
+}
@@ -0,0 +1,116 @@
+//! Tax calculations for married couples filing separately.
+//!
+//! In community-property jurisdictions, income earned by either spouse during
+//! the marriage belongs equally to both, so each spouse reports half of the
+//! combined income on their separate return.
+
+use crate::calculators::IncomeTaxCalculator;
+use crate::errors::TaxError;
+use crate::models::{TaxEntity, TaxSchedule};
+use rust_decimal::Decimal;
+
+/// Calculator for spouses filing separate returns.
+pub struct MarriedFilingSeparatelyCalculator;
+
+impl MarriedFilingSeparatelyCalculator {
+    /// Calculates each spouse's tax when filing separately.
+    ///
+    /// When `community_property` is true, the spouses' gross incomes are pooled
+    /// and split 50/50 before each return is calculated. All income is treated
+    /// as community income; each spouse keeps their own deductions.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_spouse` - The first spouse's tax entity
+    /// * `second_spouse` - The second spouse's tax entity
+    /// * `schedule` - The married-filing-separately tax schedule
+    /// * `community_property` - Whether the couple lives in a community-property jurisdiction
+    ///
+    /// # Returns
+    ///
+    /// The tax owed by the first and second spouse, in that order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if either spouse's tax year doesn't
+    /// match the schedule's tax year.
+    pub fn calculate(
+        first_spouse: &TaxEntity,
+        second_spouse: &TaxEntity,
+        schedule: &TaxSchedule,
+        community_property: bool,
+    ) -> Result<(Decimal, Decimal), TaxError> {
+        if !community_property {
+            return Ok((
+                IncomeTaxCalculator::calculate_tax(first_spouse, schedule)?,
+                IncomeTaxCalculator::calculate_tax(second_spouse, schedule)?,
+            ));
+        }
+
+        let half_income = (first_spouse.income + second_spouse.income) / Decimal::TWO;
+
+        let mut first = first_spouse.clone();
+        first.income = half_income;
+        let mut second = second_spouse.clone();
+        second.income = half_income;
+
+        Ok((
+            IncomeTaxCalculator::calculate_tax(&first, schedule)?,
+            IncomeTaxCalculator::calculate_tax(&second, schedule)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TaxBracket, TaxEntityType};
+    use rust_decimal_macros::dec;
+
+    fn schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.30),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_community_property_splits_income() {
+        let earner = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        let spouse = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024);
+
+        let separate =
+            MarriedFilingSeparatelyCalculator::calculate(&earner, &spouse, &schedule(), false)
+                .unwrap();
+        assert_eq!(separate, (dec!(20000), dec!(0)));
+
+        let community =
+            MarriedFilingSeparatelyCalculator::calculate(&earner, &spouse, &schedule(), true)
+                .unwrap();
+        assert_eq!(community, (dec!(5000), dec!(5000)));
+    }
+
+    #[test]
+    fn test_community_property_keeps_own_deductions() {
+        let mut earner = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        earner.add_deduction(dec!(10000), crate::models::DeductionType::Personal);
+        let spouse = TaxEntity::new(TaxEntityType::Individual, dec!(20000), 2024);
+
+        let (first, second) =
+            MarriedFilingSeparatelyCalculator::calculate(&earner, &spouse, &schedule(), true)
+                .unwrap();
+        assert_eq!(first, dec!(4000));
+        assert_eq!(second, dec!(5000));
+    }
+}
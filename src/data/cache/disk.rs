@@ -0,0 +1,256 @@
+//! Disk-backed implementation of tax data caching.
+//!
+//! Stores each schedule as a JSON file in a cache directory so cached rates
+//! survive process restarts.
+
+use super::memory::CacheKey;
+use super::TaxDataCache;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, Jurisdiction, TaxEntityType, TaxSchedule};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Contents of a single cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct DiskEntry {
+    key: CacheKey,
+    schedule: TaxSchedule,
+    stored_at: SystemTime,
+}
+
+/// A cache that persists schedules to a directory with time-based expiration.
+///
+/// Each entry is written to its own file, named after its key. Files that are
+/// missing, unreadable, corrupt or older than the TTL are treated as misses.
+pub struct DiskCache {
+    directory: PathBuf,
+    ttl: Duration,
+}
+
+impl DiskCache {
+    /// Creates a new DiskCache storing entries in `directory`.
+    ///
+    /// The directory is created when the first entry is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - Where cache files are kept
+    /// * `ttl` - How long entries should remain valid in the cache
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::data::cache::disk::DiskCache;
+    /// use std::time::Duration;
+    ///
+    /// let cache = DiskCache::new("/var/cache/tax_engine", Duration::from_secs(86400));
+    /// ```
+    pub fn new(directory: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            directory: directory.into(),
+            ttl,
+        }
+    }
+
+    /// Returns the current time-to-live duration.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the directory cache files are stored in.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the path of the file holding the entry for `key`.
+    ///
+    /// The key's parts are joined into a name using only ASCII letters,
+    /// digits and underscores, so it is valid on every platform.
+    fn path_for(&self, key: &CacheKey) -> PathBuf {
+        let name = format!(
+            "{:?}_{:?}_{:?}_{}",
+            key.jurisdiction, key.entity_type, key.filing_status, key.tax_year
+        )
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+        self.directory.join(format!("{}.json", name))
+    }
+}
+
+#[async_trait]
+impl TaxDataCache for DiskCache {
+    async fn get(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Option<TaxSchedule> {
+        let key = CacheKey {
+            jurisdiction: jurisdiction.clone(),
+            entity_type: entity_type.clone(),
+            filing_status,
+            tax_year,
+        };
+
+        let contents = tokio::fs::read(self.path_for(&key)).await.ok()?;
+        let entry: DiskEntry = serde_json::from_slice(&contents).ok()?;
+
+        let fresh = entry.stored_at.elapsed().is_ok_and(|age| age < self.ttl);
+        (fresh && entry.key == key).then_some(entry.schedule)
+    }
+
+    async fn set(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+        schedule: TaxSchedule,
+    ) -> Result<(), TaxError> {
+        let key = CacheKey {
+            jurisdiction: jurisdiction.clone(),
+            entity_type: entity_type.clone(),
+            filing_status,
+            tax_year,
+        };
+        let path = self.path_for(&key);
+
+        let entry = DiskEntry {
+            key,
+            schedule: schedule.normalized(),
+            stored_at: SystemTime::now(),
+        };
+        let contents =
+            serde_json::to_vec(&entry).map_err(|e| TaxError::CacheError(e.to_string()))?;
+
+        // Write to a temporary file first so readers never see a partial entry
+        let staging = path.with_extension("json.tmp");
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|e| TaxError::CacheError(e.to_string()))?;
+        tokio::fs::write(&staging, contents)
+            .await
+            .map_err(|e| TaxError::CacheError(e.to_string()))?;
+        tokio::fs::rename(&staging, &path)
+            .await
+            .map_err(|e| TaxError::CacheError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Country, TaxBracket};
+    use rust_decimal_macros::dec;
+
+    /// Returns an empty directory for a test, removing any earlier contents.
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "tax_engine_disk_cache_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![TaxBracket {
+                lower_bound: dec!(0),
+                upper_bound: None,
+                rate: dec!(0.10),
+            }],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_entries_survive_a_new_instance() {
+        let directory = test_directory("persist");
+        let jurisdiction = Jurisdiction::Federal(Country::USA);
+        let entity_type = TaxEntityType::Individual;
+
+        DiskCache::new(&directory, Duration::from_secs(60))
+            .set(
+                &jurisdiction,
+                &entity_type,
+                FilingStatus::Single,
+                2024,
+                schedule(),
+            )
+            .await
+            .unwrap();
+
+        let reopened = DiskCache::new(&directory, Duration::from_secs(60));
+        let cached = reopened
+            .get(&jurisdiction, &entity_type, FilingStatus::Single, 2024)
+            .await
+            .unwrap();
+        assert_eq!(cached, schedule());
+
+        let other_status = reopened
+            .get(
+                &jurisdiction,
+                &entity_type,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await;
+        assert!(other_status.is_none());
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_corrupt_files_are_misses() {
+        let directory = test_directory("misses");
+        let jurisdiction = Jurisdiction::Federal(Country::USA);
+        let entity_type = TaxEntityType::Individual;
+        let cache = DiskCache::new(&directory, Duration::from_millis(100));
+
+        // A missing directory is a miss rather than an error
+        assert!(cache
+            .get(&jurisdiction, &entity_type, FilingStatus::Single, 2024)
+            .await
+            .is_none());
+
+        cache
+            .set(
+                &jurisdiction,
+                &entity_type,
+                FilingStatus::Single,
+                2024,
+                schedule(),
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(cache
+            .get(&jurisdiction, &entity_type, FilingStatus::Single, 2024)
+            .await
+            .is_none());
+
+        let corrupt = DiskCache::new(&directory, Duration::from_secs(60));
+        let key = CacheKey {
+            jurisdiction: jurisdiction.clone(),
+            entity_type: entity_type.clone(),
+            filing_status: FilingStatus::Single,
+            tax_year: 2024,
+        };
+        std::fs::write(corrupt.path_for(&key), "{ not json").unwrap();
+        assert!(corrupt
+            .get(&jurisdiction, &entity_type, FilingStatus::Single, 2024)
+            .await
+            .is_none());
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
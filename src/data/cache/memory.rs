@@ -4,32 +4,79 @@
 
 use super::TaxDataCache;
 use crate::errors::TaxError;
-use crate::models::{Jurisdiction, TaxEntityType, TaxSchedule};
+use crate::models::{FilingStatus, Jurisdiction, TaxEntityType, TaxSchedule};
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-/// Key for cache entries combining jurisdiction, entity type, and tax year.
-#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+/// Key for cache entries combining jurisdiction, entity type, filing status, and tax year.
+#[derive(Clone, Hash, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct CacheKey {
     pub jurisdiction: Jurisdiction,
     pub entity_type: TaxEntityType,
+    pub filing_status: FilingStatus,
     pub tax_year: u16,
 }
 
+/// How a capacity-limited cache chooses which entry to evict.
+///
+/// Expired entries are always evicted first; the policy decides between
+/// entries that are still valid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evict the entry stored earliest, which is the next to expire
+    #[default]
+    Ttl,
+    /// Evict the entry read or written least recently
+    Lru,
+    /// Evict the entry read least often, breaking ties by recency
+    Lfu,
+}
+
 /// Cache entry containing the tax schedule and its timestamp.
 #[derive(Clone, Debug)]
 pub struct CacheEntry {
     pub schedule: TaxSchedule,
     pub timestamp: Instant,
+    /// Value of the cache's access counter when the entry was last read or written
+    pub last_access: u64,
+    /// Number of reads since the entry was stored
+    pub access_count: u64,
 }
 
 /// An in-memory cache implementation with time-based expiration.
+///
+/// The cache is unbounded unless created with [`MemoryCache::with_policy`],
+/// in which case an entry is evicted according to its [`EvictionPolicy`]
+/// whenever a new key is stored at capacity.
+///
+/// # Thread safety
+///
+/// `MemoryCache` is `Send + Sync` and can be shared between tasks, typically
+/// behind an `Arc`. All entries sit behind a single `tokio::sync::RwLock`:
+///
+/// - `set` takes the write lock for the whole check-evict-insert step, so the
+///   capacity limit is never exceeded and an evicted entry is never one being
+///   inserted concurrently.
+/// - `get` takes the read lock under `EvictionPolicy::Ttl`, so reads proceed
+///   in parallel. Under `Lru` and `Lfu` it takes the write lock, since every
+///   read updates the entry's access statistics.
+/// - Reads clone the schedule before releasing the lock, so callers never
+///   hold a reference into the cache and never observe a partial write.
+///
+/// No lock is held across an `.await` outside the cache's own methods, and
+/// each method takes the lock at most once, so the cache cannot deadlock
+/// against itself.
 pub struct MemoryCache {
     data: Arc<RwLock<HashMap<CacheKey, CacheEntry>>>,
     ttl: Duration,
+    max_entries: Option<usize>,
+    policy: EvictionPolicy,
+    access_clock: AtomicU64,
 }
 
 impl MemoryCache {
@@ -51,13 +98,127 @@ impl MemoryCache {
         Self {
             data: Arc::new(RwLock::new(HashMap::new())),
             ttl,
+            max_entries: None,
+            policy: EvictionPolicy::default(),
+            access_clock: AtomicU64::new(0),
+        }
+    }
+
+    /// Creates a MemoryCache holding at most `max_entries` entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long entries should remain valid in the cache
+    /// * `max_entries` - Maximum number of entries, at least 1
+    /// * `policy` - How to choose the entry evicted when storing at capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::data::cache::memory::{EvictionPolicy, MemoryCache};
+    /// use std::time::Duration;
+    ///
+    /// let cache = MemoryCache::with_policy(Duration::from_secs(3600), 100, EvictionPolicy::Lfu);
+    /// ```
+    pub fn with_policy(ttl: Duration, max_entries: usize, policy: EvictionPolicy) -> Self {
+        Self {
+            max_entries: Some(max_entries.max(1)),
+            policy,
+            ..Self::new(ttl)
         }
     }
 
+    /// Creates a MemoryCache holding at most `max_entries` entries, evicting
+    /// the least recently used entry when storing at capacity.
+    ///
+    /// Shorthand for [`MemoryCache::with_policy`] with `EvictionPolicy::Lru`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long entries should remain valid in the cache
+    /// * `max_entries` - Maximum number of entries, at least 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::data::cache::memory::MemoryCache;
+    /// use std::time::Duration;
+    ///
+    /// let cache = MemoryCache::with_capacity(Duration::from_secs(3600), 500);
+    /// ```
+    pub fn with_capacity(ttl: Duration, max_entries: usize) -> Self {
+        Self::with_policy(ttl, max_entries, EvictionPolicy::Lru)
+    }
+
     /// Returns the current time-to-live duration.
     pub fn ttl(&self) -> Duration {
         self.ttl
     }
+
+    /// Returns the eviction policy used at capacity.
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Returns a snapshot of all unexpired entries.
+    ///
+    /// The schedules are cloned, so the cache is not locked while callers
+    /// work with the result.
+    pub async fn entries(&self) -> Vec<(CacheKey, TaxSchedule)> {
+        let cache = self.data.read().await;
+        cache
+            .iter()
+            .filter(|(_, entry)| entry.timestamp.elapsed() < self.ttl)
+            .map(|(key, entry)| (key.clone(), entry.schedule.clone()))
+            .collect()
+    }
+
+    /// Removes every expired entry and returns how many were removed.
+    ///
+    /// Expired entries are otherwise only skipped on read, so a long-running
+    /// process can call this periodically to release their memory.
+    pub async fn purge_expired(&self) -> usize {
+        let mut cache = self.data.write().await;
+        let before = cache.len();
+        cache.retain(|_, entry| entry.timestamp.elapsed() < self.ttl);
+        before - cache.len()
+    }
+
+    /// Returns the number of entries held, including expired entries not yet
+    /// purged.
+    pub async fn len(&self) -> usize {
+        self.data.read().await.len()
+    }
+
+    /// Returns true if the cache holds no entries, expired or not.
+    pub async fn is_empty(&self) -> bool {
+        self.data.read().await.is_empty()
+    }
+
+    /// Advances the access counter and returns its new value.
+    fn tick(&self) -> u64 {
+        self.access_clock.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Returns the key that should be evicted to make room for a new entry.
+    fn eviction_candidate(&self, cache: &HashMap<CacheKey, CacheEntry>) -> Option<CacheKey> {
+        if let Some((key, _)) = cache
+            .iter()
+            .find(|(_, entry)| entry.timestamp.elapsed() >= self.ttl)
+        {
+            return Some(key.clone());
+        }
+
+        let entries = cache.iter();
+        let victim = match self.policy {
+            EvictionPolicy::Ttl => entries.min_by_key(|(_, entry)| entry.timestamp),
+            EvictionPolicy::Lru => entries.min_by_key(|(_, entry)| entry.last_access),
+            EvictionPolicy::Lfu => {
+                entries.min_by_key(|(_, entry)| (entry.access_count, entry.last_access))
+            }
+        };
+        victim.map(|(key, _)| key.clone())
+    }
 }
 
 #[async_trait]
@@ -66,42 +227,67 @@ impl TaxDataCache for MemoryCache {
         &self,
         jurisdiction: &Jurisdiction,
         entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
         tax_year: u16,
     ) -> Option<TaxSchedule> {
         let key = CacheKey {
             jurisdiction: jurisdiction.clone(),
             entity_type: entity_type.clone(),
+            filing_status,
             tax_year,
         };
 
-        let cache = self.data.read().await;
-        if let Some(entry) = cache.get(&key) {
-            if entry.timestamp.elapsed() < self.ttl {
-                return Some(entry.schedule.clone());
-            }
+        // Only LRU and LFU need to record the read, which takes the write lock
+        if self.policy == EvictionPolicy::Ttl {
+            let cache = self.data.read().await;
+            return cache
+                .get(&key)
+                .filter(|entry| entry.timestamp.elapsed() < self.ttl)
+                .map(|entry| entry.schedule.clone());
         }
-        None
+
+        let mut cache = self.data.write().await;
+        let entry = cache
+            .get_mut(&key)
+            .filter(|entry| entry.timestamp.elapsed() < self.ttl)?;
+        entry.last_access = self.tick();
+        entry.access_count += 1;
+        Some(entry.schedule.clone())
     }
 
     async fn set(
         &self,
         jurisdiction: &Jurisdiction,
         entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
         tax_year: u16,
         schedule: TaxSchedule,
     ) -> Result<(), TaxError> {
         let key = CacheKey {
             jurisdiction: jurisdiction.clone(),
             entity_type: entity_type.clone(),
+            filing_status,
             tax_year,
         };
 
+        let schedule = schedule.normalized();
+
+        let mut cache = self.data.write().await;
+        // Stamp the entry while holding the lock, so access order matches the
+        // order in which writers and recording readers acquired it
         let entry = CacheEntry {
             schedule,
             timestamp: Instant::now(),
+            last_access: self.tick(),
+            access_count: 0,
         };
-
-        let mut cache = self.data.write().await;
+        if let Some(max_entries) = self.max_entries {
+            if !cache.contains_key(&key) && cache.len() >= max_entries {
+                if let Some(victim) = self.eviction_candidate(&cache) {
+                    cache.remove(&victim);
+                }
+            }
+        }
         cache.insert(key, entry);
         Ok(())
     }
@@ -130,16 +316,58 @@ mod tests {
 
         // Test set
         let set_result = cache
-            .set(&jurisdiction, &entity_type, tax_year, schedule.clone())
+            .set(
+                &jurisdiction,
+                &entity_type,
+                FilingStatus::Single,
+                tax_year,
+                schedule.clone(),
+            )
             .await;
         assert!(set_result.is_ok());
 
         // Test get
-        let get_result = cache.get(&jurisdiction, &entity_type, tax_year).await;
+        let get_result = cache
+            .get(&jurisdiction, &entity_type, FilingStatus::Single, tax_year)
+            .await;
         assert!(get_result.is_some());
         assert_eq!(get_result.unwrap().tax_year, tax_year);
     }
 
+    #[tokio::test]
+    async fn test_entries_skips_expired() {
+        let cache = MemoryCache::new(Duration::from_millis(100));
+        let entity_type = TaxEntityType::Individual;
+        let schedule = TaxSchedule::new(2024, vec![]);
+
+        let _ = cache
+            .set(
+                &Jurisdiction::Federal(crate::models::Country::USA),
+                &entity_type,
+                FilingStatus::Single,
+                2024,
+                schedule.clone(),
+            )
+            .await;
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        let _ = cache
+            .set(
+                &Jurisdiction::Federal(crate::models::Country::Canada),
+                &entity_type,
+                FilingStatus::Single,
+                2024,
+                schedule,
+            )
+            .await;
+
+        let entries = cache.entries().await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].0.jurisdiction,
+            Jurisdiction::Federal(crate::models::Country::Canada)
+        );
+    }
+
     #[tokio::test]
     async fn test_cache_expiration() {
         let cache = MemoryCache::new(Duration::from_millis(100));
@@ -158,14 +386,207 @@ mod tests {
 
         // Set the value
         let _ = cache
-            .set(&jurisdiction, &entity_type, tax_year, schedule)
+            .set(
+                &jurisdiction,
+                &entity_type,
+                FilingStatus::Single,
+                tax_year,
+                schedule,
+            )
             .await;
 
         // Wait for expiration
         tokio::time::sleep(Duration::from_millis(150)).await;
 
         // Should be expired
-        let result = cache.get(&jurisdiction, &entity_type, tax_year).await;
+        let result = cache
+            .get(&jurisdiction, &entity_type, FilingStatus::Single, tax_year)
+            .await;
         assert!(result.is_none());
     }
+
+    /// Fills a two-entry cache, reads `first` twice and `second` once, then
+    /// stores a third entry and returns which of the first two survived.
+    async fn survivors_after_eviction(policy: EvictionPolicy) -> (bool, bool) {
+        let cache = MemoryCache::with_policy(Duration::from_secs(60), 2, policy);
+        let entity_type = TaxEntityType::Individual;
+        let schedule = TaxSchedule::new(2024, vec![]);
+        let first = Jurisdiction::Federal(crate::models::Country::USA);
+        let second = Jurisdiction::Federal(crate::models::Country::Canada);
+        let third = Jurisdiction::USState(crate::models::USState::California);
+
+        for jurisdiction in [&first, &second] {
+            cache
+                .set(
+                    jurisdiction,
+                    &entity_type,
+                    FilingStatus::Single,
+                    2024,
+                    schedule.clone(),
+                )
+                .await
+                .unwrap();
+        }
+        // `first` is read most often, `second` most recently
+        for jurisdiction in [&first, &first, &second] {
+            cache
+                .get(jurisdiction, &entity_type, FilingStatus::Single, 2024)
+                .await
+                .unwrap();
+        }
+        cache
+            .set(&third, &entity_type, FilingStatus::Single, 2024, schedule)
+            .await
+            .unwrap();
+
+        let entries = cache.entries().await;
+        assert_eq!(entries.len(), 2);
+        let survived = |jurisdiction: &Jurisdiction| {
+            entries
+                .iter()
+                .any(|(key, _)| key.jurisdiction == *jurisdiction)
+        };
+        (survived(&first), survived(&second))
+    }
+
+    #[tokio::test]
+    async fn test_lru_and_lfu_evict_different_entries() {
+        assert_eq!(
+            survivors_after_eviction(EvictionPolicy::Lru).await,
+            (false, true)
+        );
+        assert_eq!(
+            survivors_after_eviction(EvictionPolicy::Lfu).await,
+            (true, false)
+        );
+        // TTL ignores reads and evicts the entry stored first
+        assert_eq!(
+            survivors_after_eviction(EvictionPolicy::Ttl).await,
+            (false, true)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired() {
+        let cache = MemoryCache::new(Duration::from_millis(100));
+        let jurisdiction = Jurisdiction::Federal(crate::models::Country::USA);
+        let entity_type = TaxEntityType::Individual;
+
+        for year in [2023, 2024] {
+            cache
+                .set(
+                    &jurisdiction,
+                    &entity_type,
+                    FilingStatus::Single,
+                    year,
+                    TaxSchedule::new(year, vec![]),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(cache.len().await, 2);
+        assert_eq!(cache.purge_expired().await, 0);
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        // Expired entries are still held until purged
+        assert_eq!(cache.len().await, 2);
+        assert_eq!(cache.purge_expired().await, 2);
+        assert!(cache.is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn test_capacity_evicts_least_recently_used() {
+        let cache = MemoryCache::with_capacity(Duration::from_secs(60), 3);
+        let jurisdiction = Jurisdiction::Federal(crate::models::Country::USA);
+        let entity_type = TaxEntityType::Individual;
+        let set = |year: u16| {
+            cache.set(
+                &jurisdiction,
+                &entity_type,
+                FilingStatus::Single,
+                year,
+                TaxSchedule::new(year, vec![]),
+            )
+        };
+        let get = |year: u16| cache.get(&jurisdiction, &entity_type, FilingStatus::Single, year);
+
+        for year in [2021, 2022, 2023] {
+            set(year).await.unwrap();
+        }
+        // 2021 was stored first but read since, leaving 2022 least recently used
+        assert!(get(2021).await.is_some());
+        set(2024).await.unwrap();
+        set(2025).await.unwrap();
+
+        assert!(get(2022).await.is_none());
+        assert!(get(2023).await.is_none());
+        assert!(get(2021).await.is_some());
+        assert!(get(2024).await.is_some());
+        assert!(get(2025).await.is_some());
+        assert_eq!(cache.entries().await.len(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_readers_and_writers() {
+        let years: Vec<u16> = (2016..2028).collect();
+        for (policy, max_entries) in [
+            (EvictionPolicy::Ttl, None),
+            (EvictionPolicy::Lru, Some(5)),
+            (EvictionPolicy::Lfu, Some(5)),
+        ] {
+            let cache = Arc::new(match max_entries {
+                Some(max_entries) => {
+                    MemoryCache::with_policy(Duration::from_secs(60), max_entries, policy)
+                }
+                None => MemoryCache::new(Duration::from_secs(60)),
+            });
+
+            let tasks: Vec<_> = (0..64)
+                .map(|task| {
+                    let cache = Arc::clone(&cache);
+                    let years = years.clone();
+                    tokio::spawn(async move {
+                        let jurisdiction = Jurisdiction::Federal(crate::models::Country::USA);
+                        let entity_type = TaxEntityType::Individual;
+                        for step in 0..200 {
+                            let year = years[(task * 7 + step) % years.len()];
+                            if step % 3 == 0 {
+                                cache
+                                    .set(
+                                        &jurisdiction,
+                                        &entity_type,
+                                        FilingStatus::Single,
+                                        year,
+                                        TaxSchedule::new(year, vec![]),
+                                    )
+                                    .await
+                                    .unwrap();
+                            } else if let Some(schedule) = cache
+                                .get(&jurisdiction, &entity_type, FilingStatus::Single, year)
+                                .await
+                            {
+                                // A read never returns another key's schedule
+                                assert_eq!(schedule.tax_year, year);
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            // Every task must finish, without panicking, well within the timeout
+            let all_done =
+                tokio::time::timeout(Duration::from_secs(30), futures::future::join_all(tasks))
+                    .await
+                    .expect("cache operations deadlocked");
+            for result in all_done {
+                result.expect("cache task panicked");
+            }
+
+            let entries = cache.entries().await;
+            assert_eq!(entries.len(), max_entries.unwrap_or(years.len()));
+            for (key, schedule) in entries {
+                assert_eq!(key.tax_year, schedule.tax_year);
+            }
+        }
+    }
 }
@@ -3,9 +3,13 @@
 //! This module provides traits and implementations for caching tax schedules
 //! to reduce network calls and improve performance.
 
+use crate::calculators::IncomeTaxCalculator;
 use crate::errors::TaxError;
-use crate::models::{Jurisdiction, TaxEntityType, TaxSchedule};
+use crate::models::{FilingStatus, Jurisdiction, TaxEntity, TaxEntityType, TaxSchedule};
 use async_trait::async_trait;
+use memory::{CacheKey, MemoryCache};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 /// Defines the interface for tax data caching implementations.
 #[async_trait]
@@ -16,6 +20,7 @@ pub trait TaxDataCache: Send + Sync {
     ///
     /// * `jurisdiction` - The tax jurisdiction
     /// * `entity_type` - The type of tax entity
+    /// * `filing_status` - The filing status the schedule applies to
     /// * `tax_year` - The tax year
     ///
     /// # Returns
@@ -26,6 +31,7 @@ pub trait TaxDataCache: Send + Sync {
         &self,
         jurisdiction: &Jurisdiction,
         entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
         tax_year: u16,
     ) -> Option<TaxSchedule>;
 
@@ -35,6 +41,7 @@ pub trait TaxDataCache: Send + Sync {
     ///
     /// * `jurisdiction` - The tax jurisdiction
     /// * `entity_type` - The type of tax entity
+    /// * `filing_status` - The filing status the schedule applies to
     /// * `tax_year` - The tax year
     /// * `schedule` - The tax schedule to cache
     ///
@@ -46,9 +53,98 @@ pub trait TaxDataCache: Send + Sync {
         &self,
         jurisdiction: &Jurisdiction,
         entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
         tax_year: u16,
         schedule: TaxSchedule,
     ) -> Result<(), TaxError>;
 }
 
+/// Recomputes tax for an entity against every schedule held in a cache.
+///
+/// Useful after calculation rules change, to refresh stored results without
+/// re-fetching any rates. Each unexpired cached schedule is used as-is, so
+/// schedules for a different tax year than the entity's yield
+/// `TaxError::Incompatible` in their slot of the result.
+///
+/// # Arguments
+///
+/// * `cache` - The cache whose schedules should be replayed
+/// * `entity` - The tax entity to calculate tax for
+///
+/// # Returns
+///
+/// The calculation result for each cached schedule, keyed by its cache key.
+pub async fn recompute_cached(
+    cache: &MemoryCache,
+    entity: &TaxEntity,
+) -> HashMap<CacheKey, Result<Decimal, TaxError>> {
+    cache
+        .entries()
+        .await
+        .into_iter()
+        .map(|(key, schedule)| {
+            let result = IncomeTaxCalculator::calculate_tax(entity, &schedule);
+            (key, result)
+        })
+        .collect()
+}
+
+pub mod disk;
 pub mod memory;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Country, USState};
+    use crate::testing::flat_schedule;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_recompute_against_cached_schedules() {
+        let cache = MemoryCache::new(Duration::from_secs(60));
+        let entity_type = TaxEntityType::Individual;
+        let federal = Jurisdiction::Federal(Country::USA);
+        let state = Jurisdiction::USState(USState::California);
+
+        cache
+            .set(
+                &federal,
+                &entity_type,
+                FilingStatus::Single,
+                2024,
+                flat_schedule(2024, dec!(0.20)),
+            )
+            .await
+            .unwrap();
+        cache
+            .set(
+                &state,
+                &entity_type,
+                FilingStatus::Single,
+                2024,
+                flat_schedule(2024, dec!(0.05)),
+            )
+            .await
+            .unwrap();
+
+        let entity = TaxEntity::new(entity_type.clone(), dec!(50000), 2024);
+        let results = recompute_cached(&cache, &entity).await;
+
+        assert_eq!(results.len(), 2);
+        let federal_key = CacheKey {
+            jurisdiction: federal,
+            entity_type: entity_type.clone(),
+            filing_status: FilingStatus::Single,
+            tax_year: 2024,
+        };
+        let state_key = CacheKey {
+            jurisdiction: state,
+            entity_type,
+            filing_status: FilingStatus::Single,
+            tax_year: 2024,
+        };
+        assert_eq!(*results[&federal_key].as_ref().unwrap(), dec!(10000));
+        assert_eq!(*results[&state_key].as_ref().unwrap(), dec!(2500));
+    }
+}
@@ -4,4 +4,5 @@
 //! from various sources.
 
 pub mod cache;
+pub mod provider;
 pub mod scrapers;
@@ -0,0 +1,179 @@
+//! High-level access to tax schedules through a cache.
+//!
+//! Pairs a scraper with a cache so callers can ask for a schedule without
+//! checking the cache and storing fetched rates themselves.
+
+use crate::data::cache::TaxDataCache;
+use crate::data::scrapers::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, Jurisdiction, TaxEntityType, TaxSchedule};
+
+/// Serves tax schedules from a cache, scraping them on a miss.
+pub struct TaxDataProvider {
+    scraper: Box<dyn TaxRateScraper>,
+    cache: Box<dyn TaxDataCache>,
+}
+
+impl TaxDataProvider {
+    /// Creates a provider that fetches with `scraper` and stores in `cache`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::data::provider::TaxDataProvider;
+    /// use tax_engine::{MemoryCache, USFederalScraper};
+    /// use std::time::Duration;
+    ///
+    /// let provider = TaxDataProvider::new(
+    ///     USFederalScraper::new(),
+    ///     MemoryCache::new(Duration::from_secs(3600)),
+    /// );
+    /// ```
+    pub fn new(scraper: impl TaxRateScraper + 'static, cache: impl TaxDataCache + 'static) -> Self {
+        Self {
+            scraper: Box::new(scraper),
+            cache: Box::new(cache),
+        }
+    }
+
+    /// Returns the schedule for a jurisdiction, entity type, filing status
+    /// and year.
+    ///
+    /// The cache is checked first. On a miss the schedule is fetched with
+    /// the scraper and stored in the cache before being returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - The tax jurisdiction
+    /// * `entity_type` - The type of tax entity
+    /// * `filing_status` - The filing status the schedule applies to
+    /// * `tax_year` - The tax year
+    ///
+    /// # Errors
+    ///
+    /// Returns the scraper's error if the schedule is not cached and cannot
+    /// be fetched, or the cache's error if the fetched schedule cannot be
+    /// stored. Failed fetches are not cached.
+    pub async fn get_schedule(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        if let Some(schedule) = self
+            .cache
+            .get(jurisdiction, entity_type, filing_status, tax_year)
+            .await
+        {
+            return Ok(schedule);
+        }
+
+        let schedule = self
+            .scraper
+            .fetch_rates(jurisdiction, entity_type, filing_status, tax_year)
+            .await?;
+        self.cache
+            .set(
+                jurisdiction,
+                entity_type,
+                filing_status,
+                tax_year,
+                schedule.clone(),
+            )
+            .await?;
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::cache::memory::MemoryCache;
+    use crate::models::{Country, TaxBracket};
+    use crate::testing::MockScraper;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Cache handle the test keeps a second reference to, for inspection.
+    struct SharedCache(Arc<MemoryCache>);
+
+    #[async_trait]
+    impl TaxDataCache for SharedCache {
+        async fn get(
+            &self,
+            jurisdiction: &Jurisdiction,
+            entity_type: &TaxEntityType,
+            filing_status: FilingStatus,
+            tax_year: u16,
+        ) -> Option<TaxSchedule> {
+            self.0
+                .get(jurisdiction, entity_type, filing_status, tax_year)
+                .await
+        }
+
+        async fn set(
+            &self,
+            jurisdiction: &Jurisdiction,
+            entity_type: &TaxEntityType,
+            filing_status: FilingStatus,
+            tax_year: u16,
+            schedule: TaxSchedule,
+        ) -> Result<(), TaxError> {
+            self.0
+                .set(jurisdiction, entity_type, filing_status, tax_year, schedule)
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_second_request_is_served_from_cache() {
+        let usa = Jurisdiction::Federal(Country::USA);
+        let individual = TaxEntityType::Individual;
+        let scraper = MockScraper::default().with_schedule(
+            usa.clone(),
+            individual.clone(),
+            FilingStatus::Single,
+            TaxSchedule::new(
+                2024,
+                vec![TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: None,
+                    rate: dec!(0.10),
+                }],
+            ),
+        );
+        let cache = Arc::new(MemoryCache::new(Duration::from_secs(60)));
+        let provider = TaxDataProvider::new(scraper.clone(), SharedCache(Arc::clone(&cache)));
+
+        let first = provider
+            .get_schedule(&usa, &individual, FilingStatus::Single, 2024)
+            .await
+            .unwrap();
+        assert_eq!(scraper.fetch_count(), 1);
+        assert_eq!(
+            cache
+                .get(&usa, &individual, FilingStatus::Single, 2024)
+                .await,
+            Some(first.clone())
+        );
+
+        let second = provider
+            .get_schedule(&usa, &individual, FilingStatus::Single, 2024)
+            .await
+            .unwrap();
+        assert_eq!(second, first);
+        assert_eq!(scraper.fetch_count(), 1);
+
+        // Failures are not cached, so each attempt reaches the scraper
+        for _ in 0..2 {
+            let missing = provider
+                .get_schedule(&usa, &individual, FilingStatus::Single, 1990)
+                .await;
+            assert!(matches!(missing, Err(TaxError::RateNotAvailable(1990))));
+        }
+        assert_eq!(scraper.fetch_count(), 3);
+    }
+}
@@ -0,0 +1,316 @@
+//! Canada Federal tax rate scraping implementation.
+//!
+//! Provides functionality to fetch and parse Canadian federal income tax rates
+//! from the Canada Revenue Agency (CRA) website, which lists each year's
+//! brackets as sentences such as "20.5% on the portion of taxable income over
+//! $55,867 up to $111,733".
+
+use super::us_federal::{read_body_limited, DEFAULT_MAX_RESPONSE_BYTES};
+use super::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{
+    Country, Currency, FilingStatus, Jurisdiction, TaxBracket, TaxEntityType, TaxSchedule,
+};
+use async_trait::async_trait;
+use regex::Regex;
+use rust_decimal::Decimal;
+use scraper::{Html, Selector};
+
+/// Default base URL of the Government of Canada website.
+pub const DEFAULT_CRA_BASE_URL: &str = "https://www.canada.ca";
+
+/// Path of the CRA page listing current and previous years' income tax rates.
+const CRA_RATES_PATH: &str = "/en/revenue-agency/services/tax/individuals/frequently-asked-questions-individuals/canadian-income-tax-rates-individuals-current-previous-years.html";
+
+/// Scraper implementation for Canadian federal tax rates.
+pub struct CanadaFederalScraper {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl CanadaFederalScraper {
+    /// Creates a new CanadaFederalScraper instance with a configured HTTP client.
+    ///
+    /// The client is configured with a 10-second timeout, falling back to the
+    /// default client if custom configuration fails.
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            base_url: DEFAULT_CRA_BASE_URL.to_string(),
+        }
+    }
+
+    /// Sets the base URL that the CRA page path is resolved against.
+    ///
+    /// Useful for pointing the scraper at a mirror or a local test server.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Scheme and host to use instead of `https://www.canada.ca`
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Fetches the CRA income tax rates page.
+    async fn fetch_rates_page(&self) -> Result<String, TaxError> {
+        let url = format!("{}{}", self.base_url, CRA_RATES_PATH);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| TaxError::NetworkError(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(TaxError::FetchError(format!(
+                "Unexpected status {} from {}",
+                response.status(),
+                url
+            )));
+        }
+
+        read_body_limited(response, DEFAULT_MAX_RESPONSE_BYTES).await
+    }
+
+    /// Parses one year's federal brackets from the CRA rates page.
+    ///
+    /// Only text following the heading "Federal tax rates for {year}" and
+    /// before the next heading is considered, since the page also lists
+    /// provincial rates and other years.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The HTML content from the CRA website
+    /// * `year` - The tax year whose section should be parsed
+    ///
+    /// # Returns
+    ///
+    /// The parsed brackets sorted by lower bound, `TaxError::RateNotAvailable`
+    /// if the page has no section for the year, or `TaxError::ParseError` if
+    /// the section contains no brackets.
+    fn parse_tax_brackets(&self, content: &str, year: u16) -> Result<Vec<TaxBracket>, TaxError> {
+        let document = Html::parse_document(content);
+        let heading = format!("federal tax rates for {}", year);
+
+        let mut found_section = false;
+        let mut in_section = false;
+        let mut brackets = Vec::new();
+        for element in document.select(&Selector::parse("h2,h3,h4,p,li,td").unwrap()) {
+            let text = element.text().collect::<String>().to_lowercase();
+
+            if matches!(element.value().name(), "h2" | "h3" | "h4") {
+                in_section = text.contains(&heading);
+                found_section |= in_section;
+                continue;
+            }
+
+            if in_section {
+                brackets.extend(self.parse_rate_text(&text));
+            }
+        }
+
+        if !found_section {
+            return Err(TaxError::RateNotAvailable(year));
+        }
+        if brackets.is_empty() {
+            return Err(TaxError::ParseError(
+                "Could not find tax bracket information".to_string(),
+            ));
+        }
+
+        brackets.sort_by_key(|b| b.lower_bound);
+        // Text in nested elements (a paragraph inside a table cell) is seen twice
+        brackets.dedup();
+        Ok(brackets)
+    }
+
+    /// Parses a text fragment containing a CRA bracket definition.
+    ///
+    /// Handles patterns like "15% on the first $55,867", "20.5% on the portion
+    /// of taxable income over $55,867 up to $111,733" and "33% of taxable
+    /// income over $246,752".
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The lowercased text fragment to parse
+    ///
+    /// # Returns
+    ///
+    /// An Option containing a TaxBracket if the text matches one of the patterns
+    fn parse_rate_text(&self, text: &str) -> Option<TaxBracket> {
+        let first = Regex::new(r"([0-9.]+)%\s+on\s+the\s+first\s+\$([0-9,]+)").ok()?;
+        if let Some(caps) = first.captures(text) {
+            return Some(TaxBracket {
+                lower_bound: Decimal::ZERO,
+                upper_bound: Some(self.extract_number(caps.get(2)?.as_str())?),
+                rate: self.extract_percent(caps.get(1)?.as_str())?,
+            });
+        }
+
+        let over =
+            Regex::new(r"([0-9.]+)%[^%$]*?over\s+\$([0-9,]+)(?:\s+up\s+to\s+\$([0-9,]+))?").ok()?;
+        let caps = over.captures(text)?;
+        let upper_bound = match caps.get(3) {
+            Some(upper) => Some(self.extract_number(upper.as_str())?),
+            None => None,
+        };
+        Some(TaxBracket {
+            lower_bound: self.extract_number(caps.get(2)?.as_str())?,
+            upper_bound,
+            rate: self.extract_percent(caps.get(1)?.as_str())?,
+        })
+    }
+
+    /// Converts a percentage such as "20.5" into a decimal rate.
+    fn extract_percent(&self, s: &str) -> Option<Decimal> {
+        Decimal::from_str_exact(s.trim_end_matches('.'))
+            .ok()
+            .map(|percent| percent / Decimal::ONE_HUNDRED)
+    }
+
+    /// Extracts a decimal number from a dollar amount such as "55,867".
+    fn extract_number(&self, s: &str) -> Option<Decimal> {
+        Decimal::from_str_exact(&s.trim().replace([',', ' '], "")).ok()
+    }
+}
+
+impl Default for CanadaFederalScraper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TaxRateScraper for CanadaFederalScraper {
+    /// Fetches and parses Canadian federal tax rates for a given year.
+    ///
+    /// Canada taxes individuals separately rather than by household, so the
+    /// same brackets apply whatever the filing status, and the returned
+    /// schedule has no filing status.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - Must be Federal(Canada)
+    /// * `entity_type` - Must be Individual
+    /// * `filing_status` - Ignored
+    /// * `tax_year` - The tax year to fetch rates for
+    ///
+    /// # Returns
+    ///
+    /// A TaxSchedule in Canadian dollars containing the parsed brackets, or an
+    /// error if:
+    /// - The jurisdiction/entity type combination is not supported
+    /// - The CRA website cannot be accessed
+    /// - The page has no rates for the specified year
+    /// - The tax bracket information cannot be parsed or is inconsistent
+    async fn fetch_rates(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        _filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        match (jurisdiction, entity_type) {
+            (Jurisdiction::Federal(Country::Canada), TaxEntityType::Individual) => {
+                let content = self.fetch_rates_page().await?;
+                let brackets = self.parse_tax_brackets(&content, tax_year)?;
+
+                let schedule = TaxSchedule::new_validated(tax_year, brackets).map_err(|e| {
+                    TaxError::ParseError(format!("Parsed brackets are inconsistent: {}", e))
+                })?;
+                Ok(schedule.with_currency(Currency::CAD).normalized())
+            }
+            _ => Err(TaxError::UnsupportedJurisdiction),
+        }
+    }
+
+    /// Checks if this scraper supports the given jurisdiction.
+    ///
+    /// Currently only supports Canada Federal jurisdiction.
+    fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+        matches!(jurisdiction, Jurisdiction::Federal(Country::Canada))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const CRA_HTML: &str = "<html><body>\
+        <h2>Federal tax rates for 2024</h2>\
+        <table><tbody>\
+        <tr><td>15% on the first $55,867 of taxable income, plus</td></tr>\
+        <tr><td>20.5% on the portion of taxable income over $55,867 up to $111,733, plus</td></tr>\
+        <tr><td>26% on the portion of taxable income over $111,733 up to $173,205, plus</td></tr>\
+        <tr><td>29% on the portion of taxable income over $173,205 up to $246,752, plus</td></tr>\
+        <tr><td>33% of taxable income over $246,752</td></tr>\
+        </tbody></table>\
+        <h2>Provincial and territorial tax rates for 2024</h2>\
+        <p>5.05% on the first $51,446 of taxable income</p>\
+        </body></html>";
+
+    async fn scraper_for(body: &str) -> (mockito::ServerGuard, CanadaFederalScraper) {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", CRA_RATES_PATH)
+            .with_body(body)
+            .create_async()
+            .await;
+        let scraper = CanadaFederalScraper::new().with_base_url(server.url());
+        (server, scraper)
+    }
+
+    #[tokio::test]
+    async fn test_parses_federal_section() {
+        let (_server, scraper) = scraper_for(CRA_HTML).await;
+        let schedule = scraper
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::Canada),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(schedule.currency, Currency::CAD);
+        assert_eq!(schedule.filing_status, None);
+        assert_eq!(schedule.brackets.len(), 5);
+        assert_eq!(schedule.brackets[0].upper_bound, Some(dec!(55867)));
+        assert_eq!(schedule.brackets[1].rate, dec!(0.205));
+        assert_eq!(schedule.brackets[4].lower_bound, dec!(246752));
+        assert_eq!(schedule.brackets[4].upper_bound, None);
+        assert_eq!(schedule.brackets[4].rate, dec!(0.33));
+    }
+
+    #[tokio::test]
+    async fn test_missing_year_and_other_jurisdictions() {
+        let (_server, scraper) = scraper_for(CRA_HTML).await;
+
+        let missing = scraper
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::Canada),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2019,
+            )
+            .await;
+        assert!(matches!(missing, Err(TaxError::RateNotAvailable(2019))));
+
+        let us = scraper
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+        assert!(matches!(us, Err(TaxError::UnsupportedJurisdiction)));
+        assert!(!scraper.supports_jurisdiction(&Jurisdiction::Federal(Country::USA)));
+    }
+}
@@ -0,0 +1,165 @@
+//! Scraper that dispatches to a jurisdiction-specific scraper.
+//!
+//! Lets callers fetch rates for any supported jurisdiction through a single
+//! `TaxRateScraper` instead of choosing a scraper themselves.
+
+use super::canada_federal::CanadaFederalScraper;
+use super::us_federal::USFederalScraper;
+use super::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, Jurisdiction, TaxEntityType, TaxSchedule};
+use async_trait::async_trait;
+
+/// Scraper that delegates to the first registered scraper supporting a jurisdiction.
+#[derive(Default)]
+pub struct CompositeScraper {
+    scrapers: Vec<Box<dyn TaxRateScraper>>,
+}
+
+impl CompositeScraper {
+    /// Creates a composite scraper with no registered scrapers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a composite scraper with the US and Canada federal scrapers registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::data::scrapers::composite::CompositeScraper;
+    /// use tax_engine::{Country, Jurisdiction, TaxRateScraper};
+    ///
+    /// let scraper = CompositeScraper::with_defaults();
+    /// assert!(scraper.supports_jurisdiction(&Jurisdiction::Federal(Country::Canada)));
+    /// ```
+    pub fn with_defaults() -> Self {
+        let mut composite = Self::new();
+        composite.register(USFederalScraper::new());
+        composite.register(CanadaFederalScraper::new());
+        composite
+    }
+
+    /// Adds a scraper to consult after those already registered.
+    ///
+    /// When several scrapers support the same jurisdiction, the one
+    /// registered first is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `scraper` - The scraper to register
+    pub fn register(&mut self, scraper: impl TaxRateScraper + 'static) {
+        self.scrapers.push(Box::new(scraper));
+    }
+
+    /// Returns the scraper that handles `jurisdiction`, if any.
+    fn scraper_for(&self, jurisdiction: &Jurisdiction) -> Option<&dyn TaxRateScraper> {
+        self.scrapers
+            .iter()
+            .find(|scraper| scraper.supports_jurisdiction(jurisdiction))
+            .map(|scraper| scraper.as_ref())
+    }
+}
+
+#[async_trait]
+impl TaxRateScraper for CompositeScraper {
+    /// Fetches tax rates from the first registered scraper supporting the jurisdiction.
+    ///
+    /// # Returns
+    ///
+    /// The delegate's result, or `TaxError::UnsupportedJurisdiction` if no
+    /// registered scraper supports the jurisdiction.
+    async fn fetch_rates(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        self.scraper_for(jurisdiction)
+            .ok_or(TaxError::UnsupportedJurisdiction)?
+            .fetch_rates(jurisdiction, entity_type, filing_status, tax_year)
+            .await
+    }
+
+    /// Checks if any registered scraper supports the given jurisdiction.
+    fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+        self.scraper_for(jurisdiction).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Country, TaxBracket, USState};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    /// Scraper serving a flat schedule for one jurisdiction.
+    struct FlatScraper {
+        jurisdiction: Jurisdiction,
+        rate: Decimal,
+    }
+
+    #[async_trait]
+    impl TaxRateScraper for FlatScraper {
+        async fn fetch_rates(
+            &self,
+            _jurisdiction: &Jurisdiction,
+            _entity_type: &TaxEntityType,
+            _filing_status: FilingStatus,
+            tax_year: u16,
+        ) -> Result<TaxSchedule, TaxError> {
+            Ok(TaxSchedule::new(
+                tax_year,
+                vec![TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: None,
+                    rate: self.rate,
+                }],
+            ))
+        }
+
+        fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+            *jurisdiction == self.jurisdiction
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatches_by_jurisdiction() {
+        let us = Jurisdiction::Federal(Country::USA);
+        let canada = Jurisdiction::Federal(Country::Canada);
+        let mut scraper = CompositeScraper::new();
+        scraper.register(FlatScraper {
+            jurisdiction: us.clone(),
+            rate: dec!(0.10),
+        });
+        scraper.register(FlatScraper {
+            jurisdiction: canada.clone(),
+            rate: dec!(0.15),
+        });
+        // Never consulted, since an earlier scraper handles the US
+        scraper.register(FlatScraper {
+            jurisdiction: us.clone(),
+            rate: dec!(0.99),
+        });
+
+        let fetch = |jurisdiction| {
+            scraper.fetch_rates(
+                jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+        };
+        assert_eq!(fetch(&us).await.unwrap().brackets[0].rate, dec!(0.10));
+        assert_eq!(fetch(&canada).await.unwrap().brackets[0].rate, dec!(0.15));
+
+        let california = Jurisdiction::USState(USState::California);
+        assert!(!scraper.supports_jurisdiction(&california));
+        assert!(matches!(
+            fetch(&california).await,
+            Err(TaxError::UnsupportedJurisdiction)
+        ));
+    }
+}
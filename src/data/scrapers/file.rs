@@ -0,0 +1,314 @@
+//! Tax rates loaded from files on disk.
+//!
+//! Provides a rate source for CI and air-gapped environments where the
+//! official websites cannot be reached. Schedules are read once, when the
+//! source is created, and every fetch is answered from memory.
+
+use super::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, Jurisdiction, TaxEntityType, TaxSchedule};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A schedule stored in a file, with the jurisdiction and entity type it
+/// applies to.
+///
+/// The tax year and filing status are taken from the schedule itself. A
+/// schedule without a filing status is served for every status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleRecord {
+    /// The jurisdiction the schedule applies to
+    pub jurisdiction: Jurisdiction,
+    /// The type of entity the schedule applies to
+    pub entity_type: TaxEntityType,
+    /// The schedule itself
+    pub schedule: TaxSchedule,
+}
+
+/// Contents of a schedule file: a single record or a list of records.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScheduleFile {
+    Many(Vec<ScheduleRecord>),
+    One(ScheduleRecord),
+}
+
+/// Rate source that serves schedules loaded from JSON files.
+///
+/// Each file holds either one [`ScheduleRecord`] or an array of them, for
+/// example:
+///
+/// ```json
+/// [
+///   {
+///     "jurisdiction": { "Federal": "USA" },
+///     "entity_type": "Individual",
+///     "schedule": {
+///       "tax_year": 2024,
+///       "filing_status": "Single",
+///       "brackets": [
+///         { "lower_bound": "0", "upper_bound": "11600", "rate": "0.10" },
+///         { "lower_bound": "11600", "upper_bound": null, "rate": "0.12" }
+///       ]
+///     }
+///   }
+/// ]
+/// ```
+pub struct FileScraper {
+    records: Vec<ScheduleRecord>,
+}
+
+impl FileScraper {
+    /// Loads schedules from a single file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of a JSON file holding one record or an array of records
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::FetchError` if the file cannot be read, or
+    /// `TaxError::ParseError` if it is not valid JSON in the expected shape.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TaxError> {
+        Ok(Self {
+            records: read_records(path.as_ref())?,
+        })
+    }
+
+    /// Loads schedules from every `.json` file in a directory.
+    ///
+    /// Files are read in name order, and other files are ignored. When several
+    /// records match a request, the first one loaded is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - Directory holding the schedule files
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::FetchError` if the directory or one of its files
+    /// cannot be read, or `TaxError::ParseError` if a file is not valid JSON
+    /// in the expected shape.
+    pub fn from_directory(directory: impl AsRef<Path>) -> Result<Self, TaxError> {
+        let directory = directory.as_ref();
+        let mut paths = std::fs::read_dir(directory)
+            .map_err(|e| {
+                TaxError::FetchError(format!("Could not read {}: {}", directory.display(), e))
+            })?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut records = Vec::new();
+        for path in paths {
+            records.extend(read_records(&path)?);
+        }
+        Ok(Self { records })
+    }
+
+    /// Returns the loaded records.
+    pub fn records(&self) -> &[ScheduleRecord] {
+        &self.records
+    }
+}
+
+/// Reads the records in one schedule file.
+fn read_records(path: &Path) -> Result<Vec<ScheduleRecord>, TaxError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| TaxError::FetchError(format!("Could not read {}: {}", path.display(), e)))?;
+    let file: ScheduleFile = serde_json::from_str(&contents)
+        .map_err(|e| TaxError::ParseError(format!("{}: {}", path.display(), e)))?;
+
+    Ok(match file {
+        ScheduleFile::Many(records) => records,
+        ScheduleFile::One(record) => vec![record],
+    })
+}
+
+#[async_trait]
+impl TaxRateScraper for FileScraper {
+    /// Returns the loaded schedule for the requested year.
+    ///
+    /// A schedule published for the requested filing status is preferred over
+    /// one without a filing status.
+    ///
+    /// # Returns
+    ///
+    /// The matching TaxSchedule, or an error if:
+    /// - No file has schedules for the jurisdiction
+    /// - No schedule matches the entity type and year
+    /// - Schedules for the year exist only for other filing statuses
+    async fn fetch_rates(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        if !self.supports_jurisdiction(jurisdiction) {
+            return Err(TaxError::UnsupportedJurisdiction);
+        }
+
+        let candidates: Vec<&TaxSchedule> = self
+            .records
+            .iter()
+            .filter(|record| {
+                record.jurisdiction == *jurisdiction
+                    && record.entity_type == *entity_type
+                    && record.schedule.tax_year == tax_year
+            })
+            .map(|record| &record.schedule)
+            .collect();
+        if candidates.is_empty() {
+            return Err(TaxError::RateNotAvailable(tax_year));
+        }
+
+        candidates
+            .iter()
+            .find(|schedule| schedule.filing_status == Some(filing_status))
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .find(|schedule| schedule.filing_status.is_none())
+            })
+            .map(|schedule| (*schedule).clone())
+            .ok_or(TaxError::UnsupportedFilingStatus(filing_status))
+    }
+
+    /// Checks if any loaded schedule is for the given jurisdiction.
+    fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+        self.records
+            .iter()
+            .any(|record| record.jurisdiction == *jurisdiction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Country, TaxBracket};
+    use rust_decimal_macros::dec;
+    use std::path::PathBuf;
+
+    /// Returns an empty directory for a test, removing any earlier contents.
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "tax_engine_file_scraper_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn record(country: Country, tax_year: u16, rate: rust_decimal::Decimal) -> ScheduleRecord {
+        ScheduleRecord {
+            jurisdiction: Jurisdiction::Federal(country),
+            entity_type: TaxEntityType::Individual,
+            schedule: TaxSchedule::new(
+                tax_year,
+                vec![TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: None,
+                    rate,
+                }],
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_loads_directory_of_files() {
+        let directory = test_directory("directory");
+        let us = vec![
+            record(Country::USA, 2023, dec!(0.10)),
+            record(Country::USA, 2024, dec!(0.12)),
+        ];
+        std::fs::write(
+            directory.join("us.json"),
+            serde_json::to_string(&us).unwrap(),
+        )
+        .unwrap();
+        // A file may also hold a single record
+        std::fs::write(
+            directory.join("canada.json"),
+            serde_json::to_string(&record(Country::Canada, 2024, dec!(0.15))).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(directory.join("README.txt"), "not a schedule").unwrap();
+
+        let scraper = FileScraper::from_directory(&directory).unwrap();
+        assert_eq!(scraper.records().len(), 3);
+
+        let us = Jurisdiction::Federal(Country::USA);
+        let canada = Jurisdiction::Federal(Country::Canada);
+        let fetch = |jurisdiction, tax_year| {
+            scraper.fetch_rates(
+                jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                tax_year,
+            )
+        };
+        assert_eq!(fetch(&us, 2024).await.unwrap().brackets[0].rate, dec!(0.12));
+        assert_eq!(
+            fetch(&canada, 2024).await.unwrap().brackets[0].rate,
+            dec!(0.15)
+        );
+        assert!(matches!(
+            fetch(&us, 2019).await,
+            Err(TaxError::RateNotAvailable(2019))
+        ));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[tokio::test]
+    async fn test_single_file_prefers_matching_filing_status() {
+        let directory = test_directory("single");
+        let path = directory.join("schedules.json");
+        let mut joint = record(Country::USA, 2024, dec!(0.20));
+        joint.schedule = joint
+            .schedule
+            .with_filing_status(FilingStatus::MarriedFilingJointly);
+        let all_statuses = record(Country::USA, 2024, dec!(0.10));
+        std::fs::write(
+            &path,
+            serde_json::to_string(&vec![all_statuses, joint]).unwrap(),
+        )
+        .unwrap();
+
+        let scraper = FileScraper::from_file(&path).unwrap();
+        let fetch = |filing_status| {
+            scraper.fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                filing_status,
+                2024,
+            )
+        };
+        assert_eq!(
+            fetch(FilingStatus::MarriedFilingJointly)
+                .await
+                .unwrap()
+                .brackets[0]
+                .rate,
+            dec!(0.20)
+        );
+        assert_eq!(
+            fetch(FilingStatus::Single).await.unwrap().brackets[0].rate,
+            dec!(0.10)
+        );
+        assert!(!scraper.supports_jurisdiction(&Jurisdiction::Federal(Country::Canada)));
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(matches!(
+            FileScraper::from_file(&path),
+            Err(TaxError::ParseError(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -4,30 +4,171 @@
 //! from official government sources.
 
 use crate::errors::TaxError;
-use crate::models::{Jurisdiction, TaxEntityType, TaxSchedule};
+use crate::models::{FilingStatus, Jurisdiction, TaxEntityType, TaxSchedule, TaxScheduleSet};
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use std::ops::RangeInclusive;
+use std::time::SystemTime;
+
+/// Number of fetches [`TaxRateScraper::fetch_rates_batch`] callers should allow
+/// in flight when they have no stronger preference.
+pub const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// Source reported for schedules supplied by a fallback instead of a live fetch.
+pub const FALLBACK_SOURCE: &str = "fallback";
+
+/// A fetched tax schedule together with its provenance.
+#[derive(Debug, Clone)]
+pub struct FetchedSchedule {
+    /// The parsed tax schedule
+    pub schedule: TaxSchedule,
+    /// The URL the schedule was successfully fetched from, or
+    /// [`FALLBACK_SOURCE`] for an estimated schedule
+    pub source_url: String,
+    /// When the schedule was fetched
+    pub fetched_at: SystemTime,
+    /// Non-fatal issues encountered while fetching or parsing
+    pub warnings: Vec<String>,
+    /// Whether the schedule came from a fallback source instead of a live fetch
+    pub estimated: bool,
+}
 
 /// Defines the interface for tax rate scraping implementations.
 #[async_trait]
-pub trait TaxRateScraper {
+pub trait TaxRateScraper: Send + Sync {
     /// Fetches tax rates from the appropriate source.
     ///
     /// # Arguments
     ///
     /// * `jurisdiction` - The tax jurisdiction to fetch rates for
     /// * `entity_type` - The type of tax entity
+    /// * `filing_status` - The filing status whose brackets should be fetched
     /// * `tax_year` - The tax year
     ///
     /// # Returns
     ///
     /// Returns a Result containing either a TaxSchedule or an error.
+    /// Implementations that only publish brackets for some filing statuses
+    /// return `TaxError::UnsupportedFilingStatus` for the others.
     async fn fetch_rates(
         &self,
         jurisdiction: &Jurisdiction,
         entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
         tax_year: u16,
     ) -> Result<TaxSchedule, TaxError>;
 
+    /// Fetches tax rates for a range of years as a stream.
+    ///
+    /// Each schedule is yielded as soon as its fetch completes. Fetches are
+    /// issued one at a time and only when the consumer polls for the next
+    /// item, so a slow consumer applies backpressure to the scraper.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - The tax jurisdiction to fetch rates for
+    /// * `entity_type` - The type of tax entity
+    /// * `filing_status` - The filing status whose brackets should be fetched
+    /// * `years` - The tax years to fetch, in ascending order
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream yielding one Result per year. A failed year does not
+    /// end the stream.
+    fn fetch_rates_stream<'a>(
+        &'a self,
+        jurisdiction: &'a Jurisdiction,
+        entity_type: &'a TaxEntityType,
+        filing_status: FilingStatus,
+        years: RangeInclusive<u16>,
+    ) -> BoxStream<'a, Result<TaxSchedule, TaxError>> {
+        stream::iter(years)
+            .then(move |year| self.fetch_rates(jurisdiction, entity_type, filing_status, year))
+            .boxed()
+    }
+
+    /// Fetches tax rates for several years with a bounded number in flight.
+    ///
+    /// At most `max_concurrency` fetches run at the same time, so a large
+    /// batch does not flood the scraper's rate limiter or the source server.
+    /// A `max_concurrency` of zero is treated as one. Use
+    /// [`DEFAULT_MAX_CONCURRENCY`] when there is no reason to pick another value.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - The tax jurisdiction to fetch rates for
+    /// * `entity_type` - The type of tax entity
+    /// * `filing_status` - The filing status whose brackets should be fetched
+    /// * `years` - The tax years to fetch
+    /// * `max_concurrency` - The maximum number of fetches running at once
+    ///
+    /// # Returns
+    ///
+    /// Returns one Result per year, in the same order as `years`, regardless
+    /// of the order in which the fetches complete.
+    async fn fetch_rates_batch(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        years: &[u16],
+        max_concurrency: usize,
+    ) -> Vec<Result<TaxSchedule, TaxError>> {
+        let mut results: Vec<_> = stream::iter(years.iter().copied().enumerate())
+            .map(|(index, year)| async move {
+                (
+                    index,
+                    self.fetch_rates(jurisdiction, entity_type, filing_status, year)
+                        .await,
+                )
+            })
+            .buffer_unordered(max_concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Fetches an individual's schedule for every filing status in a year.
+    ///
+    /// Statuses are fetched one at a time. A status the scraper reports as
+    /// `TaxError::UnsupportedFilingStatus` is left out of the set, so the set
+    /// holds only the schedules the source publishes.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - The tax jurisdiction to fetch rates for
+    /// * `tax_year` - The tax year
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the schedules keyed by filing status, or
+    /// the first error other than an unsupported filing status.
+    async fn fetch_rate_set(
+        &self,
+        jurisdiction: &Jurisdiction,
+        tax_year: u16,
+    ) -> Result<TaxScheduleSet, TaxError> {
+        let mut set = TaxScheduleSet::new(tax_year);
+        for filing_status in FilingStatus::ALL {
+            match self
+                .fetch_rates(
+                    jurisdiction,
+                    &TaxEntityType::Individual,
+                    filing_status,
+                    tax_year,
+                )
+                .await
+            {
+                Ok(schedule) => set.insert(filing_status, schedule),
+                Err(TaxError::UnsupportedFilingStatus(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(set)
+    }
+
     /// Checks if this scraper supports a given jurisdiction.
     ///
     /// # Arguments
@@ -41,4 +182,139 @@ pub trait TaxRateScraper {
 }
 
 pub mod canada_federal;
+pub mod composite;
+pub mod file;
 pub mod us_federal;
+pub mod us_federal_api;
+pub mod us_state;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Country, TaxBracket};
+    use rust_decimal_macros::dec;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Scraper that serves a fixed single-bracket schedule for 2022 through 2024.
+    struct StaticScraper;
+
+    #[async_trait]
+    impl TaxRateScraper for StaticScraper {
+        async fn fetch_rates(
+            &self,
+            _jurisdiction: &Jurisdiction,
+            _entity_type: &TaxEntityType,
+            _filing_status: FilingStatus,
+            tax_year: u16,
+        ) -> Result<TaxSchedule, TaxError> {
+            if !(2022..=2024).contains(&tax_year) {
+                return Err(TaxError::RateNotAvailable(tax_year));
+            }
+
+            Ok(TaxSchedule::new(
+                tax_year,
+                vec![TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: None,
+                    rate: dec!(0.10),
+                }],
+            ))
+        }
+
+        fn supports_jurisdiction(&self, _jurisdiction: &Jurisdiction) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_rates_stream_yields_each_year() {
+        let scraper = StaticScraper;
+        let jurisdiction = Jurisdiction::Federal(Country::USA);
+
+        let years: Vec<u16> = scraper
+            .fetch_rates_stream(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2022..=2024,
+            )
+            .map(|result| result.unwrap().tax_year)
+            .collect()
+            .await;
+        assert_eq!(years, vec![2022, 2023, 2024]);
+
+        let results: Vec<_> = scraper
+            .fetch_rates_stream(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024..=2025,
+            )
+            .collect()
+            .await;
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(TaxError::RateNotAvailable(2025))));
+    }
+
+    /// Scraper that records how many fetches are running at once.
+    #[derive(Default)]
+    struct ConcurrencyTrackingScraper {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TaxRateScraper for ConcurrencyTrackingScraper {
+        async fn fetch_rates(
+            &self,
+            jurisdiction: &Jurisdiction,
+            entity_type: &TaxEntityType,
+            filing_status: FilingStatus,
+            tax_year: u16,
+        ) -> Result<TaxSchedule, TaxError> {
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(running, Ordering::SeqCst);
+
+            // Earlier years take longer so fetches complete out of order
+            let delay = 2030u64.saturating_sub(u64::from(tax_year));
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            StaticScraper
+                .fetch_rates(jurisdiction, entity_type, filing_status, tax_year)
+                .await
+        }
+
+        fn supports_jurisdiction(&self, _jurisdiction: &Jurisdiction) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_rates_batch_limits_concurrency() {
+        let scraper = ConcurrencyTrackingScraper::default();
+        let jurisdiction = Jurisdiction::Federal(Country::USA);
+        let years: Vec<u16> = (2015..=2025).collect();
+
+        let results = scraper
+            .fetch_rates_batch(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                &years,
+                3,
+            )
+            .await;
+
+        assert_eq!(scraper.max_in_flight.load(Ordering::SeqCst), 3);
+        assert_eq!(results.len(), years.len());
+        for (year, result) in years.iter().zip(&results) {
+            match result {
+                Ok(schedule) => assert_eq!(schedule.tax_year, *year),
+                Err(error) => assert!(matches!(error, TaxError::RateNotAvailable(y) if y == year)),
+            }
+        }
+        assert!(results[7..10].iter().all(Result::is_ok));
+    }
+}
@@ -3,17 +3,45 @@
 //! Provides functionality to fetch and parse US federal tax rates from the IRS website.
 //! Handles various IRS website formats and patterns for tax bracket information.
 
-use super::TaxRateScraper;
+use super::{FetchedSchedule, TaxRateScraper, FALLBACK_SOURCE};
+use crate::calculators::{US_FEDERAL_CORPORATE_RATE, US_FEDERAL_FLAT_CORPORATE_RATE_SINCE};
 use crate::errors::TaxError;
-use crate::models::{Country, Jurisdiction, TaxBracket, TaxEntityType, TaxSchedule};
+use crate::models::{Country, FilingStatus, Jurisdiction, TaxBracket, TaxEntityType, TaxSchedule};
 use async_trait::async_trait;
 use regex::Regex;
 use rust_decimal::prelude::*;
 use scraper::{Html, Selector};
+use std::time::{Duration, SystemTime};
+
+/// Default upper limit on the size of a fetched response body (5 MiB).
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Default highest rate accepted from a scraped page (100%).
+pub const DEFAULT_MAX_SCRAPED_RATE: Decimal = Decimal::ONE;
+
+/// Default base URL of the IRS website.
+pub const DEFAULT_IRS_BASE_URL: &str = "https://www.irs.gov";
+
+/// Source reported for corporate schedules, which come from the statute
+/// rather than a fetched page.
+pub const CORPORATE_RATE_SOURCE: &str = "https://www.law.cornell.edu/uscode/text/26/11";
 
 /// Scraper implementation for US federal tax rates.
 pub struct USFederalScraper {
     client: reqwest::Client,
+    max_response_bytes: usize,
+    base_url: String,
+    max_rate: Decimal,
+    fallback: Option<Box<dyn TaxRateScraper>>,
+    max_attempts: u32,
+    retry_base_delay: Duration,
+}
+
+/// A successfully fetched IRS page and notes gathered while finding it.
+struct FetchedPage {
+    url: String,
+    content: String,
+    warnings: Vec<String>,
 }
 
 impl USFederalScraper {
@@ -25,19 +53,143 @@ impl USFederalScraper {
     /// - Fallback to default client if custom configuration fails
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
-                .timeout(std::time::Duration::from_secs(10))
+            client: Self::default_client_builder()
                 .build()
-                .unwrap_or_else(|_| reqwest::Client::new())
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            base_url: DEFAULT_IRS_BASE_URL.to_string(),
+            max_rate: DEFAULT_MAX_SCRAPED_RATE,
+            fallback: None,
+            max_attempts: 1,
+            retry_base_delay: Duration::from_millis(500),
         }
     }
 
+    /// Returns the client builder with the scraper's default user agent and timeout.
+    fn default_client_builder() -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
+            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .timeout(Duration::from_secs(10))
+    }
+
+    /// Rebuilds the HTTP client from the default builder with custom settings.
+    ///
+    /// The closure receives a builder already configured with the default user
+    /// agent and timeout, so it only needs to apply its own changes, such as a
+    /// proxy or extra headers. Settings it applies override the defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `customize` - Closure that adjusts the default client builder
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::NetworkError` if the customized client cannot be built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::USFederalScraper;
+    ///
+    /// let scraper = USFederalScraper::new()
+    ///     .with_client_builder(|builder| builder.connect_timeout(std::time::Duration::from_secs(2)))
+    ///     .unwrap();
+    /// ```
+    pub fn with_client_builder(
+        mut self,
+        customize: impl FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder,
+    ) -> Result<Self, TaxError> {
+        self.client = customize(Self::default_client_builder())
+            .build()
+            .map_err(|e| TaxError::NetworkError(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the base URL that IRS page paths are resolved against.
+    ///
+    /// Useful for pointing the scraper at a mirror or a local test server.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Scheme and host to use instead of `https://www.irs.gov`
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the maximum number of bytes accepted from a single response body.
+    ///
+    /// Responses larger than this limit are rejected with `TaxError::FetchError`
+    /// instead of being read into memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_response_bytes` - The size limit in bytes
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self
+    }
+
+    /// Sets the highest bracket rate accepted from a scraped page.
+    ///
+    /// Parsed rates above this limit, or below zero, are treated as parsing
+    /// mistakes and rejected with `TaxError::ParseError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rate` - The highest plausible rate as a decimal (e.g., 0.6 for 60%)
+    pub fn with_max_rate(mut self, max_rate: Decimal) -> Self {
+        self.max_rate = max_rate;
+        self
+    }
+
+    /// Sets a source to fall back on when fetching from the IRS fails.
+    ///
+    /// If the IRS pages cannot be fetched or parsed, the schedule for the
+    /// requested year is taken from `fallback` instead and reported as
+    /// estimated, with the original failure recorded as a warning. If the
+    /// fallback also fails, the original error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - A known-good source of schedules
+    pub fn with_fallback(mut self, fallback: impl TaxRateScraper + 'static) -> Self {
+        self.fallback = Some(Box::new(fallback));
+        self
+    }
+
+    /// Retries each IRS URL after transient failures, with exponential backoff.
+    ///
+    /// A URL is retried after a network error or a 5xx response, waiting
+    /// `base_delay` before the second attempt and doubling the wait before
+    /// each further one. Other responses, such as a 404, move straight on to
+    /// the next URL. By default each URL is tried once.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - Attempts per URL, including the first (at least 1)
+    /// * `base_delay` - Wait before the first retry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::USFederalScraper;
+    /// use std::time::Duration;
+    ///
+    /// let scraper = USFederalScraper::new().with_retry(3, Duration::from_millis(250));
+    /// ```
+    pub fn with_retry(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_base_delay = base_delay;
+        self
+    }
+
     /// Attempts to fetch tax rate information from various IRS website URLs.
     ///
     /// Tries multiple URL patterns in sequence, as the IRS website structure
-    /// can vary by tax year. Debug information is printed to help diagnose
-    /// fetching issues.
+    /// can vary by tax year, retrying each as configured by
+    /// [`USFederalScraper::with_retry`]. Each attempt is logged through the
+    /// `log` crate to help diagnose fetching issues.
     ///
     /// # Arguments
     ///
@@ -45,83 +197,316 @@ impl USFederalScraper {
     ///
     /// # Returns
     ///
-    /// The HTML content of the first successfully fetched page, or an error
-    /// if all URLs fail.
-    async fn fetch_rates_from_irs(&self, year: u16) -> Result<String, TaxError> {
+    /// The first successfully fetched page along with its URL and a warning for
+    /// each failed attempt before it, or a `TaxError::NetworkError` listing
+    /// every failed attempt if all URLs fail.
+    async fn fetch_rates_from_irs(&self, year: u16) -> Result<FetchedPage, TaxError> {
+        let base = &self.base_url;
         let urls = vec![
-            format!("https://www.irs.gov/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-{}", year),
-            format!("https://www.irs.gov/pub/irs-drop/rp-{}-23.pdf", year - 1),
-            format!("https://www.irs.gov/newsroom/tax-year-{}-inflation-adjustments", year),
+            format!(
+                "{}/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-{}",
+                base, year
+            ),
+            format!("{}/pub/irs-drop/rp-{}-23.pdf", base, year - 1),
+            format!("{}/newsroom/tax-year-{}-inflation-adjustments", base, year),
         ];
 
-        let mut last_error = String::new();
-        for url in &urls {
-            println!("Trying URL: {}", url);
-            match self.client.get(url).send().await {
-                Ok(response) => {
-                    if response.status().is_success() {
-                        let text = response
-                            .text()
-                            .await
-                            .map_err(|e| TaxError::FetchError(e.to_string()))?;
-                        println!("Successfully fetched content from: {}", url);
-                        println!(
-                            "First 500 chars of content: {}",
-                            &text[..500.min(text.len())]
-                        );
-                        return Ok(text);
-                    }
-                    println!("Status not success: {}", response.status());
+        let mut attempts = 0;
+        let mut warnings = Vec::new();
+        for url in urls {
+            for attempt in 1..=self.max_attempts {
+                if attempt > 1 {
+                    let backoff = 2u32.saturating_pow(attempt - 2);
+                    tokio::time::sleep(self.retry_base_delay.saturating_mul(backoff)).await;
                 }
-                Err(e) => {
-                    last_error = e.to_string();
-                    println!("Error fetching {}: {}", url, last_error);
+                attempts += 1;
+
+                log::debug!("Trying URL: {}", url);
+                match self.client.get(&url).send().await {
+                    Ok(response) => {
+                        if response.status().is_success() {
+                            let text = read_body_limited(response, self.max_response_bytes).await?;
+                            log::debug!("Successfully fetched content from: {}", url);
+                            log::trace!(
+                                "First 500 chars of content: {}",
+                                &text[..500.min(text.len())]
+                            );
+                            return Ok(FetchedPage {
+                                url,
+                                content: text,
+                                warnings,
+                            });
+                        }
+                        log::warn!("Status not success: {}", response.status());
+                        warnings.push(format!("{} returned status {}", url, response.status()));
+                        // Client errors won't go away on retry
+                        if !response.status().is_server_error() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("Error fetching {}: {}", url, e);
+                        warnings.push(format!("{} failed: {}", url, e));
+                    }
                 }
             }
         }
 
-        Err(TaxError::FetchError(format!(
-            "Failed to fetch IRS data: {}",
-            last_error
+        Err(TaxError::NetworkError(format!(
+            "Failed to fetch IRS data after {} attempts: {}",
+            attempts,
+            warnings.join("; ")
         )))
     }
 
+    /// Fetches US federal tax rates along with where and when they were obtained.
+    ///
+    /// This is the provenance-carrying counterpart of
+    /// [`TaxRateScraper::fetch_rates`], which delegates to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - Must be Federal(USA)
+    /// * `entity_type` - Must be Individual
+    /// * `filing_status` - Single or MarriedFilingJointly
+    /// * `tax_year` - The tax year to fetch rates for
+    ///
+    /// # Returns
+    ///
+    /// A `FetchedSchedule` with the parsed schedule, the URL it came from, the
+    /// fetch time, and warnings about failed URLs or suspicious bracket data.
+    pub async fn fetch_rates_with_provenance(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<FetchedSchedule, TaxError> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "fetch_rates",
+                jurisdiction = ?jurisdiction,
+                filing_status = ?filing_status,
+                year = tax_year,
+                bracket_count = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+            );
+            let started = std::time::Instant::now();
+            let result = self
+                .fetch_schedule(jurisdiction, entity_type, filing_status, tax_year)
+                .instrument(span.clone())
+                .await;
+
+            span.record("duration_ms", started.elapsed().as_millis() as u64);
+            if let Ok(fetched) = &result {
+                span.record("bracket_count", fetched.schedule.brackets.len());
+            }
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.fetch_schedule(jurisdiction, entity_type, filing_status, tax_year)
+            .await
+    }
+
+    /// Fetches a schedule for [`Self::fetch_rates_with_provenance`], using the
+    /// fallback source if the live fetch fails.
+    async fn fetch_schedule(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<FetchedSchedule, TaxError> {
+        let error = match self
+            .fetch_live(jurisdiction, entity_type, filing_status, tax_year)
+            .await
+        {
+            Err(TaxError::UnsupportedJurisdiction) => {
+                return Err(TaxError::UnsupportedJurisdiction)
+            }
+            Err(TaxError::UnsupportedFilingStatus(status)) => {
+                return Err(TaxError::UnsupportedFilingStatus(status))
+            }
+            Err(error @ TaxError::Incompatible { .. }) => return Err(error),
+            Err(error) => error,
+            fetched => return fetched,
+        };
+
+        let Some(fallback) = &self.fallback else {
+            return Err(error);
+        };
+        match fallback
+            .fetch_rates(jurisdiction, entity_type, filing_status, tax_year)
+            .await
+        {
+            Ok(schedule) => Ok(FetchedSchedule {
+                schedule,
+                source_url: FALLBACK_SOURCE.to_string(),
+                fetched_at: SystemTime::now(),
+                warnings: vec![format!(
+                    "Live fetch failed, using fallback schedule: {}",
+                    error
+                )],
+                estimated: true,
+            }),
+            Err(_) => Err(error),
+        }
+    }
+
+    /// Fetches and parses a schedule from the IRS website.
+    ///
+    /// The IRS announcement lists single-filer thresholds, with the joint-filer
+    /// threshold in parentheses after each one, so only those two filing
+    /// statuses can be scraped.
+    ///
+    /// Corporations have paid a flat 21% since 2018, so their schedule is a
+    /// single unbounded bracket built without network access, whatever the
+    /// filing status. Partnerships pay no income tax themselves and are
+    /// rejected with `TaxError::Incompatible`.
+    async fn fetch_live(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<FetchedSchedule, TaxError> {
+        match (jurisdiction, entity_type) {
+            (Jurisdiction::Federal(Country::USA), TaxEntityType::Individual) => {
+                if !matches!(
+                    filing_status,
+                    FilingStatus::Single | FilingStatus::MarriedFilingJointly
+                ) {
+                    return Err(TaxError::UnsupportedFilingStatus(filing_status));
+                }
+
+                let page = self.fetch_rates_from_irs(tax_year).await?;
+                let brackets = self.parse_tax_brackets(&page.content, filing_status, tax_year)?;
+
+                if let Some(bracket) = brackets
+                    .iter()
+                    .find(|b| b.rate < Decimal::ZERO || b.rate > self.max_rate)
+                {
+                    return Err(TaxError::ParseError(format!(
+                        "Parsed rate {} for incomes over {} is outside the plausible range 0 to {}",
+                        bracket.rate, bracket.lower_bound, self.max_rate
+                    )));
+                }
+
+                if brackets.is_empty() {
+                    return Err(TaxError::RateNotAvailable(tax_year));
+                }
+
+                let mut warnings = page.warnings;
+                if brackets[0].lower_bound > Decimal::ZERO {
+                    warnings.push(format!(
+                        "Lowest parsed bracket starts at {} rather than zero",
+                        brackets[0].lower_bound
+                    ));
+                }
+
+                Ok(FetchedSchedule {
+                    schedule: TaxSchedule::new(tax_year, brackets)
+                        .with_filing_status(filing_status)
+                        .normalized(),
+                    source_url: page.url,
+                    fetched_at: SystemTime::now(),
+                    warnings,
+                    estimated: false,
+                })
+            }
+            (Jurisdiction::Federal(Country::USA), TaxEntityType::Corporation) => {
+                if tax_year < US_FEDERAL_FLAT_CORPORATE_RATE_SINCE {
+                    return Err(TaxError::RateNotAvailable(tax_year));
+                }
+
+                let bracket = TaxBracket {
+                    lower_bound: Decimal::ZERO,
+                    upper_bound: None,
+                    rate: US_FEDERAL_CORPORATE_RATE,
+                };
+                Ok(FetchedSchedule {
+                    schedule: TaxSchedule::new(tax_year, vec![bracket]).normalized(),
+                    source_url: CORPORATE_RATE_SOURCE.to_string(),
+                    fetched_at: SystemTime::now(),
+                    warnings: Vec::new(),
+                    estimated: false,
+                })
+            }
+            (Jurisdiction::Federal(Country::USA), TaxEntityType::Partnership) => {
+                Err(TaxError::Incompatible {
+                    reason: "partnerships pay no federal income tax; their profits pass \
+                             through and are taxed on each partner's return"
+                        .to_string(),
+                })
+            }
+            _ => Err(TaxError::UnsupportedJurisdiction),
+        }
+    }
+
     /// Parses tax brackets from IRS website content.
     ///
     /// Searches for specific text patterns that indicate tax bracket information
-    /// and constructs TaxBracket instances from the parsed data.
+    /// and constructs TaxBracket instances from the parsed data. Brackets
+    /// listed in HTML tables are read too (see
+    /// [`USFederalScraper::parse_rate_tables`]), and when prose and a table
+    /// both give a bracket with the same lower bound, it is kept once,
+    /// preferring the version with an upper bound.
     ///
     /// # Arguments
     ///
     /// * `content` - The HTML content from the IRS website
+    /// * `filing_status` - Whose thresholds to read: Single or MarriedFilingJointly
     /// * `year` - The tax year (used for validation)
     ///
     /// # Returns
     ///
-    /// A vector of parsed tax brackets, sorted by lower bound,
-    /// or an error if no valid brackets are found.
-    fn parse_tax_brackets(&self, content: &str, _year: u16) -> Result<Vec<TaxBracket>, TaxError> {
+    /// A vector of parsed tax brackets, sorted by lower bound, or an error if
+    /// no valid brackets are found or a rate line lacks the threshold for
+    /// `filing_status`.
+    fn parse_tax_brackets(
+        &self,
+        content: &str,
+        filing_status: FilingStatus,
+        _year: u16,
+    ) -> Result<Vec<TaxBracket>, TaxError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "parse_tax_brackets",
+            year = _year,
+            content_bytes = content.len(),
+            bracket_count = tracing::field::Empty,
+        )
+        .entered();
+
         let document = Html::parse_document(content);
-        let mut brackets = Vec::new();
+        let mut brackets = self.parse_rate_tables(&document, filing_status);
 
         for element in document.select(&Selector::parse("p,div").unwrap()) {
             let text = element.text().collect::<String>().to_lowercase();
 
             if text.contains("% for incomes over") {
-                if let Some(bracket) = self.parse_rate_text(&text) {
+                if let Some(bracket) = self.parse_rate_text(&text, filing_status)? {
                     brackets.push(bracket);
                 }
             }
 
             if text.contains("lowest rate is") && text.contains("or less") {
-                if let Some(bracket) = self.parse_lowest_rate_text(&text) {
+                if let Some(bracket) = self.parse_lowest_rate_text(&text, filing_status)? {
                     brackets.push(bracket);
                 }
             }
         }
 
+        #[cfg(feature = "tracing")]
+        span.record("bracket_count", brackets.len());
+
         if !brackets.is_empty() {
-            brackets.sort_by(|a, b| a.lower_bound.cmp(&b.lower_bound));
+            brackets.sort_by_key(|b| (b.lower_bound, b.upper_bound.is_none()));
+            brackets.dedup_by_key(|b| b.lower_bound);
             return Ok(brackets);
         }
 
@@ -130,57 +515,214 @@ impl USFederalScraper {
         ))
     }
 
+    /// Parses brackets from the filing status's rate table.
+    ///
+    /// IRS pages introduce each table with a paragraph or heading such as
+    /// "For a single taxpayer, the rates are:", or give it a caption. A table
+    /// is read when that text names the filing status ("single", or "joint"
+    /// for joint filers), and each row with a percentage and at least one
+    /// dollar amount becomes a bracket.
+    ///
+    /// The tables print whole-dollar ranges with both ends inclusive ("$11,601
+    /// to $47,150"), so a row starting one dollar above the previous row's
+    /// upper bound is moved down to share that bound, matching the "over
+    /// $11,600" wording of the prose.
+    ///
+    /// # Arguments
+    ///
+    /// * `document` - The parsed IRS page
+    /// * `filing_status` - Whose table to read: Single or MarriedFilingJointly
+    ///
+    /// # Returns
+    ///
+    /// The brackets from every matching table, in table order.
+    fn parse_rate_tables(&self, document: &Html, filing_status: FilingStatus) -> Vec<TaxBracket> {
+        let label = match filing_status {
+            FilingStatus::MarriedFilingJointly => "joint",
+            _ => "single",
+        };
+        let caption = Selector::parse("caption").unwrap();
+        let row = Selector::parse("tr").unwrap();
+        let cell = Selector::parse("td,th").unwrap();
+
+        let mut brackets = Vec::new();
+        let mut context = String::new();
+        for element in document.select(&Selector::parse("p,h2,h3,h4,table").unwrap()) {
+            if element.value().name() != "table" {
+                context = element.text().collect::<String>().to_lowercase();
+                continue;
+            }
+
+            if let Some(caption) = element.select(&caption).next() {
+                context = caption.text().collect::<String>().to_lowercase();
+            }
+            if !context.contains(label) {
+                continue;
+            }
+
+            let mut previous: Option<TaxBracket> = None;
+            for tr in element.select(&row) {
+                let cells: Vec<String> = tr
+                    .select(&cell)
+                    .map(|c| c.text().collect::<String>())
+                    .collect();
+                let Some(mut bracket) = self.parse_rate_row(&cells) else {
+                    continue;
+                };
+                if let Some(upper) = previous.as_ref().and_then(|p| p.upper_bound) {
+                    if bracket.lower_bound == upper + Decimal::ONE {
+                        bracket.lower_bound = upper;
+                    }
+                }
+                previous = Some(bracket.clone());
+                brackets.push(bracket);
+            }
+        }
+        brackets
+    }
+
+    /// Parses the cells of a rate table row.
+    ///
+    /// Handles rows like "12% | $11,601 | $47,150" and "37% | $609,351 | And
+    /// up", where the first dollar amount is the lower bound and the second,
+    /// if any, the upper bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The text of each cell in the row
+    ///
+    /// # Returns
+    ///
+    /// An Option containing a TaxBracket if the row has a rate and a lower bound
+    fn parse_rate_row(&self, cells: &[String]) -> Option<TaxBracket> {
+        let percent = Regex::new(r"(\d+(?:\.\d+)?)\s*%").ok()?;
+        let amount = Regex::new(r"\$\s*([0-9][0-9,]*)").ok()?;
+
+        let rate = cells.iter().find_map(|c| {
+            let caps = percent.captures(c)?;
+            Decimal::from_str_exact(caps.get(1)?.as_str()).ok()
+        })? / Decimal::ONE_HUNDRED;
+        let mut amounts = cells
+            .iter()
+            .filter_map(|c| self.extract_number(amount.captures(c)?.get(1)?.as_str()));
+
+        Some(TaxBracket {
+            rate,
+            lower_bound: amounts.next()?,
+            upper_bound: amounts.next(),
+        })
+    }
+
     /// Parses a text fragment containing a standard tax bracket definition.
     ///
-    /// Handles patterns like "35% for incomes over $243,725"
+    /// Handles patterns like "35% for incomes over $243,725 ($487,450 for
+    /// married couples filing jointly)"
     ///
     /// # Arguments
     ///
     /// * `text` - The text fragment to parse
+    /// * `filing_status` - Whose threshold to read: Single or MarriedFilingJointly
     ///
     /// # Returns
     ///
     /// An Option containing a TaxBracket if the text matches the expected pattern
-    fn parse_rate_text(&self, text: &str) -> Option<TaxBracket> {
-        let re = Regex::new(r"(\d+)%\s+for\s+incomes\s+over\s+\$([0-9,]+)").ok()?;
-        if let Some(caps) = re.captures(text) {
-            let rate = caps.get(1)?.as_str().parse::<u32>().ok()? as f64 / 100.0;
-            let lower_bound = self.extract_number(caps.get(2)?.as_str())?;
-
-            return Some(TaxBracket {
-                rate: Decimal::from_f64(rate)?,
-                lower_bound,
-                upper_bound: None,
-            });
-        }
-        None
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::ParseError` if the text matches but gives no
+    /// threshold for the filing status, since skipping the line would leave a
+    /// gap in the schedule.
+    fn parse_rate_text(
+        &self,
+        text: &str,
+        filing_status: FilingStatus,
+    ) -> Result<Option<TaxBracket>, TaxError> {
+        let re = Regex::new(&format!(
+            r"(\d+)%\s+for\s+incomes\s+over\s+\$([0-9,]+){}",
+            JOINT_THRESHOLD_PATTERN
+        ))
+        .map_err(|e| TaxError::ParseError(e.to_string()))?;
+        let Some(caps) = re.captures(text) else {
+            return Ok(None);
+        };
+
+        let rate = caps[1].parse::<u32>().ok().map(|r| r as f64 / 100.0);
+        let lower_bound = self.status_threshold(&caps, filing_status, text)?;
+        Ok(rate.and_then(Decimal::from_f64).map(|rate| TaxBracket {
+            rate,
+            lower_bound,
+            upper_bound: None,
+        }))
     }
 
     /// Parses a text fragment containing the lowest tax bracket definition.
     ///
     /// Handles patterns like "lowest rate is 10% for incomes of single individuals
-    /// with incomes of $11,600 or less"
+    /// with incomes of $11,600 or less ($23,200 for married couples filing jointly)"
     ///
     /// # Arguments
     ///
     /// * `text` - The text fragment to parse
+    /// * `filing_status` - Whose threshold to read: Single or MarriedFilingJointly
     ///
     /// # Returns
     ///
     /// An Option containing a TaxBracket if the text matches the expected pattern
-    fn parse_lowest_rate_text(&self, text: &str) -> Option<TaxBracket> {
-        let re = Regex::new(r"(\d+)%.*\$([0-9,]+)\s+or\s+less").ok()?;
-        if let Some(caps) = re.captures(text) {
-            let rate = caps.get(1)?.as_str().parse::<u32>().ok()? as f64 / 100.0;
-            let upper_bound = self.extract_number(caps.get(2)?.as_str())?;
-
-            return Some(TaxBracket {
-                rate: Decimal::from_f64(rate)?,
-                lower_bound: Decimal::zero(),
-                upper_bound: Some(upper_bound),
-            });
-        }
-        None
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::ParseError` if the text matches but gives no
+    /// threshold for the filing status.
+    fn parse_lowest_rate_text(
+        &self,
+        text: &str,
+        filing_status: FilingStatus,
+    ) -> Result<Option<TaxBracket>, TaxError> {
+        let re = Regex::new(&format!(
+            r"(\d+)%.*\$([0-9,]+)\s+or\s+less{}",
+            JOINT_THRESHOLD_PATTERN
+        ))
+        .map_err(|e| TaxError::ParseError(e.to_string()))?;
+        let Some(caps) = re.captures(text) else {
+            return Ok(None);
+        };
+
+        let rate = caps[1].parse::<u32>().ok().map(|r| r as f64 / 100.0);
+        let upper_bound = self.status_threshold(&caps, filing_status, text)?;
+        Ok(rate.and_then(Decimal::from_f64).map(|rate| TaxBracket {
+            rate,
+            lower_bound: Decimal::zero(),
+            upper_bound: Some(upper_bound),
+        }))
+    }
+
+    /// Reads the threshold for a filing status from a matched rate line.
+    ///
+    /// # Arguments
+    ///
+    /// * `caps` - The captures of a pattern ending in [`JOINT_THRESHOLD_PATTERN`]
+    /// * `filing_status` - Whose threshold to read: Single or MarriedFilingJointly
+    /// * `text` - The matched text, quoted in the error
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::ParseError` if the line has no threshold for the
+    /// filing status, as when it omits the joint-filer parenthetical.
+    fn status_threshold(
+        &self,
+        caps: &regex::Captures<'_>,
+        filing_status: FilingStatus,
+        text: &str,
+    ) -> Result<Decimal, TaxError> {
+        caps.get(threshold_group(filing_status))
+            .and_then(|threshold| self.extract_number(threshold.as_str()))
+            .ok_or_else(|| {
+                TaxError::ParseError(format!(
+                    "No {:?} threshold in rate line \"{}\"",
+                    filing_status,
+                    text.trim()
+                ))
+            })
     }
 
     /// Extracts a decimal number from a string containing currency formatting.
@@ -196,7 +738,7 @@ impl USFederalScraper {
     ///
     /// An Option containing the parsed Decimal if successful
     fn extract_number(&self, s: &str) -> Option<Decimal> {
-        let cleaned = s.trim().replace('$', "").replace(',', "").replace(" ", "");
+        let cleaned = s.trim().replace(['$', ',', ' '], "");
 
         if cleaned.chars().any(|c| c.is_numeric()) {
             Decimal::from_str_exact(&cleaned).ok()
@@ -206,6 +748,64 @@ impl USFederalScraper {
     }
 }
 
+/// Optional parenthetical giving the joint-filer threshold after a single-filer one.
+const JOINT_THRESHOLD_PATTERN: &str =
+    r"(?:\s*\(\$([0-9,]+)\s+for\s+married\s+couples\s+filing\s+jointly\))?";
+
+/// Returns the capture group holding the threshold for a filing status.
+///
+/// Group 2 is the single-filer threshold and group 3 the joint-filer
+/// threshold captured by [`JOINT_THRESHOLD_PATTERN`].
+fn threshold_group(filing_status: FilingStatus) -> usize {
+    match filing_status {
+        FilingStatus::MarriedFilingJointly => 3,
+        _ => 2,
+    }
+}
+
+/// Reads a response body, failing once it exceeds `limit` bytes.
+///
+/// The declared content length is checked up front, and the body is read in
+/// chunks so an oversized or unbounded response never gets fully buffered.
+pub(crate) async fn read_body_limited(
+    mut response: reqwest::Response,
+    limit: usize,
+) -> Result<String, TaxError> {
+    let too_large = || {
+        TaxError::FetchError(format!(
+            "Response body exceeds the maximum of {} bytes",
+            limit
+        ))
+    };
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > limit as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| TaxError::FetchError(e.to_string()))?
+    {
+        if body.len() + chunk.len() > limit {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+impl Default for USFederalScraper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl TaxRateScraper for USFederalScraper {
     /// Fetches and parses US federal tax rates for a given year.
@@ -214,12 +814,14 @@ impl TaxRateScraper for USFederalScraper {
     ///
     /// * `jurisdiction` - Must be Federal(USA)
     /// * `entity_type` - Must be Individual
+    /// * `filing_status` - Single or MarriedFilingJointly
     /// * `tax_year` - The tax year to fetch rates for
     ///
     /// # Returns
     ///
     /// A TaxSchedule containing the parsed brackets, or an error if:
     /// - The jurisdiction/entity type combination is not supported
+    /// - The filing status is not listed in the IRS announcement
     /// - The IRS website cannot be accessed
     /// - The tax bracket information cannot be parsed
     /// - No brackets are found for the specified year
@@ -227,21 +829,12 @@ impl TaxRateScraper for USFederalScraper {
         &self,
         jurisdiction: &Jurisdiction,
         entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
         tax_year: u16,
     ) -> Result<TaxSchedule, TaxError> {
-        match (jurisdiction, entity_type) {
-            (Jurisdiction::Federal(Country::USA), TaxEntityType::Individual) => {
-                let content = self.fetch_rates_from_irs(tax_year).await?;
-                let brackets = self.parse_tax_brackets(&content, tax_year)?;
-
-                if brackets.is_empty() {
-                    return Err(TaxError::RateNotAvailable(tax_year));
-                }
-
-                Ok(TaxSchedule::new(tax_year, brackets))
-            }
-            _ => Err(TaxError::UnsupportedJurisdiction),
-        }
+        self.fetch_rates_with_provenance(jurisdiction, entity_type, filing_status, tax_year)
+            .await
+            .map(|fetched| fetched.schedule)
     }
 
     /// Checks if this scraper supports the given jurisdiction.
@@ -251,3 +844,522 @@ impl TaxRateScraper for USFederalScraper {
         matches!(jurisdiction, Jurisdiction::Federal(Country::USA))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const NEWSROOM_HTML: &str = "<html><body>\
+        <p>The top tax rate remains 37% for individual single taxpayers \
+        with incomes greater than $609,350.</p>\
+        <p>35% for incomes over $243,725;</p>\
+        <p>The lowest rate is 10% for incomes of single individuals \
+        with incomes of $11,600 or less.</p>\
+        </body></html>";
+
+    #[tokio::test]
+    async fn test_fetch_with_provenance_reports_source_url() {
+        let mut server = mockito::Server::new_async().await;
+        let _first = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .with_status(404)
+            .create_async()
+            .await;
+        let _second = server
+            .mock("GET", "/pub/irs-drop/rp-2023-23.pdf")
+            .with_status(404)
+            .create_async()
+            .await;
+        let _third = server
+            .mock("GET", "/newsroom/tax-year-2024-inflation-adjustments")
+            .with_body(NEWSROOM_HTML)
+            .create_async()
+            .await;
+
+        let scraper = USFederalScraper::new().with_base_url(server.url());
+        let fetched = scraper
+            .fetch_rates_with_provenance(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fetched.source_url,
+            format!(
+                "{}/newsroom/tax-year-2024-inflation-adjustments",
+                server.url()
+            )
+        );
+        assert_eq!(fetched.warnings.len(), 2);
+        assert_eq!(fetched.schedule.brackets[0].rate, dec!(0.1));
+        assert!(fetched.fetched_at <= SystemTime::now());
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors_but_not_client_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/pub/irs-drop/rp-2023-23.pdf")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let third = server
+            .mock("GET", "/newsroom/tax-year-2024-inflation-adjustments")
+            .with_body(NEWSROOM_HTML)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let scraper = USFederalScraper::new()
+            .with_base_url(server.url())
+            .with_retry(3, Duration::from_millis(1));
+        let fetched = scraper
+            .fetch_rates_with_provenance(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        third.assert_async().await;
+        assert_eq!(fetched.warnings.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_are_network_error() {
+        // No mocks are registered, so every IRS URL returns a server error
+        let server = mockito::Server::new_async().await;
+        let scraper = USFederalScraper::new()
+            .with_base_url(server.url())
+            .with_retry(2, Duration::from_millis(1));
+
+        let result = scraper
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+
+        match result {
+            Err(TaxError::NetworkError(message)) => assert!(message.contains("after 6 attempts")),
+            other => panic!("expected a network error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_client_builder_keeps_defaults() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .match_header("x-request-source", "tax-engine-tests")
+            .match_header(
+                "user-agent",
+                mockito::Matcher::Regex("^Mozilla/5.0".to_string()),
+            )
+            .with_body(NEWSROOM_HTML)
+            .create_async()
+            .await;
+
+        let scraper = USFederalScraper::new()
+            .with_base_url(server.url())
+            .with_client_builder(|builder| {
+                let mut headers = reqwest::header::HeaderMap::new();
+                headers.insert(
+                    "x-request-source",
+                    reqwest::header::HeaderValue::from_static("tax-engine-tests"),
+                );
+                builder.default_headers(headers)
+            })
+            .unwrap();
+
+        scraper
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_joint_filers_use_parenthesized_thresholds() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .with_body(
+                "<html><body>\
+                 <p>35% for incomes over $243,725 ($487,450 for married couples \
+                 filing jointly);</p>\
+                 <p>The lowest rate is 10% for incomes of single individuals \
+                 with incomes of $11,600 or less ($23,200 for married couples \
+                 filing jointly).</p>\
+                 </body></html>",
+            )
+            .create_async()
+            .await;
+        let scraper = USFederalScraper::new().with_base_url(server.url());
+        let jurisdiction = Jurisdiction::Federal(Country::USA);
+
+        let joint = scraper
+            .fetch_rates(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            joint.filing_status,
+            Some(FilingStatus::MarriedFilingJointly)
+        );
+        assert_eq!(joint.brackets[0].upper_bound, Some(dec!(23200)));
+        assert_eq!(joint.brackets[1].lower_bound, dec!(487450));
+
+        let single = scraper
+            .fetch_rates(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(single.brackets[0].upper_bound, Some(dec!(11600)));
+        assert_eq!(single.brackets[1].lower_bound, dec!(243725));
+
+        let head_of_household = scraper
+            .fetch_rates(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::HeadOfHousehold,
+                2024,
+            )
+            .await;
+        assert!(matches!(
+            head_of_household,
+            Err(TaxError::UnsupportedFilingStatus(
+                FilingStatus::HeadOfHousehold
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_rate_set_holds_published_statuses() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .with_body(
+                "<html><body>\
+                 <p>35% for incomes over $243,725 ($487,450 for married couples \
+                 filing jointly);</p>\
+                 <p>The lowest rate is 10% for incomes of single individuals \
+                 with incomes of $11,600 or less ($23,200 for married couples \
+                 filing jointly).</p>\
+                 </body></html>",
+            )
+            .create_async()
+            .await;
+        let scraper = USFederalScraper::new().with_base_url(server.url());
+
+        let set = scraper
+            .fetch_rate_set(&Jurisdiction::Federal(Country::USA), 2024)
+            .await
+            .unwrap();
+        assert_eq!(set.tax_year, 2024);
+
+        let joint = set.get(FilingStatus::MarriedFilingJointly).unwrap();
+        assert_eq!(
+            joint.filing_status,
+            Some(FilingStatus::MarriedFilingJointly)
+        );
+        assert_eq!(joint.brackets[0].upper_bound, Some(dec!(23200)));
+        assert_eq!(joint.brackets[1].lower_bound, dec!(487450));
+
+        // The page only gives single and joint thresholds
+        assert!(set.get(FilingStatus::Single).is_some());
+        assert!(set.get(FilingStatus::HeadOfHousehold).is_none());
+        assert_eq!(set.schedules.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_joint_filers_reject_line_without_joint_threshold() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .with_body(
+                "<html><body>\
+                 <p>35% for incomes over $243,725 ($487,450 for married couples \
+                 filing jointly);</p>\
+                 <p>32% for incomes over $191,950;</p>\
+                 <p>The lowest rate is 10% for incomes of single individuals \
+                 with incomes of $11,600 or less ($23,200 for married couples \
+                 filing jointly).</p>\
+                 </body></html>",
+            )
+            .create_async()
+            .await;
+        let scraper = USFederalScraper::new().with_base_url(server.url());
+        let jurisdiction = Jurisdiction::Federal(Country::USA);
+
+        // Dropping the 32% line for joint filers would leave a gap
+        let joint = scraper
+            .fetch_rates(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await;
+        assert!(
+            matches!(joint, Err(TaxError::ParseError(message)) if message.contains("$191,950"))
+        );
+
+        let single = scraper
+            .fetch_rates(
+                &jurisdiction,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(single.brackets.len(), 3);
+    }
+
+    /// Fallback that always serves a flat 10% schedule.
+    struct FlatRateSource;
+
+    #[async_trait]
+    impl TaxRateScraper for FlatRateSource {
+        async fn fetch_rates(
+            &self,
+            _jurisdiction: &Jurisdiction,
+            _entity_type: &TaxEntityType,
+            _filing_status: FilingStatus,
+            tax_year: u16,
+        ) -> Result<TaxSchedule, TaxError> {
+            Ok(TaxSchedule::new(
+                tax_year,
+                vec![TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: None,
+                    rate: dec!(0.10),
+                }],
+            ))
+        }
+
+        fn supports_jurisdiction(&self, _jurisdiction: &Jurisdiction) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_when_fetch_fails() {
+        // No mocks are registered, so every IRS URL fails
+        let server = mockito::Server::new_async().await;
+        let scraper = USFederalScraper::new()
+            .with_base_url(server.url())
+            .with_fallback(FlatRateSource);
+
+        let fetched = scraper
+            .fetch_rates_with_provenance(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+
+        assert!(fetched.estimated);
+        assert_eq!(fetched.source_url, FALLBACK_SOURCE);
+        assert_eq!(fetched.schedule.tax_year, 2024);
+        assert_eq!(fetched.schedule.brackets[0].rate, dec!(0.10));
+        assert_eq!(fetched.warnings.len(), 1);
+
+        let unsupported = scraper
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::Canada),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+        assert!(matches!(
+            unsupported,
+            Err(TaxError::UnsupportedJurisdiction)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_implausible_rate() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock(
+                "GET",
+                "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+            )
+            .with_body(
+                "<html><body>\
+                 <p>350% for incomes over $243,725;</p>\
+                 <p>The lowest rate is 10% for incomes of single individuals \
+                 with incomes of $11,600 or less.</p>\
+                 </body></html>",
+            )
+            .create_async()
+            .await;
+
+        let result = USFederalScraper::new()
+            .with_base_url(server.url())
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+
+        match result {
+            Err(TaxError::ParseError(message)) => assert!(message.contains("3.5")),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_body_within_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/rates")
+            .with_body("10% for incomes over $11,600")
+            .create_async()
+            .await;
+
+        let response = reqwest::get(format!("{}/rates", server.url()))
+            .await
+            .unwrap();
+        let body = read_body_limited(response, 1024).await.unwrap();
+        assert_eq!(body, "10% for incomes over $11,600");
+    }
+
+    #[tokio::test]
+    async fn test_read_body_rejects_declared_oversize() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/rates")
+            .with_body(vec![b'a'; 2048])
+            .create_async()
+            .await;
+
+        let response = reqwest::get(format!("{}/rates", server.url()))
+            .await
+            .unwrap();
+        let result = read_body_limited(response, 1024).await;
+        assert!(matches!(result, Err(TaxError::FetchError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_read_body_rejects_streamed_oversize() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/rates")
+            .with_chunked_body(|writer| {
+                for _ in 0..64 {
+                    writer.write_all(&[b'a'; 512])?;
+                }
+                Ok(())
+            })
+            .create_async()
+            .await;
+
+        let response = reqwest::get(format!("{}/rates", server.url()))
+            .await
+            .unwrap();
+        assert_eq!(response.content_length(), None);
+        let result = read_body_limited(response, 4096).await;
+        assert!(matches!(result, Err(TaxError::FetchError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_corporations_get_flat_rate_schedule() {
+        // No server: the corporate schedule must not need the network
+        let scraper = USFederalScraper::new().with_base_url("http://127.0.0.1:9");
+        let usa = Jurisdiction::Federal(Country::USA);
+
+        let schedule = scraper
+            .fetch_rates(
+                &usa,
+                &TaxEntityType::Corporation,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(schedule.brackets.len(), 1);
+        assert_eq!(schedule.brackets[0].rate, dec!(0.21));
+        assert_eq!(schedule.brackets[0].upper_bound, None);
+        assert_eq!(schedule.filing_status, None);
+
+        let before_flat_rate = scraper
+            .fetch_rates(
+                &usa,
+                &TaxEntityType::Corporation,
+                FilingStatus::Single,
+                2017,
+            )
+            .await;
+        assert!(matches!(
+            before_flat_rate,
+            Err(TaxError::RateNotAvailable(2017))
+        ));
+
+        let partnership = scraper
+            .fetch_rates(
+                &usa,
+                &TaxEntityType::Partnership,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+        assert!(matches!(partnership, Err(TaxError::Incompatible { .. })));
+    }
+}
@@ -0,0 +1,317 @@
+//! US Federal tax rates from a structured JSON endpoint.
+//!
+//! Provides an alternative to HTML scraping for deployments that have access
+//! to a JSON service publishing tax brackets. The endpoint is supplied by the
+//! caller, since there is no official IRS API to default to.
+
+use super::us_federal::{read_body_limited, DEFAULT_MAX_RESPONSE_BYTES};
+use super::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{Country, FilingStatus, Jurisdiction, TaxBracket, TaxEntityType, TaxSchedule};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Placeholder in the endpoint URL that is replaced with the requested tax year.
+pub const YEAR_PLACEHOLDER: &str = "{year}";
+
+/// Placeholder in the endpoint URL that is replaced with the requested filing
+/// status, written in snake case (e.g., `married_filing_jointly`).
+pub const FILING_STATUS_PLACEHOLDER: &str = "{filing_status}";
+
+/// JSON shape of a tax schedule served by the endpoint.
+#[derive(Debug, Deserialize)]
+struct ApiSchedule {
+    tax_year: u16,
+    brackets: Vec<ApiBracket>,
+}
+
+/// JSON shape of a single bracket. Decimals may be strings or numbers.
+#[derive(Debug, Deserialize)]
+struct ApiBracket {
+    lower_bound: Decimal,
+    upper_bound: Option<Decimal>,
+    rate: Decimal,
+}
+
+/// Rate source that deserializes US federal brackets from a JSON endpoint.
+///
+/// The endpoint must return a document of the form:
+///
+/// ```json
+/// {
+///   "tax_year": 2024,
+///   "brackets": [
+///     { "lower_bound": "0", "upper_bound": "11600", "rate": "0.10" },
+///     { "lower_bound": "11600", "upper_bound": null, "rate": "0.12" }
+///   ]
+/// }
+/// ```
+pub struct UsFederalApiSource {
+    client: reqwest::Client,
+    endpoint: String,
+}
+
+impl UsFederalApiSource {
+    /// Creates a new source reading from the given endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - URL of the JSON service. Any `{year}` placeholder is
+    ///   replaced with the requested tax year, and any `{filing_status}`
+    ///   placeholder with the requested filing status. An endpoint without a
+    ///   `{filing_status}` placeholder is assumed to serve single-filer brackets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::UsFederalApiSource;
+    ///
+    /// let source = UsFederalApiSource::new("https://rates.example.com/us/federal/{year}.json");
+    /// ```
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            endpoint: endpoint.into(),
+        }
+    }
+
+    /// Fetches and deserializes the schedule document for a year and filing status.
+    async fn fetch_schedule(
+        &self,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<ApiSchedule, TaxError> {
+        if !self.endpoint.contains(FILING_STATUS_PLACEHOLDER)
+            && filing_status != FilingStatus::Single
+        {
+            return Err(TaxError::UnsupportedFilingStatus(filing_status));
+        }
+
+        let url = self
+            .endpoint
+            .replace(YEAR_PLACEHOLDER, &tax_year.to_string())
+            .replace(FILING_STATUS_PLACEHOLDER, filing_status_slug(filing_status));
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| TaxError::NetworkError(e.to_string()))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(TaxError::RateNotAvailable(tax_year));
+        }
+        if !response.status().is_success() {
+            return Err(TaxError::FetchError(format!(
+                "Unexpected status {} from {}",
+                response.status(),
+                url
+            )));
+        }
+
+        let body = read_body_limited(response, DEFAULT_MAX_RESPONSE_BYTES).await?;
+        serde_json::from_str(&body).map_err(|e| TaxError::ParseError(e.to_string()))
+    }
+}
+
+/// Returns the snake case name substituted for [`FILING_STATUS_PLACEHOLDER`].
+fn filing_status_slug(filing_status: FilingStatus) -> &'static str {
+    match filing_status {
+        FilingStatus::Single => "single",
+        FilingStatus::MarriedFilingJointly => "married_filing_jointly",
+        FilingStatus::MarriedFilingSeparately => "married_filing_separately",
+        FilingStatus::HeadOfHousehold => "head_of_household",
+    }
+}
+
+#[async_trait]
+impl TaxRateScraper for UsFederalApiSource {
+    /// Fetches US federal tax rates from the configured JSON endpoint.
+    ///
+    /// # Returns
+    ///
+    /// A TaxSchedule built from the endpoint's brackets, or an error if:
+    /// - The jurisdiction/entity type combination is not supported
+    /// - The filing status is not single and the endpoint has no
+    ///   `{filing_status}` placeholder
+    /// - The endpoint cannot be reached or has no data for the year
+    /// - The response is not valid JSON in the expected shape
+    /// - The response is for a different tax year or contains no brackets
+    async fn fetch_rates(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        match (jurisdiction, entity_type) {
+            (Jurisdiction::Federal(Country::USA), TaxEntityType::Individual) => {
+                let schedule = self.fetch_schedule(filing_status, tax_year).await?;
+
+                if schedule.tax_year != tax_year || schedule.brackets.is_empty() {
+                    return Err(TaxError::RateNotAvailable(tax_year));
+                }
+
+                let brackets = schedule
+                    .brackets
+                    .into_iter()
+                    .map(|b| TaxBracket {
+                        lower_bound: b.lower_bound,
+                        upper_bound: b.upper_bound,
+                        rate: b.rate,
+                    })
+                    .collect();
+
+                Ok(TaxSchedule::new(tax_year, brackets)
+                    .with_filing_status(filing_status)
+                    .normalized())
+            }
+            _ => Err(TaxError::UnsupportedJurisdiction),
+        }
+    }
+
+    /// Checks if this source supports the given jurisdiction.
+    ///
+    /// Currently only supports US Federal jurisdiction.
+    fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+        matches!(jurisdiction, Jurisdiction::Federal(Country::USA))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const SCHEDULE_JSON: &str = r#"{
+        "tax_year": 2024,
+        "brackets": [
+            { "lower_bound": "11600", "upper_bound": null, "rate": "0.12" },
+            { "lower_bound": "0", "upper_bound": "11600", "rate": 0.10 }
+        ]
+    }"#;
+
+    #[tokio::test]
+    async fn test_fetch_rates_from_json() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/federal/2024.json")
+            .with_header("content-type", "application/json")
+            .with_body(SCHEDULE_JSON)
+            .create_async()
+            .await;
+
+        let source = UsFederalApiSource::new(format!("{}/federal/{{year}}.json", server.url()));
+        let schedule = source
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(schedule.tax_year, 2024);
+        assert_eq!(schedule.brackets.len(), 2);
+        assert_eq!(schedule.brackets[0].rate, dec!(0.10));
+        assert_eq!(schedule.brackets[0].upper_bound, Some(dec!(11600)));
+        assert_eq!(schedule.brackets[1].lower_bound, dec!(11600));
+        assert_eq!(schedule.brackets[1].upper_bound, None);
+    }
+
+    #[tokio::test]
+    async fn test_missing_year_is_not_available() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/federal/1990.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let source = UsFederalApiSource::new(format!("{}/federal/{{year}}.json", server.url()));
+        let result = source
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                1990,
+            )
+            .await;
+
+        assert!(matches!(result, Err(TaxError::RateNotAvailable(1990))));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_is_parse_error() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/federal/2024.json")
+            .with_body(r#"{ "tax_year": 2024, "brackets": [ { "rate": "abc" } ] }"#)
+            .create_async()
+            .await;
+
+        let source = UsFederalApiSource::new(format!("{}/federal/{{year}}.json", server.url()));
+        let result = source
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+
+        assert!(matches!(result, Err(TaxError::ParseError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_filing_status_placeholder() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/federal/2024/married_filing_jointly.json")
+            .with_body(SCHEDULE_JSON)
+            .create_async()
+            .await;
+
+        let source = UsFederalApiSource::new(format!(
+            "{}/federal/{{year}}/{{filing_status}}.json",
+            server.url()
+        ));
+        let schedule = source
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            schedule.filing_status,
+            Some(FilingStatus::MarriedFilingJointly)
+        );
+
+        // Without the placeholder the endpoint is assumed to serve single filers
+        let single_only =
+            UsFederalApiSource::new(format!("{}/federal/{{year}}.json", server.url()));
+        let result = single_only
+            .fetch_rates(
+                &Jurisdiction::Federal(Country::USA),
+                &TaxEntityType::Individual,
+                FilingStatus::HeadOfHousehold,
+                2024,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(TaxError::UnsupportedFilingStatus(
+                FilingStatus::HeadOfHousehold
+            ))
+        ));
+    }
+}
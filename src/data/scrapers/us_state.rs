@@ -0,0 +1,506 @@
+//! US state tax rate scraping implementation.
+//!
+//! Provides functionality to fetch and parse state income tax rate schedules
+//! from state tax agency websites. California's Franchise Tax Board and New
+//! York's Department of Taxation and Finance both publish their schedules as
+//! HTML tables with one row per bracket, such as
+//! "$10,756 | $25,499 | $107.56 plus 2.00% | $10,756".
+
+use super::us_federal::{read_body_limited, DEFAULT_MAX_RESPONSE_BYTES};
+use super::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, Jurisdiction, TaxBracket, TaxEntityType, TaxSchedule, USState};
+use async_trait::async_trait;
+use regex::Regex;
+use rust_decimal::Decimal;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+
+/// Placeholder in a page URL that is replaced with the requested tax year.
+pub const YEAR_PLACEHOLDER: &str = "{year}";
+
+/// Scraper implementation for US state income tax rates.
+///
+/// Each supported state's rate schedule page is fetched from a default URL,
+/// which can be replaced with [`USStateScraper::with_page_url`] when an
+/// agency reorganizes its website or to read from a mirror.
+pub struct USStateScraper {
+    client: reqwest::Client,
+    page_urls: HashMap<USState, String>,
+}
+
+impl USStateScraper {
+    /// Creates a new USStateScraper instance with a configured HTTP client.
+    ///
+    /// The client is configured with a 10-second timeout, falling back to the
+    /// default client if custom configuration fails.
+    pub fn new() -> Self {
+        let page_urls = USState::ALL
+            .into_iter()
+            .filter_map(|state| Some((state, default_page_url(&state)?.to_string())))
+            .collect();
+
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            page_urls,
+        }
+    }
+
+    /// Sets the URL of the page listing a state's rate schedules.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state whose page should be replaced
+    /// * `url` - URL of the rate schedule page. Any `{year}` placeholder is
+    ///   replaced with the requested tax year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::USState;
+    /// use tax_engine::data::scrapers::us_state::USStateScraper;
+    ///
+    /// let scraper = USStateScraper::new()
+    ///     .with_page_url(USState::NewYork, "https://mirror.example.com/ny/{year}.html");
+    /// ```
+    pub fn with_page_url(mut self, state: USState, url: impl Into<String>) -> Self {
+        self.page_urls.insert(state, url.into());
+        self
+    }
+
+    /// Fetches a state's rate schedule page for a year.
+    async fn fetch_rates_page(&self, state: &USState, year: u16) -> Result<String, TaxError> {
+        let url = self
+            .page_urls
+            .get(state)
+            .ok_or(TaxError::UnsupportedJurisdiction)?
+            .replace(YEAR_PLACEHOLDER, &year.to_string());
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| TaxError::NetworkError(e.to_string()))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(TaxError::RateNotAvailable(year));
+        }
+        if !response.status().is_success() {
+            return Err(TaxError::FetchError(format!(
+                "Unexpected status {} from {}",
+                response.status(),
+                url
+            )));
+        }
+
+        read_body_limited(response, DEFAULT_MAX_RESPONSE_BYTES).await
+    }
+
+    /// Parses the brackets for one filing status from a rate schedule page.
+    ///
+    /// Only table rows following a heading or table caption that names the
+    /// filing status's schedule are considered, and the section ends at the
+    /// next heading or caption.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The HTML content of the rate schedule page
+    /// * `state` - The state the page belongs to
+    /// * `filing_status` - The filing status whose schedule should be parsed
+    /// * `year` - The tax year, which the page must mention
+    ///
+    /// # Returns
+    ///
+    /// The parsed brackets, `TaxError::RateNotAvailable` if the page does not
+    /// mention the year, or `TaxError::ParseError` if it has no schedule for
+    /// the filing status.
+    fn parse_tax_brackets(
+        &self,
+        content: &str,
+        state: &USState,
+        filing_status: FilingStatus,
+        year: u16,
+    ) -> Result<Vec<TaxBracket>, TaxError> {
+        let document = Html::parse_document(content);
+        if !document
+            .root_element()
+            .text()
+            .any(|text| text.contains(&year.to_string()))
+        {
+            return Err(TaxError::RateNotAvailable(year));
+        }
+
+        let label = schedule_label(state, filing_status);
+        let mut in_section = false;
+        let mut brackets = Vec::new();
+        for element in document.select(&Selector::parse("h2,h3,h4,caption,tr").unwrap()) {
+            if element.value().name() == "tr" {
+                if in_section {
+                    brackets.extend(self.parse_rate_row(element));
+                }
+                continue;
+            }
+
+            let text = element.text().collect::<String>().to_lowercase();
+            in_section = text.contains(label);
+        }
+
+        if brackets.is_empty() {
+            return Err(TaxError::ParseError(format!(
+                "Could not find the {:?} schedule for {:?}",
+                filing_status, state
+            )));
+        }
+        Ok(brackets)
+    }
+
+    /// Parses a table row describing one bracket.
+    ///
+    /// The first cell holds the bracket's lower bound and the second its upper
+    /// bound, or text such as "And over" for the top bracket. The rate is the
+    /// last percentage in the row, since earlier cells may hold the tax on
+    /// income below the bracket.
+    ///
+    /// # Returns
+    ///
+    /// An Option containing a TaxBracket if the row has a lower bound and a rate
+    fn parse_rate_row(&self, row: ElementRef) -> Option<TaxBracket> {
+        let cells: Vec<String> = row
+            .select(&Selector::parse("td,th").unwrap())
+            .map(|cell| cell.text().collect::<String>())
+            .collect();
+        if cells.len() < 3 {
+            return None;
+        }
+
+        let amount = Regex::new(r"\$\s*([0-9][0-9,]*(?:\.[0-9]+)?)").ok()?;
+        let percent = Regex::new(r"([0-9]+(?:\.[0-9]+)?)\s*%").ok()?;
+
+        let lower_bound = self.extract_number(amount.captures(&cells[0])?.get(1)?.as_str())?;
+        let upper_bound = match amount.captures(&cells[1]) {
+            Some(caps) => Some(self.extract_number(caps.get(1)?.as_str())?),
+            None => None,
+        };
+        let rate = percent
+            .captures_iter(&cells.join(" "))
+            .last()
+            .and_then(|caps| Decimal::from_str_exact(caps.get(1)?.as_str()).ok())?
+            / Decimal::ONE_HUNDRED;
+
+        Some(TaxBracket {
+            lower_bound,
+            upper_bound,
+            rate,
+        })
+    }
+
+    /// Extracts a decimal number from a dollar amount such as "10,756".
+    fn extract_number(&self, s: &str) -> Option<Decimal> {
+        Decimal::from_str_exact(&s.replace(',', "")).ok()
+    }
+}
+
+/// Returns the default rate schedule page for a state, if one is known.
+fn default_page_url(state: &USState) -> Option<&'static str> {
+    match state {
+        USState::California => {
+            Some("https://www.ftb.ca.gov/forms/{year}-california-tax-rates-and-exemptions.html")
+        }
+        USState::NewYork => Some("https://www.tax.ny.gov/forms/current-forms/it/it201i.htm"),
+        _ => None,
+    }
+}
+
+/// Returns whether a state taxes wage income in a year.
+///
+/// Washington's tax on capital gains is not an income tax on wages, and New
+/// Hampshire's tax on interest and dividends was repealed from 2025.
+fn levies_income_tax(state: &USState, year: u16) -> bool {
+    match state {
+        USState::Alaska
+        | USState::Florida
+        | USState::Nevada
+        | USState::SouthDakota
+        | USState::Tennessee
+        | USState::Texas
+        | USState::Washington
+        | USState::Wyoming => false,
+        USState::NewHampshire => year < 2025,
+        _ => true,
+    }
+}
+
+/// Returns the lowercased text that identifies a filing status's schedule on
+/// a state's page.
+///
+/// California names its schedules by letter; other states are assumed to
+/// title each schedule with the filing status.
+fn schedule_label(state: &USState, filing_status: FilingStatus) -> &'static str {
+    match (state, filing_status) {
+        (USState::California, FilingStatus::MarriedFilingJointly) => "schedule y",
+        (USState::California, FilingStatus::HeadOfHousehold) => "schedule z",
+        (USState::California, _) => "schedule x",
+        (_, FilingStatus::MarriedFilingJointly) => "married filing jointly",
+        (_, FilingStatus::HeadOfHousehold) => "head of household",
+        (_, _) => "single",
+    }
+}
+
+impl Default for USStateScraper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TaxRateScraper for USStateScraper {
+    /// Fetches and parses a state's income tax rates for a given year.
+    ///
+    /// States without an income tax, such as Texas and Florida, yield a
+    /// schedule with no brackets, which taxes all income at zero, without
+    /// any network access.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - Must be a USState with a configured page or no
+    ///   income tax
+    /// * `entity_type` - Must be Individual
+    /// * `filing_status` - The filing status whose schedule should be parsed;
+    ///   married people filing separately use the single filers' schedule
+    /// * `tax_year` - The tax year to fetch rates for
+    ///
+    /// # Returns
+    ///
+    /// A TaxSchedule for the filing status, or an error if:
+    /// - The jurisdiction/entity type combination is not supported
+    /// - The state's website cannot be accessed
+    /// - The page has no rates for the specified year
+    /// - The tax bracket information cannot be parsed or is inconsistent
+    async fn fetch_rates(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        match (jurisdiction, entity_type) {
+            (Jurisdiction::USState(state), TaxEntityType::Individual)
+                if !levies_income_tax(state, tax_year) =>
+            {
+                Ok(TaxSchedule::new(tax_year, Vec::new()).with_filing_status(filing_status))
+            }
+            (Jurisdiction::USState(state), TaxEntityType::Individual)
+                if self.page_urls.contains_key(state) =>
+            {
+                let content = self.fetch_rates_page(state, tax_year).await?;
+                let brackets = self.parse_tax_brackets(&content, state, filing_status, tax_year)?;
+
+                let schedule = TaxSchedule::new_validated(tax_year, brackets).map_err(|e| {
+                    TaxError::ParseError(format!("Parsed brackets are inconsistent: {}", e))
+                })?;
+                Ok(schedule.with_filing_status(filing_status).normalized())
+            }
+            _ => Err(TaxError::UnsupportedJurisdiction),
+        }
+    }
+
+    /// Checks if this scraper supports the given jurisdiction.
+    ///
+    /// Supports every US state with a configured rate schedule page, and
+    /// every state that does not currently tax wage income.
+    fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+        match jurisdiction {
+            Jurisdiction::USState(state) => {
+                // Support doesn't depend on the year, so ask about the present
+                self.page_urls.contains_key(state) || !levies_income_tax(state, u16::MAX)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Country;
+    use rust_decimal_macros::dec;
+
+    const CALIFORNIA_HTML: &str = "<html><body>\
+        <h2>2024 California tax rate schedules</h2>\
+        <table><caption>Schedule X - Single or married/RDP filing separately</caption>\
+        <tr><th>If the taxable income is over</th><th>But not over</th><th>Tax is</th></tr>\
+        <tr><td>$0</td><td>$10,756</td><td>$0.00 plus 1.00%</td><td>$0</td></tr>\
+        <tr><td>$10,756</td><td>$25,499</td><td>$107.56 plus 2.00%</td><td>$10,756</td></tr>\
+        <tr><td>$25,499</td><td>And over</td><td>$402.42 plus 4.00%</td><td>$25,499</td></tr>\
+        </table>\
+        <table><caption>Schedule Y - Married/RDP filing jointly</caption>\
+        <tr><td>$0</td><td>$21,512</td><td>$0.00 plus 1.00%</td><td>$0</td></tr>\
+        <tr><td>$21,512</td><td>And over</td><td>$215.12 plus 2.00%</td><td>$21,512</td></tr>\
+        </table>\
+        </body></html>";
+
+    const NEW_YORK_HTML: &str = "<html><body>\
+        <h2>New York State tax rate schedule for 2024</h2>\
+        <h3>Single and married filing separately</h3>\
+        <table>\
+        <tr><td>$0</td><td>$8,500</td><td>4% of line 38</td></tr>\
+        <tr><td>$8,500</td><td>$11,700</td><td>$340 plus 4.5% of the excess over $8,500</td></tr>\
+        <tr><td>$11,700</td><td>or more</td><td>$484 plus 5.25% of the excess over $11,700</td></tr>\
+        </table>\
+        <h3>Head of household</h3>\
+        <table>\
+        <tr><td>$0</td><td>$12,800</td><td>4% of line 38</td></tr>\
+        </table>\
+        </body></html>";
+
+    async fn scraper_for(
+        state: USState,
+        path: &str,
+        body: &str,
+    ) -> (mockito::ServerGuard, USStateScraper) {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", path)
+            .with_body(body)
+            .create_async()
+            .await;
+        let scraper = USStateScraper::new()
+            .with_page_url(state, format!("{}/rates/{{year}}.html", server.url()));
+        (server, scraper)
+    }
+
+    #[tokio::test]
+    async fn test_parses_california_schedule_for_filing_status() {
+        let (_server, scraper) =
+            scraper_for(USState::California, "/rates/2024.html", CALIFORNIA_HTML).await;
+        let california = Jurisdiction::USState(USState::California);
+
+        let single = scraper
+            .fetch_rates(
+                &california,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(single.brackets.len(), 3);
+        assert_eq!(single.brackets[1].lower_bound, dec!(10756));
+        assert_eq!(single.brackets[1].upper_bound, Some(dec!(25499)));
+        assert_eq!(single.brackets[1].rate, dec!(0.02));
+        assert_eq!(single.brackets[2].upper_bound, None);
+
+        let joint = scraper
+            .fetch_rates(
+                &california,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            joint.filing_status,
+            Some(FilingStatus::MarriedFilingJointly)
+        );
+        assert_eq!(joint.brackets.len(), 2);
+        assert_eq!(joint.brackets[0].upper_bound, Some(dec!(21512)));
+    }
+
+    #[tokio::test]
+    async fn test_parses_new_york_schedule() {
+        let (_server, scraper) =
+            scraper_for(USState::NewYork, "/rates/2024.html", NEW_YORK_HTML).await;
+        let new_york = Jurisdiction::USState(USState::NewYork);
+
+        let schedule = scraper
+            .fetch_rates(
+                &new_york,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingSeparately,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert_eq!(schedule.brackets.len(), 3);
+        assert_eq!(schedule.brackets[0].rate, dec!(0.04));
+        assert_eq!(schedule.brackets[1].rate, dec!(0.045));
+        assert_eq!(schedule.brackets[2].rate, dec!(0.0525));
+
+        let joint = scraper
+            .fetch_rates(
+                &new_york,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await;
+        assert!(matches!(joint, Err(TaxError::ParseError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_missing_year_and_other_jurisdictions() {
+        let (_server, scraper) =
+            scraper_for(USState::California, "/rates/2019.html", CALIFORNIA_HTML).await;
+
+        let stale = scraper
+            .fetch_rates(
+                &Jurisdiction::USState(USState::California),
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2019,
+            )
+            .await;
+        assert!(matches!(stale, Err(TaxError::RateNotAvailable(2019))));
+
+        let federal = Jurisdiction::Federal(Country::USA);
+        assert!(!scraper.supports_jurisdiction(&federal));
+        let result = scraper
+            .fetch_rates(
+                &federal,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+        assert!(matches!(result, Err(TaxError::UnsupportedJurisdiction)));
+    }
+
+    #[tokio::test]
+    async fn test_states_without_income_tax_have_no_brackets() {
+        let scraper = USStateScraper::new();
+        let texas = Jurisdiction::USState(USState::Texas);
+        assert!(scraper.supports_jurisdiction(&texas));
+
+        let schedule = scraper
+            .fetch_rates(
+                &texas,
+                &TaxEntityType::Individual,
+                FilingStatus::MarriedFilingJointly,
+                2024,
+            )
+            .await
+            .unwrap();
+        assert!(schedule.brackets.is_empty());
+        assert_eq!(
+            schedule.filing_status,
+            Some(FilingStatus::MarriedFilingJointly)
+        );
+
+        let ohio = Jurisdiction::USState(USState::Ohio);
+        assert!(!scraper.supports_jurisdiction(&ohio));
+        let result = scraper
+            .fetch_rates(
+                &ohio,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+        assert!(matches!(result, Err(TaxError::UnsupportedJurisdiction)));
+    }
+}
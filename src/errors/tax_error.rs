@@ -3,6 +3,7 @@
 //! This module uses the `thiserror` crate to provide detailed error
 //! handling with proper error message formatting and type safety.
 
+use crate::models::FilingStatus;
 use thiserror::Error;
 
 /// Represents all possible errors that can occur during tax operations.
@@ -10,19 +11,32 @@ use thiserror::Error;
 pub enum TaxError {
     /// Occurs when the tax year of an entity doesn't match its tax schedule.
     ///
-    /// This typically happens when trying to calculate taxes using a schedule
-    /// from a different year than the entity's tax year.
+    /// The calculators report year mismatches as `TaxError::Incompatible`,
+    /// alongside the other attribute mismatches; this variant is kept so that
+    /// existing code matching on it still compiles.
     #[error("Tax year mismatch between entity and schedule")]
     YearMismatch,
 
+    /// Occurs when an entity and a schedule differ in an attribute such as the
+    /// tax year, filing status or currency.
+    ///
+    /// The reason names the mismatched attribute and both values.
+    #[error("Entity and schedule are incompatible: {reason}")]
+    Incompatible {
+        /// Which attribute mismatched, with the entity's and schedule's values
+        reason: String,
+    },
+
     /// Indicates invalid tax bracket configuration.
     ///
+    /// The String parameter describes the problem and the brackets involved.
     /// This can occur when:
     /// - Brackets overlap
     /// - Brackets have gaps
     /// - Bracket rates are invalid (e.g., negative or over 100%)
-    #[error("Invalid tax bracket configuration")]
-    InvalidBrackets,
+    /// - More than one bracket has no upper bound
+    #[error("Invalid tax bracket configuration: {0}")]
+    InvalidBrackets(String),
 
     /// Represents failures when fetching tax data from external sources.
     ///
@@ -49,6 +63,33 @@ pub enum TaxError {
     #[error("Unsupported jurisdiction")]
     UnsupportedJurisdiction,
 
+    /// Indicates that a string does not name a jurisdiction.
+    ///
+    /// The String parameter describes what was wrong with the input, such as
+    /// an unknown country prefix or state abbreviation.
+    #[error("Invalid jurisdiction: {0}")]
+    InvalidJurisdiction(String),
+
+    /// Indicates that ownership shares cannot be used to split income.
+    ///
+    /// The String parameter describes the problem, such as shares that don't
+    /// add up to the whole.
+    #[error("Invalid ownership shares: {0}")]
+    InvalidOwnership(String),
+
+    /// Indicates that a builder was finished without a required field.
+    ///
+    /// The parameter names the missing field.
+    #[error("Required field not set: {0}")]
+    MissingField(&'static str),
+
+    /// Indicates that a rate source cannot supply brackets for a filing status.
+    ///
+    /// This occurs when a source only publishes thresholds for some filing
+    /// statuses, such as single and joint filers.
+    #[error("Unsupported filing status: {0:?}")]
+    UnsupportedFilingStatus(FilingStatus),
+
     /// Indicates that tax rates are not available for a specific year.
     ///
     /// The u16 parameter specifies which tax year's rates were unavailable.
@@ -59,6 +100,20 @@ pub enum TaxError {
     #[error("Rate not available for year {0}")]
     RateNotAvailable(u16),
 
+    /// Indicates that a cache could not store an entry.
+    ///
+    /// The String parameter provides details about the underlying failure,
+    /// such as an unwritable cache directory.
+    #[error("Cache error: {0}")]
+    CacheError(String),
+
+    /// Indicates that a calculation could not be recorded in an audit log.
+    ///
+    /// The String parameter provides details about the underlying failure,
+    /// such as an unwritable log file.
+    #[error("Audit log error: {0}")]
+    AuditError(String),
+
     /// Represents network-related errors during operations.
     ///
     /// The String parameter provides details about the specific network error.
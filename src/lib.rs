@@ -13,10 +13,45 @@
 //!
 //! # Example
 //!
+//! Calculating tax against a known schedule runs entirely offline:
+//!
 //! ```rust
 //! use tax_engine::{
-//!     Country, IncomeTaxCalculator, Jurisdiction, TaxEntity,
-//!     TaxEntityType, USFederalScraper
+//!     DeductionType, IncomeTaxCalculator, TaxBracket, TaxEntity, TaxEntityType, TaxSchedule,
+//! };
+//! use rust_decimal_macros::dec;
+//!
+//! // Create a tax entity with $75,000 income and a $5,000 personal deduction
+//! let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(75000), 2024);
+//! entity.add_deduction(dec!(5000), DeductionType::Personal);
+//!
+//! let schedule = TaxSchedule::new(
+//!     2024,
+//!     vec![
+//!         TaxBracket {
+//!             lower_bound: dec!(0),
+//!             upper_bound: Some(dec!(50000)),
+//!             rate: dec!(0.10),
+//!         },
+//!         TaxBracket {
+//!             lower_bound: dec!(50000),
+//!             upper_bound: None,
+//!             rate: dec!(0.20),
+//!         },
+//!     ],
+//! );
+//!
+//! let tax = IncomeTaxCalculator::calculate_tax(&entity, &schedule)?;
+//! assert_eq!(tax, dec!(9000));
+//! # Ok::<(), tax_engine::TaxError>(())
+//! ```
+//!
+//! Current rates can also be fetched from official sources (requires network access):
+//!
+//! ```rust,no_run
+//! use tax_engine::{
+//!     Country, DeductionType, IncomeTaxCalculator, Jurisdiction, TaxEntity,
+//!     TaxEntityType, TaxRateScraper, USFederalScraper
 //! };
 //! use rust_decimal_macros::dec;
 //!
@@ -38,6 +73,7 @@
 //!         .fetch_rates(
 //!             &Jurisdiction::Federal(Country::USA),
 //!             &TaxEntityType::Individual,
+//!             entity.filing_status,
 //!             2024
 //!         )
 //!         .await?;
@@ -52,24 +88,38 @@
 //!
 //! # Module Organization
 //!
+//! - `audit`: Audit trail of completed calculations
 //! - `calculators`: Tax calculation implementations
 //! - `data`: Data fetching and caching
 //! - `errors`: Error types and handling
 //! - `models`: Core domain models
+//! - `testing`: Test doubles, with the `test-util` feature
 //! - `utils`: Utility functions
 
+pub mod audit;
 pub mod calculators;
 pub mod data;
 pub mod errors;
 pub mod models;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
 pub mod utils;
 
 // Re-export commonly used items
-pub use calculators::IncomeTaxCalculator;
+pub use calculators::{
+    IncomeTaxCalculator, MarginalRateCalculator, MarriedFilingSeparatelyCalculator,
+    PayrollParameters,
+};
 pub use data::cache::memory::MemoryCache;
-pub use data::scrapers::{us_federal::USFederalScraper, TaxRateScraper};
+pub use data::provider::TaxDataProvider;
+pub use data::scrapers::{
+    canada_federal::CanadaFederalScraper, composite::CompositeScraper, file::FileScraper,
+    us_federal::USFederalScraper, us_federal_api::UsFederalApiSource, us_state::USStateScraper,
+    FetchedSchedule, TaxRateScraper,
+};
 pub use errors::TaxError;
 pub use models::{
-    Country, DeductionType, Jurisdiction, TaxBracket, TaxEntity, TaxEntityType, TaxSchedule,
+    Country, DeductionType, FilingStatus, Jurisdiction, TaxBracket, TaxEntity, TaxEntityType,
+    TaxSchedule, TaxScheduleSet,
 };
 pub use utils::currency::format_currency;
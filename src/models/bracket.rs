@@ -3,26 +3,124 @@
 //! This module provides the core structures for representing tax brackets
 //! and organizing them into yearly schedules.
 
+use crate::errors::TaxError;
+use crate::models::{Currency, FilingStatus};
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 /// Represents a single tax bracket with a rate and income bounds.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaxBracket {
     /// The lower income bound for this bracket
     pub lower_bound: Decimal,
     /// The optional upper income bound (None represents no upper limit)
     pub upper_bound: Option<Decimal>,
     /// The tax rate for this bracket as a decimal (e.g., 0.25 for 25%)
+    ///
+    /// A negative rate models a refundable credit that phases in over the
+    /// bracket's income band (such as the EITC phase-in). Schedules containing
+    /// one only pass validation when they allow negative rates; see
+    /// [`TaxSchedule::with_negative_rates_allowed`].
     pub rate: Decimal,
 }
 
+impl TaxBracket {
+    /// Returns a copy with the rate and bounds in canonical decimal form.
+    ///
+    /// Trailing zeros are stripped, so `0.3500` and `0.35` produce identical
+    /// values with the same scale and string representation.
+    pub fn normalized(&self) -> TaxBracket {
+        TaxBracket {
+            lower_bound: self.lower_bound.normalize(),
+            upper_bound: self.upper_bound.map(|upper| upper.normalize()),
+            rate: self.rate.normalize(),
+        }
+    }
+}
+
+/// A temporary change applied uniformly to every bracket rate.
+///
+/// Used to model tax holidays and other rate reductions that only apply
+/// for part of a tax year.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RateAdjustment {
+    /// Multiplies each rate by a factor (e.g., 0.5 halves every rate)
+    Multiplicative(Decimal),
+    /// Adds a fixed amount to each rate (e.g., -0.02 for a two-point cut)
+    Additive(Decimal),
+}
+
+impl RateAdjustment {
+    /// Applies this adjustment to a single rate.
+    ///
+    /// A non-negative rate is never cut below zero. A negative rate, as in a
+    /// refundable credit band, is adjusted without a floor, so halving -0.10
+    /// gives -0.05.
+    pub fn apply(&self, rate: Decimal) -> Decimal {
+        let adjusted = match self {
+            RateAdjustment::Multiplicative(factor) => rate * factor,
+            RateAdjustment::Additive(delta) => rate + delta,
+        };
+        if rate >= Decimal::ZERO {
+            adjusted.max(Decimal::ZERO)
+        } else {
+            adjusted
+        }
+    }
+}
+
+/// How bracket bounds are interpreted at their exact threshold values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BoundInclusivity {
+    /// Each bracket covers income "over `lower_bound` but not over `upper_bound`",
+    /// the wording of the IRS rate schedules. Income exactly at a threshold
+    /// belongs to the lower bracket, and adjacent brackets share the threshold.
+    #[default]
+    UpperInclusive,
+    /// Each bracket covers `[lower_bound, upper_bound)`. Income exactly at a
+    /// threshold belongs to the higher bracket, and adjacent brackets share
+    /// the threshold.
+    LowerInclusive,
+    /// Both bounds are inclusive whole-dollar amounts, as printed in IRS tax
+    /// tables ("$11,601 to $47,150"). Each bracket after the first starts one
+    /// dollar above the previous bracket's upper bound.
+    BothInclusive,
+}
+
+/// How completely a schedule's brackets cover the income range, as reported
+/// by [`TaxSchedule::coverage`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Coverage {
+    /// Whether the brackets start at zero and continue upward without gaps
+    pub contiguous_from_zero: bool,
+    /// Lower bound of the open-ended top bracket, if the schedule has one
+    pub top_bracket_start: Option<Decimal>,
+    /// Income ranges `(from, to)` between consecutive brackets that no bracket covers
+    pub gaps: Vec<(Decimal, Decimal)>,
+}
+
 /// A complete set of tax brackets for a specific tax year.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaxSchedule {
     /// The tax year these brackets apply to
     pub tax_year: u16,
     /// The ordered list of tax brackets
     pub brackets: Vec<TaxBracket>,
+    /// How bracket bounds are interpreted at threshold values
+    #[serde(default)]
+    pub bound_inclusivity: BoundInclusivity,
+    /// The currency the bracket bounds are denominated in
+    #[serde(default)]
+    pub currency: Currency,
+    /// The filing status these brackets apply to, or `None` if they apply
+    /// regardless of filing status
+    #[serde(default)]
+    pub filing_status: Option<FilingStatus>,
+    /// Whether [`TaxSchedule::validate`] accepts brackets with negative rates,
+    /// such as refundable credit bands
+    #[serde(default)]
+    pub allow_negative_rates: bool,
 }
 
 impl TaxSchedule {
@@ -33,6 +131,10 @@ impl TaxSchedule {
     /// * `tax_year` - The year this schedule applies to
     /// * `brackets` - Vector of tax brackets that will be sorted by lower bound
     ///
+    /// Brackets sharing a lower bound are ordered by rate, then by upper bound
+    /// with an unbounded bracket last, so the resulting order (and any
+    /// serialized form) does not depend on the order of the input.
+    ///
     /// # Examples
     ///
     /// ```
@@ -50,7 +152,1298 @@ impl TaxSchedule {
     /// ```
     pub fn new(tax_year: u16, brackets: Vec<TaxBracket>) -> Self {
         let mut brackets = brackets;
-        brackets.sort_by(|a, b| a.lower_bound.cmp(&b.lower_bound));
-        Self { tax_year, brackets }
+        brackets.sort_by_key(|b| {
+            (
+                b.lower_bound,
+                b.rate,
+                b.upper_bound.is_none(),
+                b.upper_bound,
+            )
+        });
+        Self {
+            tax_year,
+            brackets,
+            bound_inclusivity: BoundInclusivity::default(),
+            currency: Currency::default(),
+            filing_status: None,
+            allow_negative_rates: false,
+        }
+    }
+
+    /// Creates a new tax schedule, rejecting brackets that would be
+    /// misapplied.
+    ///
+    /// Brackets are sorted as in [`TaxSchedule::new`] and then checked with
+    /// [`TaxSchedule::validate`] under the default bound inclusivity. Schedules
+    /// using other bound conventions should be built with `new` and validated
+    /// after setting the inclusivity.
+    ///
+    /// # Arguments
+    ///
+    /// * `tax_year` - The year this schedule applies to
+    /// * `brackets` - Vector of tax brackets that will be sorted by lower bound
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::InvalidBrackets` describing the first problem found.
+    pub fn new_validated(tax_year: u16, brackets: Vec<TaxBracket>) -> Result<Self, TaxError> {
+        let schedule = Self::new(tax_year, brackets);
+        schedule.validate()?;
+        Ok(schedule)
+    }
+
+    /// Returns a builder that chains bracket bounds from thresholds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::TaxSchedule;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::builder()
+    ///     .tax_year(2024)
+    ///     .bracket(dec!(0), dec!(0.10))
+    ///     .bracket(dec!(11600), dec!(0.12))
+    ///     .bracket(dec!(47150), dec!(0.22))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(schedule.brackets[1].upper_bound, Some(dec!(47150)));
+    /// assert_eq!(schedule.brackets[2].upper_bound, None);
+    /// ```
+    pub fn builder() -> TaxScheduleBuilder {
+        TaxScheduleBuilder::default()
+    }
+
+    /// Parses a schedule from JSON, such as bracket data from an external API.
+    ///
+    /// The expected shape is the one produced by [`TaxSchedule::to_json`]:
+    ///
+    /// ```json
+    /// {
+    ///   "tax_year": 2024,
+    ///   "brackets": [
+    ///     { "lower_bound": "0", "upper_bound": "11600", "rate": "0.10" },
+    ///     { "lower_bound": "11600", "upper_bound": null, "rate": "0.12" }
+    ///   ],
+    ///   "bound_inclusivity": "UpperInclusive",
+    ///   "currency": "USD",
+    ///   "filing_status": "Single"
+    /// }
+    /// ```
+    ///
+    /// Amounts and rates may be JSON strings or numbers; strings avoid the
+    /// rounding of binary floating point. `bound_inclusivity`, `currency`,
+    /// `filing_status` and `allow_negative_rates` are optional and default as
+    /// in [`TaxSchedule::new`].
+    /// Brackets may appear in any order and are sorted as in `new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON text to parse
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::ParseError` if the JSON is malformed, a field is
+    /// missing, or an amount is not a valid decimal, and
+    /// `TaxError::InvalidBrackets` if the brackets overlap, leave gaps, or
+    /// otherwise fail [`TaxSchedule::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::TaxSchedule;
+    ///
+    /// let json = r#"{"tax_year": 2024, "brackets": [
+    ///     {"lower_bound": "0", "upper_bound": null, "rate": "0.05"}
+    /// ]}"#;
+    /// let schedule = TaxSchedule::from_json(json)?;
+    /// assert_eq!(schedule.brackets.len(), 1);
+    /// # Ok::<(), tax_engine::TaxError>(())
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, TaxError> {
+        let parsed: TaxSchedule = serde_json::from_str(json)
+            .map_err(|e| TaxError::ParseError(format!("Invalid schedule JSON: {}", e)))?;
+
+        let schedule = TaxSchedule {
+            brackets: Self::new(parsed.tax_year, parsed.brackets).brackets,
+            ..parsed
+        };
+        schedule.validate()?;
+        Ok(schedule)
+    }
+
+    /// Serializes the schedule to the JSON shape read by
+    /// [`TaxSchedule::from_json`].
+    ///
+    /// Amounts and rates are written as strings so they round-trip exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::ParseError` if serialization fails.
+    pub fn to_json(&self) -> Result<String, TaxError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| TaxError::ParseError(format!("Could not serialize schedule: {}", e)))
+    }
+
+    /// Sets how bracket bounds are interpreted at threshold values.
+    ///
+    /// # Arguments
+    ///
+    /// * `bound_inclusivity` - The bound convention the brackets were written in
+    pub fn with_bound_inclusivity(mut self, bound_inclusivity: BoundInclusivity) -> Self {
+        self.bound_inclusivity = bound_inclusivity;
+        self
+    }
+
+    /// Sets the currency the bracket bounds are denominated in.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The schedule's currency
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Sets the filing status these brackets apply to.
+    ///
+    /// Schedules without a filing status apply to entities of any status.
+    ///
+    /// # Arguments
+    ///
+    /// * `filing_status` - The filing status the brackets were published for
+    pub fn with_filing_status(mut self, filing_status: FilingStatus) -> Self {
+        self.filing_status = Some(filing_status);
+        self
+    }
+
+    /// Sets whether validation accepts brackets with negative rates.
+    ///
+    /// Negative rates model refundable credits that phase in over an income
+    /// band. They are rejected by default, since a negative rate is more often
+    /// a data error than a credit.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed` - Whether rates below zero pass [`TaxSchedule::validate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{TaxBracket, TaxSchedule};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::new(
+    ///     2024,
+    ///     vec![TaxBracket {
+    ///         lower_bound: dec!(0),
+    ///         upper_bound: None,
+    ///         rate: dec!(-0.34),
+    ///     }],
+    /// );
+    /// assert!(schedule.validate().is_err());
+    /// assert!(schedule.with_negative_rates_allowed(true).validate().is_ok());
+    /// ```
+    pub fn with_negative_rates_allowed(mut self, allowed: bool) -> Self {
+        self.allow_negative_rates = allowed;
+        self
+    }
+
+    /// Returns the income level above which a bracket's rate starts to apply.
+    ///
+    /// This is the bracket's lower bound, except under
+    /// `BoundInclusivity::BothInclusive` where a bracket starting above zero
+    /// also taxes the whole dollar at its lower bound.
+    pub fn taxed_above(&self, bracket: &TaxBracket) -> Decimal {
+        match self.bound_inclusivity {
+            BoundInclusivity::BothInclusive if bracket.lower_bound > Decimal::ZERO => {
+                bracket.lower_bound - Decimal::ONE
+            }
+            _ => bracket.lower_bound,
+        }
+    }
+
+    /// Finds the bracket whose bounds contain an income amount.
+    ///
+    /// Threshold values are resolved according to the schedule's
+    /// `bound_inclusivity`. The lowest bracket always includes its lower bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `income` - The income amount to locate
+    ///
+    /// # Returns
+    ///
+    /// The containing bracket, or `None` if the income falls below the first
+    /// bracket or in a gap between brackets.
+    pub fn bracket_for(&self, income: Decimal) -> Option<&TaxBracket> {
+        self.brackets
+            .iter()
+            .enumerate()
+            .find_map(|(index, bracket)| {
+                let above_lower = match self.bound_inclusivity {
+                    BoundInclusivity::UpperInclusive => {
+                        income > bracket.lower_bound
+                            || (index == 0 && income == bracket.lower_bound)
+                    }
+                    BoundInclusivity::LowerInclusive | BoundInclusivity::BothInclusive => {
+                        income >= bracket.lower_bound
+                    }
+                };
+                let below_upper = match (self.bound_inclusivity, bracket.upper_bound) {
+                    (_, None) => true,
+                    (BoundInclusivity::LowerInclusive, Some(upper)) => income < upper,
+                    (_, Some(upper)) => income <= upper,
+                };
+
+                (above_lower && below_upper).then_some(bracket)
+            })
+    }
+
+    /// Finds the highest bracket that taxes any part of an income.
+    ///
+    /// This labels the taxpayer's top bracket without computing tax: brackets
+    /// are scanned from the top down and the scan stops at the first one the
+    /// income rises above (see [`TaxSchedule::taxed_above`]). It is the bracket
+    /// whose tax [`IncomeTaxCalculator::tax_in_top_bracket`] reports.
+    ///
+    /// For incomes strictly inside a bracket this is the same bracket as
+    /// [`TaxSchedule::bracket_for`]. They differ only at edges: income exactly
+    /// at a threshold under `BoundInclusivity::LowerInclusive` is contained in
+    /// the higher bracket but has not yet been taxed in it, and income in a gap
+    /// has no containing bracket but has still reached the bracket below.
+    ///
+    /// [`IncomeTaxCalculator::tax_in_top_bracket`]: crate::IncomeTaxCalculator::tax_in_top_bracket
+    ///
+    /// # Arguments
+    ///
+    /// * `taxable_income` - The taxable income to label
+    ///
+    /// # Returns
+    ///
+    /// The top bracket reached, or `None` if the income is not above the
+    /// start of the first bracket.
+    pub fn top_bracket_reached(&self, taxable_income: Decimal) -> Option<&TaxBracket> {
+        self.brackets
+            .iter()
+            .rev()
+            .find(|bracket| taxable_income > self.taxed_above(bracket))
+    }
+
+    /// Reports how completely the brackets cover income from zero upward.
+    ///
+    /// Never fails, so it can be used to display the health of schedules that
+    /// would otherwise be rejected. Gaps are detected between consecutive
+    /// brackets according to the schedule's `bound_inclusivity`; each is
+    /// reported as the previous bracket's upper bound and the next bracket's
+    /// lower bound.
+    ///
+    /// # Returns
+    ///
+    /// A `Coverage` summary of the schedule.
+    pub fn coverage(&self) -> Coverage {
+        let gaps: Vec<(Decimal, Decimal)> = self
+            .brackets
+            .windows(2)
+            .filter_map(|pair| {
+                let upper = pair[0].upper_bound?;
+                (self.taxed_above(&pair[1]) > upper).then_some((upper, pair[1].lower_bound))
+            })
+            .collect();
+
+        let starts_at_zero = self
+            .brackets
+            .first()
+            .is_some_and(|first| first.lower_bound <= Decimal::ZERO);
+
+        Coverage {
+            contiguous_from_zero: starts_at_zero && gaps.is_empty(),
+            top_bracket_start: self
+                .brackets
+                .iter()
+                .find(|bracket| bracket.upper_bound.is_none())
+                .map(|bracket| bracket.lower_bound),
+            gaps,
+        }
+    }
+
+    /// Checks that the brackets form a well-defined progression.
+    ///
+    /// Brackets are checked in order, and each must have a rate between 0 and
+    /// 1 (or at most 1, if the schedule allows negative rates) and an upper
+    /// bound above its lower bound. Consecutive brackets must
+    /// meet exactly under the schedule's bound inclusivity, and only the last
+    /// bracket may be unbounded. Income below the first bracket is not checked,
+    /// since a first bracket above zero is a tax-free threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::InvalidBrackets` describing the first problem found,
+    /// identifying brackets by their position and bounds.
+    pub fn validate(&self) -> Result<(), TaxError> {
+        fn describe(index: usize, bracket: &TaxBracket) -> String {
+            match bracket.upper_bound {
+                Some(upper) => format!("bracket {} ({} to {})", index, bracket.lower_bound, upper),
+                None => format!("bracket {} ({} and over)", index, bracket.lower_bound),
+            }
+        }
+
+        for (index, bracket) in self.brackets.iter().enumerate() {
+            if self.allow_negative_rates && bracket.rate > Decimal::ONE {
+                return Err(TaxError::InvalidBrackets(format!(
+                    "{} has rate {} above 1",
+                    describe(index, bracket),
+                    bracket.rate
+                )));
+            }
+            if !self.allow_negative_rates
+                && (bracket.rate < Decimal::ZERO || bracket.rate > Decimal::ONE)
+            {
+                return Err(TaxError::InvalidBrackets(format!(
+                    "{} has rate {} outside 0 to 1",
+                    describe(index, bracket),
+                    bracket.rate
+                )));
+            }
+            if bracket
+                .upper_bound
+                .is_some_and(|upper| upper <= bracket.lower_bound)
+            {
+                return Err(TaxError::InvalidBrackets(format!(
+                    "{} has an upper bound not above its lower bound",
+                    describe(index, bracket)
+                )));
+            }
+        }
+
+        for (index, pair) in self.brackets.windows(2).enumerate() {
+            let (current, next) = (&pair[0], &pair[1]);
+            let Some(upper) = current.upper_bound else {
+                return Err(TaxError::InvalidBrackets(format!(
+                    "{} is unbounded but is followed by {}",
+                    describe(index, current),
+                    describe(index + 1, next)
+                )));
+            };
+
+            let next_start = self.taxed_above(next);
+            if next_start < upper {
+                return Err(TaxError::InvalidBrackets(format!(
+                    "{} overlaps {}",
+                    describe(index + 1, next),
+                    describe(index, current)
+                )));
+            }
+            if next_start > upper {
+                return Err(TaxError::InvalidBrackets(format!(
+                    "gap from {} to {} between {} and {}",
+                    upper,
+                    next.lower_bound,
+                    describe(index, current),
+                    describe(index + 1, next)
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a copy with every bracket normalized.
+    ///
+    /// See [`TaxBracket::normalized`].
+    pub fn normalized(&self) -> TaxSchedule {
+        TaxSchedule {
+            tax_year: self.tax_year,
+            brackets: self.brackets.iter().map(TaxBracket::normalized).collect(),
+            bound_inclusivity: self.bound_inclusivity,
+            currency: self.currency,
+            filing_status: self.filing_status,
+            allow_negative_rates: self.allow_negative_rates,
+        }
+    }
+
+    /// Creates a contiguous schedule from thresholds in thousands and percent rates.
+    ///
+    /// Each pair is `(threshold, percent)`, where the threshold is the bracket's
+    /// lower bound in thousands of dollars and the percent is its rate. Brackets
+    /// are chained so each upper bound is the next bracket's lower bound, and the
+    /// highest bracket is unbounded.
+    ///
+    /// # Arguments
+    ///
+    /// * `tax_year` - The year this schedule applies to
+    /// * `pairs` - `(thousands, percent)` pairs in any order
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::InvalidBrackets` if a percent is not a finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::TaxSchedule;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::from_thousands(2024, &[(0, 10.0), (50, 15.0)]).unwrap();
+    /// assert_eq!(schedule.brackets[1].lower_bound, dec!(50000));
+    /// assert_eq!(schedule.brackets[1].rate, dec!(0.15));
+    /// ```
+    pub fn from_thousands(tax_year: u16, pairs: &[(u32, f64)]) -> Result<Self, TaxError> {
+        let mut pairs = pairs.to_vec();
+        pairs.sort_by_key(|(thousands, _)| *thousands);
+
+        let thresholds: Vec<Decimal> = pairs
+            .iter()
+            .map(|(thousands, _)| Decimal::from(*thousands) * Decimal::ONE_THOUSAND)
+            .collect();
+
+        let mut brackets = Vec::with_capacity(pairs.len());
+        for (index, (_, percent)) in pairs.iter().enumerate() {
+            let rate = Decimal::from_f64(*percent).ok_or_else(|| {
+                TaxError::InvalidBrackets(format!("percent {} is not a finite number", percent))
+            })? / Decimal::ONE_HUNDRED;
+            brackets.push(TaxBracket {
+                lower_bound: thresholds[index],
+                upper_bound: thresholds.get(index + 1).copied(),
+                rate,
+            });
+        }
+
+        Ok(Self::new(tax_year, brackets))
+    }
+
+    /// Produces a blended schedule for an adjustment in effect for part of the year.
+    ///
+    /// Each bracket's rate becomes a weighted average of its base rate and its
+    /// adjusted rate, weighted by the fraction of the year the adjustment applies.
+    /// Bracket bounds are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjustment` - The rate adjustment to apply
+    /// * `fraction_of_year` - Portion of the year the adjustment is in effect,
+    ///   clamped to the range 0 to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{RateAdjustment, TaxBracket, TaxSchedule};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::new(
+    ///     2024,
+    ///     vec![TaxBracket {
+    ///         lower_bound: dec!(0),
+    ///         upper_bound: None,
+    ///         rate: dec!(0.10),
+    ///     }],
+    /// );
+    /// let blended = schedule.with_rate_adjustment(RateAdjustment::Additive(dec!(-0.02)), dec!(0.5));
+    /// assert_eq!(blended.brackets[0].rate, dec!(0.09));
+    /// ```
+    pub fn with_rate_adjustment(
+        &self,
+        adjustment: RateAdjustment,
+        fraction_of_year: Decimal,
+    ) -> TaxSchedule {
+        let fraction = fraction_of_year.max(Decimal::ZERO).min(Decimal::ONE);
+        let brackets = self
+            .brackets
+            .iter()
+            .map(|bracket| TaxBracket {
+                rate: bracket.rate + (adjustment.apply(bracket.rate) - bracket.rate) * fraction,
+                ..bracket.clone()
+            })
+            .collect();
+
+        self.with_brackets(brackets)
+    }
+
+    /// Adds a new open-ended top bracket, as when modeling a proposed law.
+    ///
+    /// The current open-ended bracket, if any, is closed where the new one
+    /// starts, and the resulting schedule is validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower_bound` - Where the new bracket starts
+    /// * `rate` - The new bracket's rate as a decimal
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::InvalidBrackets` if the new bracket does not start
+    /// above the current top bracket's lower bound, if it would leave a gap
+    /// after a closed top bracket, or if the rate is outside 0 to 1 (or above 1
+    /// when the schedule allows negative rates).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{TaxBracket, TaxSchedule};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::new(
+    ///     2024,
+    ///     vec![TaxBracket {
+    ///         lower_bound: dec!(0),
+    ///         upper_bound: None,
+    ///         rate: dec!(0.37),
+    ///     }],
+    /// );
+    /// let proposed = schedule.with_added_bracket(dec!(1000000), dec!(0.45)).unwrap();
+    /// assert_eq!(proposed.brackets[0].upper_bound, Some(dec!(1000000)));
+    /// assert_eq!(proposed.brackets[1].rate, dec!(0.45));
+    /// ```
+    pub fn with_added_bracket(
+        &self,
+        lower_bound: Decimal,
+        rate: Decimal,
+    ) -> Result<TaxSchedule, TaxError> {
+        let mut brackets = self.brackets.clone();
+        if let Some(top) = brackets.iter_mut().find(|b| b.upper_bound.is_none()) {
+            top.upper_bound = Some(match self.bound_inclusivity {
+                BoundInclusivity::BothInclusive => lower_bound - Decimal::ONE,
+                _ => lower_bound,
+            });
+        }
+        brackets.push(TaxBracket {
+            lower_bound,
+            upper_bound: None,
+            rate,
+        });
+
+        let schedule = self.with_brackets(brackets);
+        schedule.validate()?;
+        Ok(schedule)
+    }
+
+    /// Splits the schedule into the parts below and above an income level.
+    ///
+    /// The first schedule keeps every bracket that starts below `income`,
+    /// with the straddling bracket closed at `income`. The second keeps every
+    /// bracket that extends above `income`, with the straddling bracket
+    /// starting there. Tax on any income under the two schedules sums to the
+    /// tax under the original.
+    ///
+    /// # Arguments
+    ///
+    /// * `income` - The income level to split at
+    ///
+    /// # Returns
+    ///
+    /// A `(below, above)` pair of schedules sharing this schedule's year,
+    /// bound inclusivity, currency and filing status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{TaxBracket, TaxSchedule};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let schedule = TaxSchedule::new(
+    ///     2024,
+    ///     vec![TaxBracket {
+    ///         lower_bound: dec!(0),
+    ///         upper_bound: None,
+    ///         rate: dec!(0.10),
+    ///     }],
+    /// );
+    /// let (below, above) = schedule.split_at(dec!(40000));
+    /// assert_eq!(below.brackets[0].upper_bound, Some(dec!(40000)));
+    /// assert_eq!(above.brackets[0].lower_bound, dec!(40000));
+    /// ```
+    pub fn split_at(&self, income: Decimal) -> (TaxSchedule, TaxSchedule) {
+        let below = self
+            .brackets
+            .iter()
+            .filter(|bracket| self.taxed_above(bracket) < income)
+            .map(|bracket| TaxBracket {
+                upper_bound: Some(
+                    bracket
+                        .upper_bound
+                        .map_or(income, |upper| upper.min(income)),
+                ),
+                ..bracket.clone()
+            })
+            .collect();
+
+        let above = self
+            .brackets
+            .iter()
+            .filter(|bracket| bracket.upper_bound.is_none_or(|upper| upper > income))
+            .map(|bracket| {
+                if self.taxed_above(bracket) >= income {
+                    return bracket.clone();
+                }
+                // Start the bracket so that it taxes income above the split point
+                let lower_bound = match self.bound_inclusivity {
+                    BoundInclusivity::BothInclusive => income + Decimal::ONE,
+                    _ => income,
+                };
+                TaxBracket {
+                    lower_bound,
+                    ..bracket.clone()
+                }
+            })
+            .collect();
+
+        (self.with_brackets(below), self.with_brackets(above))
+    }
+
+    /// Returns a schedule with this schedule's settings and other brackets.
+    fn with_brackets(&self, brackets: Vec<TaxBracket>) -> TaxSchedule {
+        TaxSchedule {
+            filing_status: self.filing_status,
+            allow_negative_rates: self.allow_negative_rates,
+            ..TaxSchedule::new(self.tax_year, brackets)
+                .with_bound_inclusivity(self.bound_inclusivity)
+                .with_currency(self.currency)
+        }
+    }
+}
+
+/// Fluent builder for [`TaxSchedule`], created with [`TaxSchedule::builder`].
+///
+/// Brackets are given as `(threshold, rate)` pairs, where the threshold is
+/// the bracket's lower bound. Each bracket's upper bound is the next
+/// threshold, or one dollar below it under
+/// [`BoundInclusivity::BothInclusive`], and the highest bracket is unbounded,
+/// so the brackets are always contiguous. The tax year is required; other
+/// fields default as in [`TaxSchedule::new`].
+#[derive(Debug, Clone, Default)]
+pub struct TaxScheduleBuilder {
+    tax_year: Option<u16>,
+    thresholds: Vec<(Decimal, Decimal)>,
+    bound_inclusivity: BoundInclusivity,
+    currency: Currency,
+    filing_status: Option<FilingStatus>,
+    allow_negative_rates: bool,
+}
+
+impl TaxScheduleBuilder {
+    /// Sets the tax year the schedule applies to.
+    pub fn tax_year(mut self, tax_year: u16) -> Self {
+        self.tax_year = Some(tax_year);
+        self
+    }
+
+    /// Adds a bracket starting at `threshold` and taxed at `rate`.
+    ///
+    /// Brackets may be added in any order.
+    pub fn bracket(mut self, threshold: Decimal, rate: Decimal) -> Self {
+        self.thresholds.push((threshold, rate));
+        self
+    }
+
+    /// Sets how bracket bounds are interpreted at threshold values.
+    pub fn bound_inclusivity(mut self, bound_inclusivity: BoundInclusivity) -> Self {
+        self.bound_inclusivity = bound_inclusivity;
+        self
+    }
+
+    /// Sets the currency the thresholds are denominated in.
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Sets the filing status the brackets apply to.
+    pub fn filing_status(mut self, filing_status: FilingStatus) -> Self {
+        self.filing_status = Some(filing_status);
+        self
+    }
+
+    /// Sets whether brackets may have negative rates.
+    ///
+    /// See [`TaxSchedule::with_negative_rates_allowed`].
+    pub fn allow_negative_rates(mut self, allowed: bool) -> Self {
+        self.allow_negative_rates = allowed;
+        self
+    }
+
+    /// Builds and validates the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::MissingField` if the tax year was not set, and
+    /// `TaxError::InvalidBrackets` if the schedule fails
+    /// [`TaxSchedule::validate`], as when two brackets share a threshold or a
+    /// rate is out of range.
+    pub fn build(self) -> Result<TaxSchedule, TaxError> {
+        let tax_year = self.tax_year.ok_or(TaxError::MissingField("tax_year"))?;
+
+        let mut thresholds = self.thresholds;
+        thresholds.sort_by_key(|(threshold, _)| *threshold);
+
+        let brackets = thresholds
+            .iter()
+            .enumerate()
+            .map(|(index, (threshold, rate))| TaxBracket {
+                lower_bound: *threshold,
+                upper_bound: thresholds.get(index + 1).map(|(next, _)| {
+                    match self.bound_inclusivity {
+                        BoundInclusivity::BothInclusive => *next - Decimal::ONE,
+                        _ => *next,
+                    }
+                }),
+                rate: *rate,
+            })
+            .collect();
+
+        let schedule = TaxSchedule {
+            bound_inclusivity: self.bound_inclusivity,
+            currency: self.currency,
+            filing_status: self.filing_status,
+            allow_negative_rates: self.allow_negative_rates,
+            ..TaxSchedule::new(tax_year, brackets)
+        };
+        schedule.validate()?;
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn two_bracket_schedule() -> TaxSchedule {
+        TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(50000)),
+                    rate: dec!(0.15),
+                },
+                TaxBracket {
+                    lower_bound: dec!(50000),
+                    upper_bound: None,
+                    rate: dec!(0.25),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_bracket_for_at_shared_threshold() {
+        let schedule = two_bracket_schedule();
+        assert_eq!(schedule.bracket_for(dec!(0)).unwrap().rate, dec!(0.15));
+        assert_eq!(schedule.bracket_for(dec!(50000)).unwrap().rate, dec!(0.15));
+        assert_eq!(
+            schedule.bracket_for(dec!(50000.01)).unwrap().rate,
+            dec!(0.25)
+        );
+
+        let schedule =
+            two_bracket_schedule().with_bound_inclusivity(BoundInclusivity::LowerInclusive);
+        assert_eq!(
+            schedule.bracket_for(dec!(49999.99)).unwrap().rate,
+            dec!(0.15)
+        );
+        assert_eq!(schedule.bracket_for(dec!(50000)).unwrap().rate, dec!(0.25));
+    }
+
+    #[test]
+    fn test_bracket_for_both_inclusive() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(11600)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(11601),
+                    upper_bound: None,
+                    rate: dec!(0.12),
+                },
+            ],
+        )
+        .with_bound_inclusivity(BoundInclusivity::BothInclusive);
+
+        assert_eq!(schedule.bracket_for(dec!(11600)).unwrap().rate, dec!(0.10));
+        assert_eq!(schedule.bracket_for(dec!(11601)).unwrap().rate, dec!(0.12));
+        assert_eq!(schedule.taxed_above(&schedule.brackets[1]), dec!(11600));
+    }
+
+    #[test]
+    fn test_normalized_strips_trailing_zeros() {
+        let padded = TaxBracket {
+            lower_bound: dec!(11600.00),
+            upper_bound: Some(dec!(47150.00)),
+            rate: dec!(0.3500),
+        };
+        let plain = TaxBracket {
+            lower_bound: dec!(11600),
+            upper_bound: Some(dec!(47150)),
+            rate: dec!(0.35),
+        };
+
+        let normalized = padded.normalized();
+        assert_eq!(normalized, plain.normalized());
+        assert_eq!(normalized.rate.to_string(), "0.35");
+        assert_eq!(normalized.rate.scale(), plain.rate.scale());
+        assert_eq!(normalized.lower_bound.to_string(), "11600");
+
+        let schedule = TaxSchedule::new(2024, vec![padded]).normalized();
+        assert_eq!(
+            format!("{:?}", schedule.brackets[0]),
+            format!("{:?}", plain)
+        );
+    }
+
+    #[test]
+    fn test_from_thousands() {
+        let schedule = TaxSchedule::from_thousands(2024, &[(50, 15.0), (0, 10.0)]).unwrap();
+
+        assert_eq!(schedule.brackets.len(), 2);
+        assert_eq!(schedule.brackets[0].lower_bound, dec!(0));
+        assert_eq!(schedule.brackets[0].upper_bound, Some(dec!(50000)));
+        assert_eq!(schedule.brackets[0].rate, dec!(0.10));
+        assert_eq!(schedule.brackets[1].lower_bound, dec!(50000));
+        assert_eq!(schedule.brackets[1].upper_bound, None);
+        assert_eq!(schedule.brackets[1].rate, dec!(0.15));
+    }
+
+    #[test]
+    fn test_from_thousands_rejects_non_finite_rate() {
+        let result = TaxSchedule::from_thousands(2024, &[(0, f64::NAN)]);
+        assert!(matches!(result, Err(TaxError::InvalidBrackets(_))));
+    }
+
+    #[test]
+    fn test_additive_adjustment_for_half_year() {
+        let schedule = two_bracket_schedule();
+        let blended =
+            schedule.with_rate_adjustment(RateAdjustment::Additive(dec!(-0.02)), dec!(0.5));
+
+        assert_eq!(blended.brackets[0].rate, dec!(0.14));
+        assert_eq!(blended.brackets[1].rate, dec!(0.24));
+        assert_eq!(blended.brackets[1].lower_bound, dec!(50000));
+    }
+
+    #[test]
+    fn test_multiplicative_adjustment_for_full_year() {
+        let schedule = two_bracket_schedule();
+        let blended =
+            schedule.with_rate_adjustment(RateAdjustment::Multiplicative(dec!(0.5)), dec!(1));
+
+        assert_eq!(blended.brackets[0].rate, dec!(0.075));
+        assert_eq!(blended.brackets[1].rate, dec!(0.125));
+    }
+
+    #[test]
+    fn test_adjustment_keeps_negative_credit_band() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(10000)), dec!(-0.10)),
+                bracket(dec!(10000), None, dec!(0.01)),
+            ],
+        )
+        .with_negative_rates_allowed(true);
+
+        let halved =
+            schedule.with_rate_adjustment(RateAdjustment::Multiplicative(dec!(0.5)), dec!(1));
+        assert_eq!(halved.brackets[0].rate, dec!(-0.05));
+
+        // A two-point cut deepens the credit but stops the positive band at zero
+        let cut = schedule.with_rate_adjustment(RateAdjustment::Additive(dec!(-0.02)), dec!(1));
+        assert_eq!(cut.brackets[0].rate, dec!(-0.12));
+        assert_eq!(cut.brackets[1].rate, dec!(0));
+        assert!(cut.validate().is_ok());
+    }
+
+    #[test]
+    fn test_coverage_reports_gap() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                TaxBracket {
+                    lower_bound: dec!(0),
+                    upper_bound: Some(dec!(10000)),
+                    rate: dec!(0.10),
+                },
+                TaxBracket {
+                    lower_bound: dec!(12000),
+                    upper_bound: None,
+                    rate: dec!(0.20),
+                },
+            ],
+        );
+
+        let coverage = schedule.coverage();
+        assert!(!coverage.contiguous_from_zero);
+        assert_eq!(coverage.top_bracket_start, Some(dec!(12000)));
+        assert_eq!(coverage.gaps, vec![(dec!(10000), dec!(12000))]);
+
+        let complete = two_bracket_schedule().coverage();
+        assert!(complete.contiguous_from_zero);
+        assert!(complete.gaps.is_empty());
+    }
+
+    #[test]
+    fn test_top_bracket_reached() {
+        let schedule = two_bracket_schedule();
+
+        assert_eq!(schedule.top_bracket_reached(dec!(0)), None);
+        assert_eq!(
+            schedule.top_bracket_reached(dec!(30000)),
+            Some(&schedule.brackets[0])
+        );
+        assert_eq!(
+            schedule.top_bracket_reached(dec!(50000)),
+            Some(&schedule.brackets[0])
+        );
+        assert_eq!(
+            schedule.top_bracket_reached(dec!(50000.01)),
+            Some(&schedule.brackets[1])
+        );
+
+        // At the threshold, LowerInclusive places income in the next bracket
+        // before any of it is taxed there
+        let lower_inclusive = schedule.with_bound_inclusivity(BoundInclusivity::LowerInclusive);
+        assert_eq!(
+            lower_inclusive.bracket_for(dec!(50000)),
+            Some(&lower_inclusive.brackets[1])
+        );
+        assert_eq!(
+            lower_inclusive.top_bracket_reached(dec!(50000)),
+            Some(&lower_inclusive.brackets[0])
+        );
+    }
+
+    #[test]
+    fn test_serialization_independent_of_input_order() {
+        let brackets = vec![
+            TaxBracket {
+                lower_bound: dec!(0),
+                upper_bound: None,
+                rate: dec!(0.20),
+            },
+            TaxBracket {
+                lower_bound: dec!(0),
+                upper_bound: Some(dec!(10000)),
+                rate: dec!(0.10),
+            },
+            TaxBracket {
+                lower_bound: dec!(0),
+                upper_bound: Some(dec!(20000)),
+                rate: dec!(0.10),
+            },
+        ];
+        let mut reversed = brackets.clone();
+        reversed.reverse();
+
+        let json = serde_json::to_string(&TaxSchedule::new(2024, brackets)).unwrap();
+        let reversed_json = serde_json::to_string(&TaxSchedule::new(2024, reversed)).unwrap();
+        assert_eq!(json, reversed_json);
+
+        let schedule: TaxSchedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(schedule.brackets[0].upper_bound, Some(dec!(10000)));
+        assert_eq!(schedule.brackets[1].upper_bound, Some(dec!(20000)));
+        assert_eq!(schedule.brackets[2].upper_bound, None);
+    }
+
+    fn bracket(lower: Decimal, upper: Option<Decimal>, rate: Decimal) -> TaxBracket {
+        TaxBracket {
+            lower_bound: lower,
+            upper_bound: upper,
+            rate,
+        }
+    }
+
+    fn validation_error(brackets: Vec<TaxBracket>) -> String {
+        match TaxSchedule::new_validated(2024, brackets) {
+            Err(TaxError::InvalidBrackets(message)) => message,
+            other => panic!("expected invalid brackets, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_contiguous_schedule() {
+        let schedule = TaxSchedule::new_validated(
+            2024,
+            vec![
+                bracket(dec!(50000), None, dec!(0.25)),
+                bracket(dec!(0), Some(dec!(50000)), dec!(0.15)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(schedule.brackets.len(), 2);
+
+        let table = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(11600)), dec!(0.10)),
+                bracket(dec!(11601), None, dec!(0.12)),
+            ],
+        )
+        .with_bound_inclusivity(BoundInclusivity::BothInclusive);
+        assert!(table.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reports_each_problem() {
+        let overlap = validation_error(vec![
+            bracket(dec!(0), Some(dec!(60000)), dec!(0.15)),
+            bracket(dec!(50000), None, dec!(0.25)),
+        ]);
+        assert_eq!(
+            overlap,
+            "bracket 1 (50000 and over) overlaps bracket 0 (0 to 60000)"
+        );
+
+        let gap = validation_error(vec![
+            bracket(dec!(0), Some(dec!(40000)), dec!(0.15)),
+            bracket(dec!(50000), None, dec!(0.25)),
+        ]);
+        assert!(gap.starts_with("gap from 40000 to 50000"));
+
+        let rate = validation_error(vec![bracket(dec!(0), None, dec!(1.5))]);
+        assert!(rate.contains("rate 1.5 outside 0 to 1"));
+
+        let unbounded = validation_error(vec![
+            bracket(dec!(0), None, dec!(0.10)),
+            bracket(dec!(50000), None, dec!(0.25)),
+        ]);
+        assert!(unbounded.contains("bracket 0 (0 and over) is unbounded"));
+    }
+
+    #[test]
+    fn test_validate_negative_rates_only_when_allowed() {
+        // EITC-like phase-in: a 34% credit on the first 10,000 of income
+        let brackets = vec![
+            bracket(dec!(0), Some(dec!(10000)), dec!(-0.34)),
+            bracket(dec!(10000), None, dec!(0.20)),
+        ];
+
+        let rate = validation_error(brackets.clone());
+        assert!(rate.contains("rate -0.34 outside 0 to 1"));
+
+        let schedule = TaxSchedule::new(2024, brackets).with_negative_rates_allowed(true);
+        assert!(schedule.validate().is_ok());
+        let proposed = schedule.with_added_bracket(dec!(100000), dec!(0.30));
+        assert!(proposed.unwrap().allow_negative_rates);
+
+        let built = TaxSchedule::builder()
+            .tax_year(2024)
+            .bracket(dec!(0), dec!(-0.34))
+            .bracket(dec!(10000), dec!(0.20));
+        assert!(built.clone().build().is_err());
+        assert!(built.allow_negative_rates(true).build().is_ok());
+
+        // Allowing negative rates still caps rates at 1
+        let capped = TaxSchedule::new(2024, vec![bracket(dec!(0), None, dec!(1.5))])
+            .with_negative_rates_allowed(true);
+        assert!(matches!(
+            capped.validate(),
+            Err(TaxError::InvalidBrackets(message)) if message.contains("rate 1.5 above 1")
+        ));
+    }
+
+    #[test]
+    fn test_schedule_json_round_trip() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(11600)), dec!(0.10)),
+                bracket(dec!(11600), None, dec!(0.12)),
+            ],
+        )
+        .with_currency(Currency::CAD)
+        .with_filing_status(FilingStatus::HeadOfHousehold);
+
+        let json = serde_json::to_string(&schedule).unwrap();
+        // Decimals are written as strings so no precision is lost
+        assert!(json.contains(r#""rate":"0.10""#));
+
+        let restored: TaxSchedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, schedule);
+    }
+
+    #[test]
+    fn test_split_at_preserves_total_tax() {
+        use crate::calculators::IncomeTaxCalculator;
+        use crate::models::{TaxEntity, TaxEntityType};
+
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(11600)), dec!(0.10)),
+                bracket(dec!(11600), Some(dec!(47150)), dec!(0.12)),
+                bracket(dec!(47150), None, dec!(0.22)),
+            ],
+        )
+        .with_filing_status(FilingStatus::Single);
+
+        // Split inside the middle bracket so it straddles the split point
+        let (below, above) = schedule.split_at(dec!(30000));
+        assert_eq!(below.brackets.len(), 2);
+        assert_eq!(below.brackets[1].upper_bound, Some(dec!(30000)));
+        assert_eq!(above.brackets.len(), 2);
+        assert_eq!(above.brackets[0].lower_bound, dec!(30000));
+        assert_eq!(above.filing_status, Some(FilingStatus::Single));
+
+        let tax = |schedule: &TaxSchedule, income| {
+            let entity = TaxEntity::new(TaxEntityType::Individual, income, 2024);
+            IncomeTaxCalculator::calculate_tax(&entity, schedule).unwrap()
+        };
+        for income in [dec!(0), dec!(20000), dec!(30000), dec!(40000), dec!(90000)] {
+            assert_eq!(
+                tax(&below, income) + tax(&above, income),
+                tax(&schedule, income),
+                "income {}",
+                income
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_added_bracket() {
+        use crate::calculators::IncomeTaxCalculator;
+        use crate::models::{TaxEntity, TaxEntityType};
+
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(100000)), dec!(0.24)),
+                bracket(dec!(100000), None, dec!(0.37)),
+            ],
+        );
+        let proposed = schedule
+            .with_added_bracket(dec!(1000000), dec!(0.45))
+            .unwrap();
+        assert_eq!(proposed.brackets.len(), 3);
+        assert_eq!(proposed.brackets[1].upper_bound, Some(dec!(1000000)));
+
+        let tax = |schedule: &TaxSchedule, income| {
+            let entity = TaxEntity::new(TaxEntityType::Individual, income, 2024);
+            IncomeTaxCalculator::calculate_tax(&entity, schedule).unwrap()
+        };
+        // Income up to $1M is taxed as before
+        assert_eq!(tax(&proposed, dec!(1000000)), tax(&schedule, dec!(1000000)));
+        // The next 500,000 is taxed at 45% rather than 37%
+        assert_eq!(
+            tax(&proposed, dec!(1500000)) - tax(&schedule, dec!(1500000)),
+            dec!(40000)
+        );
+
+        // A new bracket must start above the current top bracket
+        let below = schedule.with_added_bracket(dec!(50000), dec!(0.45));
+        assert!(matches!(below, Err(TaxError::InvalidBrackets(_))));
+    }
+
+    #[test]
+    fn test_from_json_round_trips_to_json() {
+        let schedule = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(11600)), dec!(0.10)),
+                bracket(dec!(11600), None, dec!(0.12)),
+            ],
+        )
+        .with_currency(Currency::CAD)
+        .with_filing_status(FilingStatus::HeadOfHousehold);
+
+        let json = schedule.to_json().unwrap();
+        assert_eq!(TaxSchedule::from_json(&json).unwrap(), schedule);
+
+        // Optional fields default, numbers are accepted and order doesn't matter
+        let minimal = TaxSchedule::from_json(
+            r#"{"tax_year": 2024, "brackets": [
+                {"lower_bound": "11600", "upper_bound": null, "rate": 0.12},
+                {"lower_bound": 0, "upper_bound": "11600", "rate": "0.10"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(minimal.brackets, schedule.brackets);
+        assert_eq!(minimal.currency, Currency::USD);
+        assert_eq!(minimal.filing_status, None);
+    }
+
+    #[test]
+    fn test_from_json_rejects_malformed_input() {
+        let malformed = [
+            "not json",
+            r#"{"tax_year": 2024}"#,
+            r#"{"tax_year": 2024, "brackets": [
+                {"lower_bound": "zero", "upper_bound": null, "rate": "0.10"}
+            ]}"#,
+        ];
+        for json in malformed {
+            assert!(
+                matches!(TaxSchedule::from_json(json), Err(TaxError::ParseError(_))),
+                "{} should not parse",
+                json
+            );
+        }
+
+        let gap = r#"{"tax_year": 2024, "brackets": [
+            {"lower_bound": "0", "upper_bound": "10000", "rate": "0.10"},
+            {"lower_bound": "20000", "upper_bound": null, "rate": "0.20"}
+        ]}"#;
+        assert!(matches!(
+            TaxSchedule::from_json(gap),
+            Err(TaxError::InvalidBrackets(_))
+        ));
+
+        let credit = r#"{"tax_year": 2024, "brackets": [
+            {"lower_bound": "0", "upper_bound": "10000", "rate": "-0.34"},
+            {"lower_bound": "10000", "upper_bound": null, "rate": "0.20"}
+        ]}"#;
+        assert!(matches!(
+            TaxSchedule::from_json(credit),
+            Err(TaxError::InvalidBrackets(_))
+        ));
+        let allowed = credit.replacen('{', r#"{"allow_negative_rates": true, "#, 1);
+        assert!(TaxSchedule::from_json(&allowed).is_ok());
+    }
+
+    #[test]
+    fn test_builder_matches_hand_built_schedule() {
+        let hand_built = TaxSchedule::new(
+            2024,
+            vec![
+                bracket(dec!(0), Some(dec!(11600)), dec!(0.10)),
+                bracket(dec!(11600), Some(dec!(47150)), dec!(0.12)),
+                bracket(dec!(47150), None, dec!(0.22)),
+            ],
+        )
+        .with_filing_status(FilingStatus::Single);
+
+        let built = TaxSchedule::builder()
+            .tax_year(2024)
+            .filing_status(FilingStatus::Single)
+            .bracket(dec!(47150), dec!(0.22))
+            .bracket(dec!(0), dec!(0.10))
+            .bracket(dec!(11600), dec!(0.12))
+            .build()
+            .unwrap();
+        assert_eq!(built, hand_built);
+
+        let both_inclusive = TaxSchedule::builder()
+            .tax_year(2024)
+            .bound_inclusivity(BoundInclusivity::BothInclusive)
+            .bracket(dec!(0), dec!(0.10))
+            .bracket(dec!(11601), dec!(0.12))
+            .build()
+            .unwrap();
+        assert_eq!(both_inclusive.brackets[0].upper_bound, Some(dec!(11600)));
+    }
+
+    #[test]
+    fn test_builder_rejects_missing_year_and_invalid_brackets() {
+        let missing = TaxSchedule::builder().bracket(dec!(0), dec!(0.10)).build();
+        assert!(matches!(missing, Err(TaxError::MissingField("tax_year"))));
+
+        let duplicate = TaxSchedule::builder()
+            .tax_year(2024)
+            .bracket(dec!(0), dec!(0.10))
+            .bracket(dec!(0), dec!(0.12))
+            .build();
+        assert!(matches!(duplicate, Err(TaxError::InvalidBrackets(_))));
     }
 }
@@ -0,0 +1,45 @@
+//! Defines the currencies income can be denominated in.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+
+/// A currency identified by its ISO 4217 code.
+///
+/// Defaults to US dollars, the currency of the crate's built-in rate sources.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Currency {
+    /// United States dollar
+    #[default]
+    USD,
+    /// Canadian dollar
+    CAD,
+    /// Euro
+    EUR,
+    /// British pound sterling
+    GBP,
+    /// Japanese yen
+    JPY,
+    /// Mexican peso
+    MXN,
+}
+
+impl Currency {
+    /// Returns the ISO 4217 code for this currency.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::USD => "USD",
+            Currency::CAD => "CAD",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::JPY => "JPY",
+            Currency::MXN => "MXN",
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
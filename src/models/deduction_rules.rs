@@ -0,0 +1,250 @@
+//! Limits on how much of each deduction category may be claimed.
+//!
+//! Tax codes commonly cap deductions, either at a fixed amount (such as the
+//! SALT cap) or at a share of income (such as charitable contribution limits
+//! as a percentage of AGI).
+
+use crate::models::DeductionType;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A limit on the total deductions claimed in one category.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DeductionCap {
+    /// The category total may not exceed a fixed amount
+    Flat(Decimal),
+    /// The category total may not exceed a rate applied to gross income
+    PercentOfIncome(Decimal),
+}
+
+impl DeductionCap {
+    /// Returns the maximum amount allowed for a given gross income.
+    ///
+    /// A negative limit is treated as zero.
+    pub fn limit_for(&self, income: Decimal) -> Decimal {
+        let limit = match *self {
+            DeductionCap::Flat(amount) => amount,
+            DeductionCap::PercentOfIncome(rate) => income * rate,
+        };
+        limit.max(Decimal::ZERO)
+    }
+}
+
+/// Caps applied to deductions by category, as used by
+/// [`TaxEntity::allowed_deductions`](crate::models::TaxEntity::allowed_deductions).
+///
+/// Categories without a cap are allowed in full.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeductionRules {
+    caps: HashMap<DeductionType, DeductionCap>,
+}
+
+impl DeductionRules {
+    /// Creates a rule set without any caps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Caps a category's total deductions at a fixed amount.
+    ///
+    /// Replaces any cap previously set for the category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The deduction category to cap
+    /// * `amount` - The maximum total allowed for the category
+    pub fn with_flat_cap(mut self, category: DeductionType, amount: Decimal) -> Self {
+        self.caps.insert(category, DeductionCap::Flat(amount));
+        self
+    }
+
+    /// Caps a category's total deductions at a share of gross income.
+    ///
+    /// Replaces any cap previously set for the category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The deduction category to cap
+    /// * `rate` - The share of income allowed, as a decimal (e.g., 0.60 for 60%)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{DeductionCap, DeductionRules, DeductionType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let rules = DeductionRules::new()
+    ///     .with_percent_of_income_cap(DeductionType::Charitable, dec!(0.60));
+    /// assert_eq!(
+    ///     rules.cap_for(&DeductionType::Charitable),
+    ///     Some(DeductionCap::PercentOfIncome(dec!(0.60)))
+    /// );
+    /// ```
+    pub fn with_percent_of_income_cap(mut self, category: DeductionType, rate: Decimal) -> Self {
+        self.caps
+            .insert(category, DeductionCap::PercentOfIncome(rate));
+        self
+    }
+
+    /// Creates a rule set with the statutory flat caps for a tax year.
+    ///
+    /// Each category with a limit in [`DeductionLimits`] for `tax_year` is
+    /// capped at that limit. Further caps can be added with the builder
+    /// methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `tax_year` - The tax year whose limits should apply
+    pub fn for_year(tax_year: u16) -> Self {
+        DeductionLimits::CATEGORIES
+            .iter()
+            .fold(
+                Self::new(),
+                |rules, category| match DeductionLimits::limit_for(category, tax_year) {
+                    Some(limit) => rules.with_flat_cap(category.clone(), limit),
+                    None => rules,
+                },
+            )
+    }
+
+    /// Returns the cap for a category, if one is set.
+    pub fn cap_for(&self, category: &DeductionType) -> Option<DeductionCap> {
+        self.caps.get(category).copied()
+    }
+
+    /// Limits a category's claimed total according to its cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The deduction category
+    /// * `claimed` - The total claimed in the category
+    /// * `income` - Gross income that percentage caps are applied to
+    ///
+    /// # Returns
+    ///
+    /// The smaller of `claimed` and the category's limit, or `claimed`
+    /// unchanged if the category has no cap.
+    pub fn apply(&self, category: &DeductionType, claimed: Decimal, income: Decimal) -> Decimal {
+        match self.cap_for(category) {
+            Some(cap) => claimed.min(cap.limit_for(income)),
+            None => claimed,
+        }
+    }
+}
+
+/// Statutory dollar limits on deduction categories by tax year.
+///
+/// The single source for yearly limits such as the SALT cap, so capping code
+/// doesn't hardcode amounts. Limits expressed as a share of income, such as
+/// charitable contribution limits, are not included.
+pub struct DeductionLimits;
+
+impl DeductionLimits {
+    /// Categories that have a dollar limit in at least one year.
+    const CATEGORIES: [DeductionType; 2] = [
+        DeductionType::StateAndLocalTax,
+        DeductionType::RetirementContribution,
+    ];
+
+    /// Returns the dollar limit on a deduction category for a tax year.
+    ///
+    /// The SALT limit is the cap before any phase-down for high incomes, and
+    /// the retirement limit is the 401(k) elective deferral limit before
+    /// catch-up contributions.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The deduction category
+    /// * `tax_year` - The tax year
+    ///
+    /// # Returns
+    ///
+    /// The limit, or `None` if the category has no dollar limit or the year
+    /// is not covered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{DeductionLimits, DeductionType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// assert_eq!(
+    ///     DeductionLimits::limit_for(&DeductionType::StateAndLocalTax, 2024),
+    ///     Some(dec!(10000))
+    /// );
+    /// ```
+    pub fn limit_for(category: &DeductionType, tax_year: u16) -> Option<Decimal> {
+        let limit: u32 = match (category, tax_year) {
+            (DeductionType::StateAndLocalTax, 2018..=2024) => 10_000,
+            (DeductionType::StateAndLocalTax, 2025) => 40_000,
+            (DeductionType::StateAndLocalTax, 2026) => 40_400,
+            (DeductionType::RetirementContribution, 2020 | 2021) => 19_500,
+            (DeductionType::RetirementContribution, 2022) => 20_500,
+            (DeductionType::RetirementContribution, 2023) => 22_500,
+            (DeductionType::RetirementContribution, 2024) => 23_000,
+            (DeductionType::RetirementContribution, 2025) => 23_500,
+            (DeductionType::RetirementContribution, 2026) => 24_500,
+            _ => return None,
+        };
+        Some(Decimal::from(limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_apply_caps_by_category() {
+        let rules = DeductionRules::new()
+            .with_flat_cap(DeductionType::Personal, dec!(10000))
+            .with_percent_of_income_cap(DeductionType::Charitable, dec!(0.60));
+
+        assert_eq!(
+            rules.apply(&DeductionType::Personal, dec!(14000), dec!(100000)),
+            dec!(10000)
+        );
+        assert_eq!(
+            rules.apply(&DeductionType::Charitable, dec!(70000), dec!(100000)),
+            dec!(60000)
+        );
+        assert_eq!(
+            rules.apply(&DeductionType::Charitable, dec!(20000), dec!(100000)),
+            dec!(20000)
+        );
+        // Uncapped categories are allowed in full
+        assert_eq!(
+            rules.apply(&DeductionType::Business, dec!(90000), dec!(100000)),
+            dec!(90000)
+        );
+    }
+
+    #[test]
+    fn test_limits_by_year() {
+        assert_eq!(
+            DeductionLimits::limit_for(&DeductionType::StateAndLocalTax, 2024),
+            Some(dec!(10000))
+        );
+        assert_eq!(
+            DeductionLimits::limit_for(&DeductionType::RetirementContribution, 2024),
+            Some(dec!(23000))
+        );
+        assert_eq!(
+            DeductionLimits::limit_for(&DeductionType::StateAndLocalTax, 1990),
+            None
+        );
+        assert_eq!(
+            DeductionLimits::limit_for(&DeductionType::Charitable, 2024),
+            None
+        );
+
+        let rules = DeductionRules::for_year(2024);
+        assert_eq!(
+            rules.apply(&DeductionType::StateAndLocalTax, dec!(18000), dec!(250000)),
+            dec!(10000)
+        );
+        assert_eq!(rules.cap_for(&DeductionType::Business), None);
+    }
+}
@@ -3,11 +3,16 @@
 //! This module provides types for representing different kinds of taxable entities
 //! and managing their income and deductions.
 
+use crate::errors::TaxError;
+use crate::models::{Currency, DeductionRules};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::hash::Hash;
 
 /// The type of entity being taxed.
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum TaxEntityType {
     /// Individual taxpayer
     Individual,
@@ -17,8 +22,35 @@ pub enum TaxEntityType {
     Partnership,
 }
 
+/// The filing status an individual files a return under.
+///
+/// US federal bracket thresholds differ by filing status, so a schedule fetched
+/// for one status does not apply to another.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum FilingStatus {
+    /// Unmarried taxpayer filing alone
+    #[default]
+    Single,
+    /// Married couple filing one combined return
+    MarriedFilingJointly,
+    /// Married taxpayer filing a separate return from their spouse
+    MarriedFilingSeparately,
+    /// Unmarried taxpayer who pays for more than half of a qualifying household
+    HeadOfHousehold,
+}
+
+impl FilingStatus {
+    /// Every filing status.
+    pub const ALL: [FilingStatus; 4] = [
+        FilingStatus::Single,
+        FilingStatus::MarriedFilingJointly,
+        FilingStatus::MarriedFilingSeparately,
+        FilingStatus::HeadOfHousehold,
+    ];
+}
+
 /// Represents a taxable entity with income and deductions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaxEntity {
     /// The type of this tax entity
     pub entity_type: TaxEntityType,
@@ -28,19 +60,252 @@ pub struct TaxEntity {
     pub deductions: Vec<Deduction>,
     /// Tax year for this entity's calculations
     pub tax_year: u16,
+    /// Number of dependents claimed by this entity
+    #[serde(default)]
+    pub dependents: u8,
+    /// The filing status this entity's return is filed under
+    #[serde(default)]
+    pub filing_status: FilingStatus,
+    /// The home currency that `income` and deductions are denominated in
+    #[serde(default)]
+    pub currency: Currency,
+    /// Income items taxed in the year they are recognized, such as vested RSUs
+    #[serde(default)]
+    pub deferred_income: Vec<DeferredIncome>,
+    /// Foreign income already converted into `income`, kept in its original currency
+    #[serde(default)]
+    pub foreign_income: Vec<ForeignIncome>,
+    /// Credits that reduce the tax owed directly
+    #[serde(default)]
+    pub credits: Vec<TaxCredit>,
+    /// Income already included in `income`, broken out by how it is taxed
+    #[serde(default)]
+    pub income_items: Vec<IncomeItem>,
+    /// Estimated tax payments made toward this tax year
+    #[serde(default)]
+    pub estimated_payments: Vec<Decimal>,
+}
+
+/// The kinds of income that tax rules distinguish.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum IncomeType {
+    /// Wages, interest and other income taxed at ordinary rates
+    Ordinary,
+    /// Gains on assets held for a year or less, taxed at ordinary rates
+    ShortTermGain,
+    /// Gains on assets held for more than a year
+    LongTermGain,
+    /// Dividends taxed at long-term capital gains rates
+    QualifiedDividend,
+}
+
+impl IncomeType {
+    /// Returns whether this income is taxed at capital gains rates rather
+    /// than ordinary rates.
+    pub fn is_preferential(&self) -> bool {
+        matches!(
+            self,
+            IncomeType::LongTermGain | IncomeType::QualifiedDividend
+        )
+    }
+}
+
+/// An amount of income of a particular type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncomeItem {
+    /// The amount of income
+    pub amount: Decimal,
+    /// How the income is taxed
+    pub income_type: IncomeType,
+}
+
+/// Income earned in a foreign currency, recorded as originally received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForeignIncome {
+    /// The amount in the foreign currency
+    pub amount: Decimal,
+    /// The currency the income was earned in
+    pub currency: Currency,
+    /// Exchange rate used to convert one unit into the entity's currency
+    pub rate_to_home: Decimal,
+}
+
+impl ForeignIncome {
+    /// Returns the amount converted into the home currency.
+    pub fn home_amount(&self) -> Decimal {
+        self.amount * self.rate_to_home
+    }
+}
+
+/// A credit subtracted from the tax owed, rather than from taxable income.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxCredit {
+    /// The amount of the credit
+    pub amount: Decimal,
+    /// The category of this credit
+    pub category: CreditType,
+    /// Whether the credit is paid out once it exceeds the tax owed
+    ///
+    /// Non-refundable credits can only reduce tax to zero.
+    pub refundable: bool,
+}
+
+/// Categories of tax credits.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum CreditType {
+    /// Credits for qualifying children
+    Child,
+    /// Credits for low to moderate earned income
+    EarnedIncome,
+    /// Credits for tuition and other education expenses
+    Education,
+    /// Credits for tax paid to foreign jurisdictions
+    Foreign,
+}
+
+/// Income that is taxed when it is recognized rather than when it is granted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeferredIncome {
+    /// The amount of income
+    pub amount: Decimal,
+    /// The tax year in which the income is recognized
+    pub recognition_year: u16,
 }
 
 /// Represents a single tax deduction.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Deduction {
-    /// The amount to be deducted
+    /// The amount to be deducted (unused for percentage-based deductions, and
+    /// equal to `units * rate_per_unit` for per-unit deductions)
     pub amount: Decimal,
     /// The category of this deduction
     pub category: DeductionType,
+    /// Optional reference to supporting documentation (e.g., a receipt ID or file path)
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// How the deducted amount is determined
+    pub basis: DeductionBasis,
+}
+
+/// How the amount of a deduction is determined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DeductionBasis {
+    /// A fixed amount, taken from `Deduction::amount`
+    Fixed,
+    /// A rate applied to gross income whenever deductions are totaled
+    ///
+    /// The rate is always applied to gross income, not income net of other
+    /// deductions, so the order in which deductions are added doesn't matter.
+    PercentageOfIncome(Decimal),
+    /// A number of units multiplied by a rate per unit, such as miles driven
+    /// at the standard mileage rate
+    PerUnit {
+        /// Number of units claimed
+        units: Decimal,
+        /// Amount deducted per unit
+        rate_per_unit: Decimal,
+    },
+}
+
+impl Deduction {
+    /// Returns the amount this deduction is worth for a given gross income.
+    pub fn amount_for(&self, income: Decimal) -> Decimal {
+        match self.basis {
+            DeductionBasis::Fixed => self.amount,
+            DeductionBasis::PercentageOfIncome(rate) => income * rate,
+            DeductionBasis::PerUnit {
+                units,
+                rate_per_unit,
+            } => units * rate_per_unit,
+        }
+    }
+}
+
+/// Differences between two tax entities, as reported by [`TaxEntity::diff`].
+///
+/// Each changed field holds its `(before, after)` values. Deductions are
+/// compared as a multiset, so reordering alone is not reported as a change.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntityDiff {
+    /// Changed entity type, if any
+    pub entity_type: Option<(TaxEntityType, TaxEntityType)>,
+    /// Changed gross income, if any
+    pub income: Option<(Decimal, Decimal)>,
+    /// Changed tax year, if any
+    pub tax_year: Option<(u16, u16)>,
+    /// Changed filing status, if any
+    pub filing_status: Option<(FilingStatus, FilingStatus)>,
+    /// Deductions present only in the original entity
+    pub removed_deductions: Vec<Deduction>,
+    /// Deductions present only in the compared entity
+    pub added_deductions: Vec<Deduction>,
+}
+
+impl EntityDiff {
+    /// Returns true if the two entities were identical.
+    pub fn is_empty(&self) -> bool {
+        *self == EntityDiff::default()
+    }
+}
+
+impl fmt::Display for EntityDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no differences");
+        }
+
+        let mut lines = Vec::new();
+        if let Some((before, after)) = &self.entity_type {
+            lines.push(format!("entity type: {:?} -> {:?}", before, after));
+        }
+        if let Some((before, after)) = &self.income {
+            lines.push(format!("income: {} -> {}", before, after));
+        }
+        if let Some((before, after)) = &self.tax_year {
+            lines.push(format!("tax year: {} -> {}", before, after));
+        }
+        if let Some((before, after)) = &self.filing_status {
+            lines.push(format!("filing status: {:?} -> {:?}", before, after));
+        }
+        for deduction in &self.removed_deductions {
+            lines.push(format!("- deduction: {}", deduction));
+        }
+        for deduction in &self.added_deductions {
+            lines.push(format!("+ deduction: {}", deduction));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl fmt::Display for Deduction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.basis {
+            DeductionBasis::Fixed => write!(f, "{} ({:?})", self.amount, self.category)?,
+            DeductionBasis::PercentageOfIncome(rate) => write!(
+                f,
+                "{}% of income ({:?})",
+                rate * Decimal::ONE_HUNDRED,
+                self.category
+            )?,
+            DeductionBasis::PerUnit {
+                units,
+                rate_per_unit,
+            } => write!(
+                f,
+                "{} ({} units at {}) ({:?})",
+                self.amount, units, rate_per_unit, self.category
+            )?,
+        }
+        if let Some(reference) = &self.reference {
+            write!(f, " [ref: {}]", reference)?;
+        }
+        Ok(())
+    }
 }
 
 /// Categories of tax deductions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum DeductionType {
     /// Business-related deductions
     Business,
@@ -48,33 +313,796 @@ pub enum DeductionType {
     Personal,
     /// Charitable contribution deductions
     Charitable,
+    /// State and local taxes paid (SALT)
+    StateAndLocalTax,
+    /// Elective deferrals to a retirement plan, such as a 401(k)
+    RetirementContribution,
 }
 
 impl TaxEntity {
     /// Creates a new tax entity without any deductions.
+    ///
+    /// The entity files as `FilingStatus::Single`; use
+    /// [`TaxEntity::with_filing_status`] to choose another status.
     pub fn new(entity_type: TaxEntityType, income: Decimal, tax_year: u16) -> Self {
         Self {
             entity_type,
             income,
             deductions: Vec::new(),
             tax_year,
+            dependents: 0,
+            filing_status: FilingStatus::default(),
+            currency: Currency::default(),
+            deferred_income: Vec::new(),
+            foreign_income: Vec::new(),
+            credits: Vec::new(),
+            income_items: Vec::new(),
+            estimated_payments: Vec::new(),
+        }
+    }
+
+    /// Returns a builder for configuring an entity fluently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{DeductionType, FilingStatus, TaxEntity, TaxEntityType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let entity = TaxEntity::builder()
+    ///     .entity_type(TaxEntityType::Individual)
+    ///     .income(dec!(90000))
+    ///     .tax_year(2024)
+    ///     .filing_status(FilingStatus::HeadOfHousehold)
+    ///     .deduction(dec!(5000), DeductionType::Charitable)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(entity.taxable_income(), dec!(85000));
+    /// ```
+    pub fn builder() -> TaxEntityBuilder {
+        TaxEntityBuilder::default()
+    }
+
+    /// Sets the filing status this entity's return is filed under.
+    ///
+    /// # Arguments
+    ///
+    /// * `filing_status` - The filing status
+    pub fn with_filing_status(mut self, filing_status: FilingStatus) -> Self {
+        self.filing_status = filing_status;
+        self
+    }
+
+    /// Returns a copy of this entity filed in a different tax year.
+    ///
+    /// The copy recognizes the same income as this entity does: deferred
+    /// income recognized in this entity's year is folded into `income`, so
+    /// changing the year doesn't drop it. Deferred income for other years
+    /// stays deferred, and counts in the copy if it falls in `tax_year`.
+    /// Deductions are carried over unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `tax_year` - The tax year for the copy
+    pub fn for_year(&self, tax_year: u16) -> TaxEntity {
+        let (recognized, deferred_income): (Vec<_>, Vec<_>) = self
+            .deferred_income
+            .iter()
+            .cloned()
+            .partition(|item| item.recognition_year == self.tax_year);
+
+        TaxEntity {
+            tax_year,
+            income: recognized
+                .iter()
+                .fold(self.income, |acc, item| acc + item.amount),
+            deferred_income,
+            ..self.clone()
         }
     }
 
+    /// Returns a copy of this entity filed in the following tax year.
+    ///
+    /// See [`TaxEntity::for_year`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::Incompatible` if the entity's tax year is the last
+    /// one representable.
+    pub fn for_next_year(&self) -> Result<TaxEntity, TaxError> {
+        let next_year = self
+            .tax_year
+            .checked_add(1)
+            .ok_or_else(|| TaxError::Incompatible {
+                reason: format!("no tax year follows {}", self.tax_year),
+            })?;
+        Ok(self.for_year(next_year))
+    }
+
     /// Adds a new deduction to this entity.
     pub fn add_deduction(&mut self, amount: Decimal, category: DeductionType) {
-        self.deductions.push(Deduction { amount, category });
+        self.deductions.push(Deduction {
+            amount,
+            category,
+            reference: None,
+            basis: DeductionBasis::Fixed,
+        });
+    }
+
+    /// Adds a new deduction backed by supporting documentation.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount to be deducted
+    /// * `category` - The category of the deduction
+    /// * `reference` - Identifier for the supporting document, such as a receipt ID
+    pub fn add_documented_deduction(
+        &mut self,
+        amount: Decimal,
+        category: DeductionType,
+        reference: impl Into<String>,
+    ) {
+        self.deductions.push(Deduction {
+            amount,
+            category,
+            reference: Some(reference.into()),
+            basis: DeductionBasis::Fixed,
+        });
+    }
+
+    /// Adds a deduction worth a percentage of gross income.
+    ///
+    /// The amount is not fixed when the deduction is added; it is recalculated
+    /// from the entity's current income each time deductions are totaled, so
+    /// later changes to income are reflected automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The share of gross income to deduct (e.g., 0.05 for 5%)
+    /// * `category` - The category of the deduction
+    pub fn add_percentage_deduction(&mut self, rate: Decimal, category: DeductionType) {
+        self.deductions.push(Deduction {
+            amount: Decimal::ZERO,
+            category,
+            reference: None,
+            basis: DeductionBasis::PercentageOfIncome(rate),
+        });
+    }
+
+    /// Adds a deduction computed from a number of units and a rate per unit.
+    ///
+    /// The deducted amount is `units * rate_per_unit`; both inputs are kept on
+    /// the deduction's basis so the calculation can be audited.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - Number of units claimed (e.g., miles driven or square feet)
+    /// * `rate_per_unit` - Amount deducted per unit
+    /// * `category` - The category of the deduction
+    pub fn add_unit_deduction(
+        &mut self,
+        units: Decimal,
+        rate_per_unit: Decimal,
+        category: DeductionType,
+    ) {
+        self.deductions.push(Deduction {
+            amount: units * rate_per_unit,
+            category,
+            reference: None,
+            basis: DeductionBasis::PerUnit {
+                units,
+                rate_per_unit,
+            },
+        });
+    }
+
+    /// Removes deductions that exactly duplicate an earlier one.
+    ///
+    /// Two deductions are duplicates only when their amount, category, reference
+    /// and basis all match, so repeated deductions that differ in any of these
+    /// (such as separate receipts for the same amount) are kept. The first
+    /// occurrence of each deduction is retained in its original position.
+    ///
+    /// # Returns
+    ///
+    /// The number of deductions removed.
+    pub fn dedup_deductions(&mut self) -> usize {
+        let original_len = self.deductions.len();
+        let mut unique: Vec<Deduction> = Vec::with_capacity(original_len);
+        for deduction in self.deductions.drain(..) {
+            if !unique.contains(&deduction) {
+                unique.push(deduction);
+            }
+        }
+        self.deductions = unique;
+
+        original_len - self.deductions.len()
+    }
+
+    /// Adds income that is taxed in a specific recognition year.
+    ///
+    /// The income only counts toward taxable income when `recognition_year`
+    /// matches the entity's `tax_year`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount of income
+    /// * `recognition_year` - The tax year in which the income is recognized
+    pub fn add_deferred_income(&mut self, amount: Decimal, recognition_year: u16) {
+        self.deferred_income.push(DeferredIncome {
+            amount,
+            recognition_year,
+        });
+    }
+
+    /// Adds income earned in a foreign currency.
+    ///
+    /// The amount is converted at `rate_to_home` and added to `income`, so all
+    /// calculations work in the home currency. The original amount, currency
+    /// and rate are recorded in `foreign_income`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount in the foreign currency
+    /// * `currency` - The currency the income was earned in
+    /// * `rate_to_home` - Home-currency value of one unit of `currency`
+    pub fn add_foreign_income(
+        &mut self,
+        amount: Decimal,
+        currency: Currency,
+        rate_to_home: Decimal,
+    ) {
+        let foreign = ForeignIncome {
+            amount,
+            currency,
+            rate_to_home,
+        };
+        self.income += foreign.home_amount();
+        self.foreign_income.push(foreign);
+    }
+
+    /// Adds income of a particular type.
+    ///
+    /// The amount is added to `income`, so calculations that don't
+    /// distinguish income types tax it like any other income, and recorded in
+    /// `income_items` for calculations that do.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount of income
+    /// * `income_type` - How the income is taxed
+    pub fn add_income(&mut self, amount: Decimal, income_type: IncomeType) {
+        self.income += amount;
+        self.income_items.push(IncomeItem {
+            amount,
+            income_type,
+        });
+    }
+
+    /// Returns the total income recorded with `add_income` under a type.
+    ///
+    /// Income added directly to `income`, without a type, is not included.
+    pub fn income_of_type(&self, income_type: IncomeType) -> Decimal {
+        self.income_items
+            .iter()
+            .filter(|item| item.income_type == income_type)
+            .fold(Decimal::ZERO, |acc, item| acc + item.amount)
+    }
+
+    /// Returns the income taxed at capital gains rates: long-term gains and
+    /// qualified dividends.
+    pub fn preferential_income(&self) -> Decimal {
+        self.income_items
+            .iter()
+            .filter(|item| item.income_type.is_preferential())
+            .fold(Decimal::ZERO, |acc, item| acc + item.amount)
+    }
+
+    /// Adds a tax credit to this entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount of the credit
+    /// * `category` - The category of the credit
+    /// * `refundable` - Whether any excess over the tax owed is refunded
+    pub fn add_credit(&mut self, amount: Decimal, category: CreditType, refundable: bool) {
+        self.credits.push(TaxCredit {
+            amount,
+            category,
+            refundable,
+        });
+    }
+
+    /// Records an estimated tax payment made toward this tax year.
+    pub fn add_estimated_payment(&mut self, amount: Decimal) {
+        self.estimated_payments.push(amount);
+    }
+
+    /// Returns the total of all estimated tax payments.
+    pub fn total_estimated_payments(&self) -> Decimal {
+        self.estimated_payments
+            .iter()
+            .fold(Decimal::ZERO, |acc, amount| acc + amount)
+    }
+
+    /// Calculates gross income recognized in this entity's tax year.
+    ///
+    /// This is `income` plus any deferred income whose recognition year is the
+    /// entity's `tax_year`.
+    pub fn recognized_income(&self) -> Decimal {
+        self.deferred_income
+            .iter()
+            .filter(|item| item.recognition_year == self.tax_year)
+            .fold(self.income, |acc, item| acc + item.amount)
     }
 
     /// Calculates the total of all deductions.
+    ///
+    /// Percentage-based deductions are evaluated against the current recognized income.
     pub fn total_deductions(&self) -> Decimal {
+        let income = self.recognized_income();
         self.deductions
             .iter()
-            .fold(Decimal::ZERO, |acc, d| acc + d.amount)
+            .fold(Decimal::ZERO, |acc, d| acc + d.amount_for(income))
     }
 
     /// Calculates taxable income after applying all deductions.
+    ///
+    /// Only income recognized in this entity's tax year is included.
     pub fn taxable_income(&self) -> Decimal {
-        self.income - self.total_deductions()
+        self.recognized_income() - self.total_deductions()
+    }
+
+    /// Calculates the total of all deductions after applying category caps.
+    ///
+    /// Deductions are totaled per category and each category total is limited
+    /// by its cap in `rules`. Percentage-based deductions and percent-of-income
+    /// caps are both evaluated against the current recognized income.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The caps to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{DeductionRules, DeductionType, TaxEntity, TaxEntityType};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+    /// entity.add_deduction(dec!(15000), DeductionType::Personal);
+    ///
+    /// let rules = DeductionRules::new().with_flat_cap(DeductionType::Personal, dec!(10000));
+    /// assert_eq!(entity.allowed_deductions(&rules), dec!(10000));
+    /// ```
+    pub fn allowed_deductions(&self, rules: &DeductionRules) -> Decimal {
+        let income = self.recognized_income();
+
+        let mut totals: HashMap<&DeductionType, Decimal> = HashMap::new();
+        for deduction in &self.deductions {
+            *totals.entry(&deduction.category).or_default() += deduction.amount_for(income);
+        }
+
+        totals
+            .into_iter()
+            .fold(Decimal::ZERO, |acc, (category, total)| {
+                acc + rules.apply(category, total, income)
+            })
+    }
+
+    /// Calculates taxable income after applying deductions limited by `rules`.
+    ///
+    /// Like [`TaxEntity::taxable_income`], but deductions are totaled with
+    /// [`TaxEntity::allowed_deductions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The caps to apply
+    pub fn taxable_income_with_rules(&self, rules: &DeductionRules) -> Decimal {
+        self.recognized_income() - self.allowed_deductions(rules)
+    }
+
+    /// Compares this entity against another and reports what changed.
+    ///
+    /// Useful when reconciling a recomputed return against a stored one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The entity to compare against
+    ///
+    /// # Returns
+    ///
+    /// An `EntityDiff` describing changes from `self` to `other`.
+    pub fn diff(&self, other: &TaxEntity) -> EntityDiff {
+        fn changed<T: PartialEq + Clone>(before: &T, after: &T) -> Option<(T, T)> {
+            (before != after).then(|| (before.clone(), after.clone()))
+        }
+
+        let mut added_deductions = other.deductions.clone();
+        let mut removed_deductions = Vec::new();
+        for deduction in &self.deductions {
+            match added_deductions.iter().position(|d| d == deduction) {
+                Some(index) => {
+                    added_deductions.remove(index);
+                }
+                None => removed_deductions.push(deduction.clone()),
+            }
+        }
+
+        EntityDiff {
+            entity_type: changed(&self.entity_type, &other.entity_type),
+            income: changed(&self.income, &other.income),
+            tax_year: changed(&self.tax_year, &other.tax_year),
+            filing_status: changed(&self.filing_status, &other.filing_status),
+            removed_deductions,
+            added_deductions,
+        }
+    }
+}
+
+/// Fluent builder for [`TaxEntity`], created with [`TaxEntity::builder`].
+///
+/// The entity type, income and tax year are required. Other fields default
+/// as in [`TaxEntity::new`].
+#[derive(Debug, Clone, Default)]
+pub struct TaxEntityBuilder {
+    entity_type: Option<TaxEntityType>,
+    income: Option<Decimal>,
+    tax_year: Option<u16>,
+    deductions: Vec<Deduction>,
+    credits: Vec<TaxCredit>,
+    dependents: u8,
+    filing_status: FilingStatus,
+    currency: Currency,
+}
+
+impl TaxEntityBuilder {
+    /// Sets the type of entity being taxed.
+    pub fn entity_type(mut self, entity_type: TaxEntityType) -> Self {
+        self.entity_type = Some(entity_type);
+        self
+    }
+
+    /// Sets the gross income before deductions.
+    pub fn income(mut self, income: Decimal) -> Self {
+        self.income = Some(income);
+        self
+    }
+
+    /// Sets the tax year.
+    pub fn tax_year(mut self, tax_year: u16) -> Self {
+        self.tax_year = Some(tax_year);
+        self
+    }
+
+    /// Sets the filing status.
+    pub fn filing_status(mut self, filing_status: FilingStatus) -> Self {
+        self.filing_status = filing_status;
+        self
+    }
+
+    /// Sets the currency income and deductions are denominated in.
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Sets the number of dependents claimed.
+    pub fn dependents(mut self, dependents: u8) -> Self {
+        self.dependents = dependents;
+        self
+    }
+
+    /// Adds a fixed deduction, as with [`TaxEntity::add_deduction`].
+    pub fn deduction(mut self, amount: Decimal, category: DeductionType) -> Self {
+        self.deductions.push(Deduction {
+            amount,
+            category,
+            reference: None,
+            basis: DeductionBasis::Fixed,
+        });
+        self
+    }
+
+    /// Adds a deduction worth a share of gross income, as with
+    /// [`TaxEntity::add_percentage_deduction`].
+    pub fn percentage_deduction(mut self, rate: Decimal, category: DeductionType) -> Self {
+        self.deductions.push(Deduction {
+            amount: Decimal::ZERO,
+            category,
+            reference: None,
+            basis: DeductionBasis::PercentageOfIncome(rate),
+        });
+        self
+    }
+
+    /// Adds a tax credit, as with [`TaxEntity::add_credit`].
+    pub fn credit(mut self, amount: Decimal, category: CreditType, refundable: bool) -> Self {
+        self.credits.push(TaxCredit {
+            amount,
+            category,
+            refundable,
+        });
+        self
+    }
+
+    /// Builds the entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaxError::MissingField` naming the first required field that
+    /// was not set.
+    pub fn build(self) -> Result<TaxEntity, TaxError> {
+        let entity_type = self
+            .entity_type
+            .ok_or(TaxError::MissingField("entity_type"))?;
+        let income = self.income.ok_or(TaxError::MissingField("income"))?;
+        let tax_year = self.tax_year.ok_or(TaxError::MissingField("tax_year"))?;
+
+        Ok(TaxEntity {
+            deductions: self.deductions,
+            credits: self.credits,
+            dependents: self.dependents,
+            filing_status: self.filing_status,
+            currency: self.currency,
+            ..TaxEntity::new(entity_type, income, tax_year)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_diff_income_and_deduction() {
+        let mut original = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        original.add_deduction(dec!(5000), DeductionType::Personal);
+        original.add_deduction(dec!(2000), DeductionType::Charitable);
+
+        let mut updated = TaxEntity::new(TaxEntityType::Individual, dec!(95000), 2024);
+        updated.add_deduction(dec!(5000), DeductionType::Personal);
+        updated.add_deduction(dec!(3000), DeductionType::Charitable);
+
+        let diff = original.diff(&updated);
+
+        assert_eq!(diff.income, Some((dec!(100000), dec!(95000))));
+        assert_eq!(diff.tax_year, None);
+        assert_eq!(diff.entity_type, None);
+        assert_eq!(diff.removed_deductions.len(), 1);
+        assert_eq!(diff.removed_deductions[0].amount, dec!(2000));
+        assert_eq!(diff.added_deductions.len(), 1);
+        assert_eq!(diff.added_deductions[0].amount, dec!(3000));
+        assert_eq!(
+            diff.to_string(),
+            "income: 100000 -> 95000\n\
+             - deduction: 2000 (Charitable)\n\
+             + deduction: 3000 (Charitable)"
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_filing_status() {
+        let original = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        let married = original
+            .clone()
+            .with_filing_status(FilingStatus::MarriedFilingJointly);
+
+        let diff = original.diff(&married);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.filing_status,
+            Some((FilingStatus::Single, FilingStatus::MarriedFilingJointly))
+        );
+        assert_eq!(
+            diff.to_string(),
+            "filing status: Single -> MarriedFilingJointly"
+        );
+        assert!(original.diff(&original.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_changed_reference() {
+        let mut original = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        original.add_documented_deduction(dec!(1200), DeductionType::Business, "receipt-001");
+
+        let mut updated = original.clone();
+        updated.deductions.clear();
+        updated.add_documented_deduction(dec!(1200), DeductionType::Business, "receipt-002");
+
+        let diff = original.diff(&updated);
+        assert_eq!(
+            diff.removed_deductions[0].reference.as_deref(),
+            Some("receipt-001")
+        );
+        assert_eq!(
+            diff.to_string(),
+            "- deduction: 1200 (Business) [ref: receipt-001]\n\
+             + deduction: 1200 (Business) [ref: receipt-002]"
+        );
+    }
+
+    #[test]
+    fn test_percentage_deduction_tracks_income() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        entity.add_deduction(dec!(2000), DeductionType::Personal);
+        entity.add_percentage_deduction(dec!(0.05), DeductionType::Business);
+
+        assert_eq!(entity.total_deductions(), dec!(7000));
+        assert_eq!(entity.taxable_income(), dec!(93000));
+
+        entity.income = dec!(60000);
+
+        assert_eq!(entity.total_deductions(), dec!(5000));
+        assert_eq!(entity.taxable_income(), dec!(55000));
+    }
+
+    #[test]
+    fn test_charitable_deductions_capped_at_share_of_income() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(100000), 2024);
+        entity.add_deduction(dec!(50000), DeductionType::Charitable);
+        entity.add_deduction(dec!(25000), DeductionType::Charitable);
+        entity.add_deduction(dec!(5000), DeductionType::Business);
+        let rules =
+            DeductionRules::new().with_percent_of_income_cap(DeductionType::Charitable, dec!(0.60));
+
+        // 75,000 of charitable gifts is limited to 60% of 100,000
+        assert_eq!(entity.total_deductions(), dec!(80000));
+        assert_eq!(entity.allowed_deductions(&rules), dec!(65000));
+        assert_eq!(entity.taxable_income_with_rules(&rules), dec!(35000));
+
+        // Below the cap, the full amount is allowed
+        entity.income = dec!(200000);
+        assert_eq!(entity.allowed_deductions(&rules), dec!(80000));
+    }
+
+    #[test]
+    fn test_builder_with_multiple_deductions() {
+        let entity = TaxEntity::builder()
+            .entity_type(TaxEntityType::Individual)
+            .income(dec!(120000))
+            .tax_year(2024)
+            .filing_status(FilingStatus::MarriedFilingJointly)
+            .dependents(2)
+            .deduction(dec!(8000), DeductionType::Charitable)
+            .deduction(dec!(4000), DeductionType::Business)
+            .percentage_deduction(dec!(0.05), DeductionType::RetirementContribution)
+            .credit(dec!(2000), CreditType::Child, false)
+            .build()
+            .unwrap();
+
+        assert_eq!(entity.filing_status, FilingStatus::MarriedFilingJointly);
+        assert_eq!(entity.dependents, 2);
+        assert_eq!(entity.deductions.len(), 3);
+        assert_eq!(entity.credits.len(), 1);
+        // 120,000 less 8,000, 4,000 and 5% of income
+        assert_eq!(entity.taxable_income(), dec!(102000));
+
+        let missing = TaxEntity::builder()
+            .entity_type(TaxEntityType::Individual)
+            .tax_year(2024)
+            .build();
+        assert!(matches!(missing, Err(TaxError::MissingField("income"))));
+    }
+
+    #[test]
+    fn test_diff_identical_entities() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        entity.add_deduction(dec!(1000), DeductionType::Business);
+
+        let diff = entity.diff(&entity.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "no differences");
+    }
+
+    #[test]
+    fn test_dedup_deductions_removes_exact_duplicates() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(80000), 2024);
+        entity.add_documented_deduction(dec!(500), DeductionType::Charitable, "receipt-100");
+        entity.add_deduction(dec!(3000), DeductionType::Business);
+        entity.add_documented_deduction(dec!(500), DeductionType::Charitable, "receipt-100");
+        entity.add_documented_deduction(dec!(500), DeductionType::Charitable, "receipt-101");
+
+        assert_eq!(entity.dedup_deductions(), 1);
+        assert_eq!(entity.deductions.len(), 3);
+        assert_eq!(entity.total_deductions(), dec!(4000));
+        assert_eq!(
+            entity.deductions[0].reference.as_deref(),
+            Some("receipt-100")
+        );
+        assert_eq!(
+            entity.deductions[2].reference.as_deref(),
+            Some("receipt-101")
+        );
+
+        assert_eq!(entity.dedup_deductions(), 0);
+    }
+
+    #[test]
+    fn test_deferred_income_counts_only_in_recognition_year() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(90000), 2024);
+        entity.add_deduction(dec!(10000), DeductionType::Personal);
+        entity.add_deferred_income(dec!(15000), 2024);
+        entity.add_deferred_income(dec!(40000), 2026);
+
+        assert_eq!(entity.recognized_income(), dec!(105000));
+        assert_eq!(entity.taxable_income(), dec!(95000));
+
+        entity.tax_year = 2026;
+        assert_eq!(entity.recognized_income(), dec!(130000));
+    }
+
+    #[test]
+    fn test_for_year_keeps_recognized_income() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(50000), 2024);
+        entity.add_deferred_income(dec!(30000), 2024);
+        entity.add_deferred_income(dec!(10000), 2026);
+
+        let next_year = entity.for_next_year().unwrap();
+        assert_eq!(next_year.tax_year, 2025);
+        assert_eq!(next_year.recognized_income(), dec!(80000));
+        assert_eq!(next_year.for_year(2026).recognized_income(), dec!(90000));
+
+        assert!(matches!(
+            entity.for_year(u16::MAX).for_next_year(),
+            Err(TaxError::Incompatible { .. })
+        ));
+    }
+
+    #[test]
+    fn test_foreign_income_converted_to_home_currency() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(0), 2024);
+        entity.add_foreign_income(dec!(50000), Currency::EUR, dec!(1.1));
+
+        assert_eq!(entity.income, dec!(55000));
+        assert_eq!(entity.taxable_income(), dec!(55000));
+        assert_eq!(entity.foreign_income[0].amount, dec!(50000));
+        assert_eq!(entity.foreign_income[0].currency, Currency::EUR);
+    }
+
+    #[test]
+    fn test_unit_deduction_for_mileage() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(70000), 2024);
+        entity.add_unit_deduction(dec!(10000), dec!(0.655), DeductionType::Business);
+
+        assert_eq!(entity.total_deductions(), dec!(6550));
+        assert_eq!(entity.taxable_income(), dec!(63450));
+        assert_eq!(
+            entity.deductions[0].basis,
+            DeductionBasis::PerUnit {
+                units: dec!(10000),
+                rate_per_unit: dec!(0.655),
+            }
+        );
+        assert_eq!(
+            entity.deductions[0].to_string(),
+            "6550.000 (10000 units at 0.655) (Business)"
+        );
+    }
+
+    #[test]
+    fn test_entity_json_round_trip() {
+        let mut entity = TaxEntity::new(TaxEntityType::Individual, dec!(85000.50), 2024)
+            .with_filing_status(FilingStatus::MarriedFilingJointly);
+        entity.add_documented_deduction(dec!(1200), DeductionType::Charitable, "receipt-17");
+        entity.add_percentage_deduction(dec!(0.05), DeductionType::Business);
+        entity.add_deferred_income(dec!(10000), 2025);
+
+        let json = serde_json::to_string(&entity).unwrap();
+        let restored: TaxEntity = serde_json::from_str(&json).unwrap();
+
+        assert!(entity.diff(&restored).is_empty());
+        assert_eq!(restored.filing_status, FilingStatus::MarriedFilingJointly);
+        assert_eq!(restored.deferred_income, entity.deferred_income);
+        assert_eq!(restored.taxable_income(), entity.taxable_income());
+
+        // Fields added after the first release default when absent
+        let minimal: TaxEntity = serde_json::from_str(
+            r#"{"entity_type":"Corporation","income":"1000","deductions":[],"tax_year":2024}"#,
+        )
+        .unwrap();
+        assert_eq!(minimal.filing_status, FilingStatus::Single);
+        assert_eq!(minimal.currency, Currency::USD);
     }
 }
@@ -1,26 +1,392 @@
+//! Defines the jurisdictions that levy taxes.
+
+use crate::errors::TaxError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::hash::Hash;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Country {
     USA,
     Canada,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+/// A US state or the District of Columbia.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum USState {
+    Alabama,
+    Alaska,
+    Arizona,
+    Arkansas,
     California,
+    Colorado,
+    Connecticut,
+    Delaware,
+    DistrictOfColumbia,
+    Florida,
+    Georgia,
+    Hawaii,
+    Idaho,
+    Illinois,
+    Indiana,
+    Iowa,
+    Kansas,
+    Kentucky,
+    Louisiana,
+    Maine,
+    Maryland,
+    Massachusetts,
+    Michigan,
+    Minnesota,
+    Mississippi,
+    Missouri,
+    Montana,
+    Nebraska,
+    Nevada,
+    NewHampshire,
+    NewJersey,
+    NewMexico,
     NewYork,
-    // Add more as needed
+    NorthCarolina,
+    NorthDakota,
+    Ohio,
+    Oklahoma,
+    Oregon,
+    Pennsylvania,
+    RhodeIsland,
+    SouthCarolina,
+    SouthDakota,
+    Tennessee,
+    Texas,
+    Utah,
+    Vermont,
+    Virginia,
+    Washington,
+    WestVirginia,
+    Wisconsin,
+    Wyoming,
+}
+
+impl USState {
+    /// Every state, in alphabetical order of name.
+    pub const ALL: [USState; 51] = [
+        USState::Alabama,
+        USState::Alaska,
+        USState::Arizona,
+        USState::Arkansas,
+        USState::California,
+        USState::Colorado,
+        USState::Connecticut,
+        USState::Delaware,
+        USState::DistrictOfColumbia,
+        USState::Florida,
+        USState::Georgia,
+        USState::Hawaii,
+        USState::Idaho,
+        USState::Illinois,
+        USState::Indiana,
+        USState::Iowa,
+        USState::Kansas,
+        USState::Kentucky,
+        USState::Louisiana,
+        USState::Maine,
+        USState::Maryland,
+        USState::Massachusetts,
+        USState::Michigan,
+        USState::Minnesota,
+        USState::Mississippi,
+        USState::Missouri,
+        USState::Montana,
+        USState::Nebraska,
+        USState::Nevada,
+        USState::NewHampshire,
+        USState::NewJersey,
+        USState::NewMexico,
+        USState::NewYork,
+        USState::NorthCarolina,
+        USState::NorthDakota,
+        USState::Ohio,
+        USState::Oklahoma,
+        USState::Oregon,
+        USState::Pennsylvania,
+        USState::RhodeIsland,
+        USState::SouthCarolina,
+        USState::SouthDakota,
+        USState::Tennessee,
+        USState::Texas,
+        USState::Utah,
+        USState::Vermont,
+        USState::Virginia,
+        USState::Washington,
+        USState::WestVirginia,
+        USState::Wisconsin,
+        USState::Wyoming,
+    ];
+
+    /// Returns the state's full name, such as "California".
+    pub fn name(&self) -> &'static str {
+        match self {
+            USState::Alabama => "Alabama",
+            USState::Alaska => "Alaska",
+            USState::Arizona => "Arizona",
+            USState::Arkansas => "Arkansas",
+            USState::California => "California",
+            USState::Colorado => "Colorado",
+            USState::Connecticut => "Connecticut",
+            USState::Delaware => "Delaware",
+            USState::DistrictOfColumbia => "District of Columbia",
+            USState::Florida => "Florida",
+            USState::Georgia => "Georgia",
+            USState::Hawaii => "Hawaii",
+            USState::Idaho => "Idaho",
+            USState::Illinois => "Illinois",
+            USState::Indiana => "Indiana",
+            USState::Iowa => "Iowa",
+            USState::Kansas => "Kansas",
+            USState::Kentucky => "Kentucky",
+            USState::Louisiana => "Louisiana",
+            USState::Maine => "Maine",
+            USState::Maryland => "Maryland",
+            USState::Massachusetts => "Massachusetts",
+            USState::Michigan => "Michigan",
+            USState::Minnesota => "Minnesota",
+            USState::Mississippi => "Mississippi",
+            USState::Missouri => "Missouri",
+            USState::Montana => "Montana",
+            USState::Nebraska => "Nebraska",
+            USState::Nevada => "Nevada",
+            USState::NewHampshire => "New Hampshire",
+            USState::NewJersey => "New Jersey",
+            USState::NewMexico => "New Mexico",
+            USState::NewYork => "New York",
+            USState::NorthCarolina => "North Carolina",
+            USState::NorthDakota => "North Dakota",
+            USState::Ohio => "Ohio",
+            USState::Oklahoma => "Oklahoma",
+            USState::Oregon => "Oregon",
+            USState::Pennsylvania => "Pennsylvania",
+            USState::RhodeIsland => "Rhode Island",
+            USState::SouthCarolina => "South Carolina",
+            USState::SouthDakota => "South Dakota",
+            USState::Tennessee => "Tennessee",
+            USState::Texas => "Texas",
+            USState::Utah => "Utah",
+            USState::Vermont => "Vermont",
+            USState::Virginia => "Virginia",
+            USState::Washington => "Washington",
+            USState::WestVirginia => "West Virginia",
+            USState::Wisconsin => "Wisconsin",
+            USState::Wyoming => "Wyoming",
+        }
+    }
+
+    /// Returns the state's two-letter postal abbreviation, such as "CA".
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            USState::Alabama => "AL",
+            USState::Alaska => "AK",
+            USState::Arizona => "AZ",
+            USState::Arkansas => "AR",
+            USState::California => "CA",
+            USState::Colorado => "CO",
+            USState::Connecticut => "CT",
+            USState::Delaware => "DE",
+            USState::DistrictOfColumbia => "DC",
+            USState::Florida => "FL",
+            USState::Georgia => "GA",
+            USState::Hawaii => "HI",
+            USState::Idaho => "ID",
+            USState::Illinois => "IL",
+            USState::Indiana => "IN",
+            USState::Iowa => "IA",
+            USState::Kansas => "KS",
+            USState::Kentucky => "KY",
+            USState::Louisiana => "LA",
+            USState::Maine => "ME",
+            USState::Maryland => "MD",
+            USState::Massachusetts => "MA",
+            USState::Michigan => "MI",
+            USState::Minnesota => "MN",
+            USState::Mississippi => "MS",
+            USState::Missouri => "MO",
+            USState::Montana => "MT",
+            USState::Nebraska => "NE",
+            USState::Nevada => "NV",
+            USState::NewHampshire => "NH",
+            USState::NewJersey => "NJ",
+            USState::NewMexico => "NM",
+            USState::NewYork => "NY",
+            USState::NorthCarolina => "NC",
+            USState::NorthDakota => "ND",
+            USState::Ohio => "OH",
+            USState::Oklahoma => "OK",
+            USState::Oregon => "OR",
+            USState::Pennsylvania => "PA",
+            USState::RhodeIsland => "RI",
+            USState::SouthCarolina => "SC",
+            USState::SouthDakota => "SD",
+            USState::Tennessee => "TN",
+            USState::Texas => "TX",
+            USState::Utah => "UT",
+            USState::Vermont => "VT",
+            USState::Virginia => "VA",
+            USState::Washington => "WA",
+            USState::WestVirginia => "WV",
+            USState::Wisconsin => "WI",
+            USState::Wyoming => "WY",
+        }
+    }
+}
+
+impl fmt::Display for USState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for USState {
+    type Err = TaxError;
+
+    /// Parses a state from its abbreviation or full name, ignoring case and
+    /// surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|item| {
+                item.abbreviation().eq_ignore_ascii_case(s) || item.name().eq_ignore_ascii_case(s)
+            })
+            .ok_or_else(|| TaxError::ParseError(format!("Unknown US state: {}", s)))
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+/// A Canadian province or territory.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum CanadianProvince {
-    Ontario,
+    Alberta,
     BritishColumbia,
-    // Add more as needed
+    Manitoba,
+    NewBrunswick,
+    NewfoundlandAndLabrador,
+    NorthwestTerritories,
+    NovaScotia,
+    Nunavut,
+    Ontario,
+    PrinceEdwardIsland,
+    Quebec,
+    Saskatchewan,
+    Yukon,
+}
+
+impl CanadianProvince {
+    /// Every province or territory, in alphabetical order of name.
+    pub const ALL: [CanadianProvince; 13] = [
+        CanadianProvince::Alberta,
+        CanadianProvince::BritishColumbia,
+        CanadianProvince::Manitoba,
+        CanadianProvince::NewBrunswick,
+        CanadianProvince::NewfoundlandAndLabrador,
+        CanadianProvince::NorthwestTerritories,
+        CanadianProvince::NovaScotia,
+        CanadianProvince::Nunavut,
+        CanadianProvince::Ontario,
+        CanadianProvince::PrinceEdwardIsland,
+        CanadianProvince::Quebec,
+        CanadianProvince::Saskatchewan,
+        CanadianProvince::Yukon,
+    ];
+
+    /// Returns the province or territory's full name, such as "British Columbia".
+    pub fn name(&self) -> &'static str {
+        match self {
+            CanadianProvince::Alberta => "Alberta",
+            CanadianProvince::BritishColumbia => "British Columbia",
+            CanadianProvince::Manitoba => "Manitoba",
+            CanadianProvince::NewBrunswick => "New Brunswick",
+            CanadianProvince::NewfoundlandAndLabrador => "Newfoundland and Labrador",
+            CanadianProvince::NorthwestTerritories => "Northwest Territories",
+            CanadianProvince::NovaScotia => "Nova Scotia",
+            CanadianProvince::Nunavut => "Nunavut",
+            CanadianProvince::Ontario => "Ontario",
+            CanadianProvince::PrinceEdwardIsland => "Prince Edward Island",
+            CanadianProvince::Quebec => "Quebec",
+            CanadianProvince::Saskatchewan => "Saskatchewan",
+            CanadianProvince::Yukon => "Yukon",
+        }
+    }
+
+    /// Returns the province or territory's two-letter postal abbreviation, such as "BC".
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            CanadianProvince::Alberta => "AB",
+            CanadianProvince::BritishColumbia => "BC",
+            CanadianProvince::Manitoba => "MB",
+            CanadianProvince::NewBrunswick => "NB",
+            CanadianProvince::NewfoundlandAndLabrador => "NL",
+            CanadianProvince::NorthwestTerritories => "NT",
+            CanadianProvince::NovaScotia => "NS",
+            CanadianProvince::Nunavut => "NU",
+            CanadianProvince::Ontario => "ON",
+            CanadianProvince::PrinceEdwardIsland => "PE",
+            CanadianProvince::Quebec => "QC",
+            CanadianProvince::Saskatchewan => "SK",
+            CanadianProvince::Yukon => "YT",
+        }
+    }
+}
+
+impl fmt::Display for CanadianProvince {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CanadianProvince {
+    type Err = TaxError;
+
+    /// Parses a province or territory from its abbreviation or full name, ignoring case and
+    /// surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|item| {
+                item.abbreviation().eq_ignore_ascii_case(s) || item.name().eq_ignore_ascii_case(s)
+            })
+            .ok_or_else(|| {
+                TaxError::ParseError(format!("Unknown Canadian province or territory: {}", s))
+            })
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+/// A government that levies taxes.
+///
+/// Jurisdictions convert to and from a lowercase string of the country's
+/// two-letter code followed by a hyphen and either `federal` or the
+/// subdivision's postal abbreviation:
+///
+/// | Jurisdiction | String |
+/// |---|---|
+/// | `Federal(Country::USA)` | `us-federal` |
+/// | `Federal(Country::Canada)` | `ca-federal` |
+/// | `USState(USState::California)` | `us-ca` |
+/// | `CanadianProvince(CanadianProvince::Ontario)` | `ca-on` |
+///
+/// Parsing ignores case and also accepts full names, with hyphens or
+/// spaces between words, such as `ca-ontario` or `us-new-york`.
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::models::{Jurisdiction, USState};
+///
+/// let jurisdiction: Jurisdiction = "us-ca".parse()?;
+/// assert_eq!(jurisdiction, Jurisdiction::USState(USState::California));
+/// assert_eq!(jurisdiction.to_string(), "us-ca");
+/// # Ok::<(), tax_engine::TaxError>(())
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Jurisdiction {
     Federal(Country),
     USState(USState),
@@ -36,3 +402,177 @@ impl Jurisdiction {
         }
     }
 }
+
+impl fmt::Display for Jurisdiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let country = match self.get_country() {
+            Country::USA => "us",
+            Country::Canada => "ca",
+        };
+        let region = match self {
+            Jurisdiction::Federal(_) => "federal",
+            Jurisdiction::USState(state) => state.abbreviation(),
+            Jurisdiction::CanadianProvince(province) => province.abbreviation(),
+        };
+        write!(f, "{}-{}", country, region.to_lowercase())
+    }
+}
+
+impl FromStr for Jurisdiction {
+    type Err = TaxError;
+
+    /// Parses a jurisdiction in the format described on [`Jurisdiction`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (country, region) = s.trim().split_once('-').ok_or_else(|| {
+            TaxError::InvalidJurisdiction(format!(
+                "expected \"<country>-<region>\", such as \"us-ca\", got \"{}\"",
+                s
+            ))
+        })?;
+        let region = region.replace('-', " ");
+
+        if region.eq_ignore_ascii_case("federal") {
+            return match country.to_ascii_lowercase().as_str() {
+                "us" => Ok(Jurisdiction::Federal(Country::USA)),
+                "ca" => Ok(Jurisdiction::Federal(Country::Canada)),
+                _ => Err(unknown_country(country)),
+            };
+        }
+
+        match country.to_ascii_lowercase().as_str() {
+            "us" => region.parse().map(Jurisdiction::USState).map_err(|_| {
+                TaxError::InvalidJurisdiction(format!("unknown US state \"{}\"", region))
+            }),
+            "ca" => region
+                .parse()
+                .map(Jurisdiction::CanadianProvince)
+                .map_err(|_| {
+                    TaxError::InvalidJurisdiction(format!(
+                        "unknown Canadian province or territory \"{}\"",
+                        region
+                    ))
+                }),
+            _ => Err(unknown_country(country)),
+        }
+    }
+}
+
+/// Builds the error for a jurisdiction string with an unrecognized country.
+fn unknown_country(country: &str) -> TaxError {
+    TaxError::InvalidJurisdiction(format!(
+        "unknown country \"{}\", expected \"us\" or \"ca\"",
+        country
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_states_parse_from_abbreviation_or_name() {
+        assert_eq!("CA".parse::<USState>().unwrap(), USState::California);
+        assert_eq!(
+            "California".parse::<USState>().unwrap(),
+            USState::California
+        );
+        assert_eq!(" new york ".parse::<USState>().unwrap(), USState::NewYork);
+        assert_eq!(
+            "dc".parse::<USState>().unwrap(),
+            USState::DistrictOfColumbia
+        );
+        assert!(matches!(
+            "XX".parse::<USState>(),
+            Err(TaxError::ParseError(_))
+        ));
+
+        for state in USState::ALL {
+            assert_eq!(state.abbreviation().parse::<USState>().unwrap(), state);
+            assert_eq!(state.name().parse::<USState>().unwrap(), state);
+        }
+    }
+
+    #[test]
+    fn test_provinces_parse_from_abbreviation_or_name() {
+        assert_eq!(
+            "QC".parse::<CanadianProvince>().unwrap(),
+            CanadianProvince::Quebec
+        );
+        assert_eq!(
+            "Newfoundland and Labrador"
+                .parse::<CanadianProvince>()
+                .unwrap(),
+            CanadianProvince::NewfoundlandAndLabrador
+        );
+        assert_eq!(
+            CanadianProvince::PrinceEdwardIsland.to_string(),
+            "Prince Edward Island"
+        );
+
+        for province in CanadianProvince::ALL {
+            assert_eq!(
+                province.abbreviation().parse::<CanadianProvince>().unwrap(),
+                province
+            );
+            assert_eq!(
+                province.name().parse::<CanadianProvince>().unwrap(),
+                province
+            );
+        }
+    }
+
+    #[test]
+    fn test_jurisdiction_string_round_trip() {
+        let jurisdictions = [
+            (Jurisdiction::Federal(Country::USA), "us-federal"),
+            (Jurisdiction::Federal(Country::Canada), "ca-federal"),
+            (Jurisdiction::USState(USState::California), "us-ca"),
+            (
+                Jurisdiction::CanadianProvince(CanadianProvince::Ontario),
+                "ca-on",
+            ),
+        ];
+        for (jurisdiction, text) in jurisdictions {
+            assert_eq!(jurisdiction.to_string(), text);
+            assert_eq!(text.parse::<Jurisdiction>().unwrap(), jurisdiction);
+        }
+
+        for state in USState::ALL {
+            let jurisdiction = Jurisdiction::USState(state);
+            assert_eq!(
+                jurisdiction.to_string().parse::<Jurisdiction>().unwrap(),
+                jurisdiction
+            );
+        }
+        for province in CanadianProvince::ALL {
+            let jurisdiction = Jurisdiction::CanadianProvince(province);
+            assert_eq!(
+                jurisdiction.to_string().parse::<Jurisdiction>().unwrap(),
+                jurisdiction
+            );
+        }
+    }
+
+    #[test]
+    fn test_jurisdiction_parses_names_and_rejects_invalid() {
+        assert_eq!(
+            "ca-ontario".parse::<Jurisdiction>().unwrap(),
+            Jurisdiction::CanadianProvince(CanadianProvince::Ontario)
+        );
+        assert_eq!(
+            "US-New-York".parse::<Jurisdiction>().unwrap(),
+            Jurisdiction::USState(USState::NewYork)
+        );
+
+        for invalid in ["", "federal", "mx-federal", "us-on", "ca-tx"] {
+            assert!(
+                matches!(
+                    invalid.parse::<Jurisdiction>(),
+                    Err(TaxError::InvalidJurisdiction(_))
+                ),
+                "{} should not parse",
+                invalid
+            );
+        }
+    }
+}
@@ -4,9 +4,20 @@
 //! the tax calculation system, including tax brackets, entities, and jurisdictions.
 
 mod bracket;
+mod currency;
+mod deduction_rules;
 mod entity;
 mod jurisdiction;
+mod schedule_set;
 
-pub use bracket::{TaxBracket, TaxSchedule};
-pub use entity::{Deduction, DeductionType, TaxEntity, TaxEntityType};
+pub use bracket::{
+    BoundInclusivity, Coverage, RateAdjustment, TaxBracket, TaxSchedule, TaxScheduleBuilder,
+};
+pub use currency::Currency;
+pub use deduction_rules::{DeductionCap, DeductionLimits, DeductionRules};
+pub use entity::{
+    CreditType, Deduction, DeductionBasis, DeductionType, DeferredIncome, EntityDiff, FilingStatus,
+    ForeignIncome, IncomeItem, IncomeType, TaxCredit, TaxEntity, TaxEntityBuilder, TaxEntityType,
+};
 pub use jurisdiction::{CanadianProvince, Country, Jurisdiction, USState};
+pub use schedule_set::TaxScheduleSet;
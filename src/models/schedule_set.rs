@@ -0,0 +1,56 @@
+//! Groups the schedules published for each filing status in a year.
+
+use crate::models::{FilingStatus, TaxSchedule};
+use std::collections::HashMap;
+
+/// The tax schedules for one year, keyed by filing status.
+///
+/// US federal rates are published as one schedule per filing status; this
+/// keeps them together so callers can pick the one matching an entity.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaxScheduleSet {
+    /// The tax year the schedules apply to
+    pub tax_year: u16,
+    /// The schedule for each filing status that has one
+    pub schedules: HashMap<FilingStatus, TaxSchedule>,
+}
+
+impl TaxScheduleSet {
+    /// Creates an empty set for a tax year.
+    ///
+    /// # Arguments
+    ///
+    /// * `tax_year` - The year the schedules will apply to
+    pub fn new(tax_year: u16) -> Self {
+        Self {
+            tax_year,
+            schedules: HashMap::new(),
+        }
+    }
+
+    /// Adds the schedule for a filing status, replacing any existing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `filing_status` - The filing status the schedule applies to
+    /// * `schedule` - The schedule to add
+    pub fn insert(&mut self, filing_status: FilingStatus, schedule: TaxSchedule) {
+        self.schedules.insert(filing_status, schedule);
+    }
+
+    /// Returns the schedule for a filing status, if the set has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tax_engine::models::{FilingStatus, TaxSchedule, TaxScheduleSet};
+    ///
+    /// let mut set = TaxScheduleSet::new(2024);
+    /// set.insert(FilingStatus::Single, TaxSchedule::new(2024, vec![]));
+    /// assert!(set.get(FilingStatus::Single).is_some());
+    /// assert!(set.get(FilingStatus::HeadOfHousehold).is_none());
+    /// ```
+    pub fn get(&self, filing_status: FilingStatus) -> Option<&TaxSchedule> {
+        self.schedules.get(&filing_status)
+    }
+}
@@ -0,0 +1,211 @@
+//! Test doubles and fixtures for code that depends on the crate's traits.
+//!
+//! Available to the crate's own tests and, with the `test-util` feature, to
+//! downstream crates, so they can exercise scrapers, caches and calculators
+//! without network access.
+
+use crate::data::cache::memory::CacheKey;
+use crate::data::scrapers::TaxRateScraper;
+use crate::errors::TaxError;
+use crate::models::{FilingStatus, Jurisdiction, TaxBracket, TaxEntityType, TaxSchedule};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Returns a schedule taxing all income at one rate.
+///
+/// # Arguments
+///
+/// * `tax_year` - The year the schedule applies to
+/// * `rate` - The rate as a decimal (e.g., 0.20 for 20%)
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::testing::flat_schedule;
+/// use rust_decimal_macros::dec;
+///
+/// let schedule = flat_schedule(2024, dec!(0.20));
+/// assert_eq!(schedule.brackets[0].rate, dec!(0.20));
+/// ```
+pub fn flat_schedule(tax_year: u16, rate: Decimal) -> TaxSchedule {
+    TaxSchedule::new(
+        tax_year,
+        vec![TaxBracket {
+            lower_bound: Decimal::ZERO,
+            upper_bound: None,
+            rate,
+        }],
+    )
+}
+
+/// Scraper that serves preconfigured schedules and counts its fetches.
+///
+/// Clones share the fetch count, so a test can keep a clone to inspect after
+/// handing the scraper to a `TaxDataProvider` or `CompositeScraper`.
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::testing::MockScraper;
+/// use tax_engine::{Country, FilingStatus, Jurisdiction, TaxEntityType, TaxRateScraper, TaxSchedule};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let usa = Jurisdiction::Federal(Country::USA);
+/// let scraper = MockScraper::default().with_schedule(
+///     usa.clone(),
+///     TaxEntityType::Individual,
+///     FilingStatus::Single,
+///     TaxSchedule::new(2024, vec![]),
+/// );
+///
+/// let schedule = scraper
+///     .fetch_rates(&usa, &TaxEntityType::Individual, FilingStatus::Single, 2024)
+///     .await
+///     .unwrap();
+/// assert_eq!(schedule.tax_year, 2024);
+/// assert_eq!(scraper.fetch_count(), 1);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockScraper {
+    schedules: HashMap<CacheKey, TaxSchedule>,
+    fetches: Arc<AtomicUsize>,
+}
+
+impl MockScraper {
+    /// Creates a scraper serving the given schedules.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedules` - The schedule to return for each jurisdiction, entity
+    ///   type, filing status and tax year
+    pub fn new(schedules: HashMap<CacheKey, TaxSchedule>) -> Self {
+        Self {
+            schedules,
+            fetches: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Adds a schedule to serve for its own tax year.
+    ///
+    /// # Arguments
+    ///
+    /// * `jurisdiction` - The jurisdiction the schedule is for
+    /// * `entity_type` - The type of entity the schedule is for
+    /// * `filing_status` - The filing status the schedule is for
+    /// * `schedule` - The schedule to return
+    pub fn with_schedule(
+        mut self,
+        jurisdiction: Jurisdiction,
+        entity_type: TaxEntityType,
+        filing_status: FilingStatus,
+        schedule: TaxSchedule,
+    ) -> Self {
+        let key = CacheKey {
+            jurisdiction,
+            entity_type,
+            filing_status,
+            tax_year: schedule.tax_year,
+        };
+        self.schedules.insert(key, schedule);
+        self
+    }
+
+    /// Returns how many times `fetch_rates` has been called on this scraper
+    /// or any of its clones, whether or not the fetch succeeded.
+    pub fn fetch_count(&self) -> usize {
+        self.fetches.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl TaxRateScraper for MockScraper {
+    /// Returns the configured schedule.
+    ///
+    /// # Returns
+    ///
+    /// A copy of the schedule configured for the arguments, or
+    /// `TaxError::UnsupportedJurisdiction` if no schedule is configured for
+    /// the jurisdiction and `TaxError::RateNotAvailable` if none matches the
+    /// other arguments.
+    async fn fetch_rates(
+        &self,
+        jurisdiction: &Jurisdiction,
+        entity_type: &TaxEntityType,
+        filing_status: FilingStatus,
+        tax_year: u16,
+    ) -> Result<TaxSchedule, TaxError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+
+        let key = CacheKey {
+            jurisdiction: jurisdiction.clone(),
+            entity_type: entity_type.clone(),
+            filing_status,
+            tax_year,
+        };
+        match self.schedules.get(&key) {
+            Some(schedule) => Ok(schedule.clone()),
+            None if self.supports_jurisdiction(jurisdiction) => {
+                Err(TaxError::RateNotAvailable(tax_year))
+            }
+            None => Err(TaxError::UnsupportedJurisdiction),
+        }
+    }
+
+    /// Supports every jurisdiction with at least one configured schedule.
+    fn supports_jurisdiction(&self, jurisdiction: &Jurisdiction) -> bool {
+        self.schedules
+            .keys()
+            .any(|key| &key.jurisdiction == jurisdiction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Country, USState};
+
+    #[tokio::test]
+    async fn test_serves_configured_schedules_and_counts_fetches() {
+        let usa = Jurisdiction::Federal(Country::USA);
+        let key = CacheKey {
+            jurisdiction: usa.clone(),
+            entity_type: TaxEntityType::Individual,
+            filing_status: FilingStatus::Single,
+            tax_year: 2024,
+        };
+        let scraper = MockScraper::new(HashMap::from([(key, TaxSchedule::new(2024, vec![]))]));
+        let observer = scraper.clone();
+
+        let found = scraper
+            .fetch_rates(&usa, &TaxEntityType::Individual, FilingStatus::Single, 2024)
+            .await;
+        assert_eq!(found.unwrap().tax_year, 2024);
+
+        let other_year = scraper
+            .fetch_rates(&usa, &TaxEntityType::Individual, FilingStatus::Single, 2023)
+            .await;
+        assert!(matches!(other_year, Err(TaxError::RateNotAvailable(2023))));
+
+        let state = Jurisdiction::USState(USState::Texas);
+        assert!(!scraper.supports_jurisdiction(&state));
+        let unsupported = scraper
+            .fetch_rates(
+                &state,
+                &TaxEntityType::Individual,
+                FilingStatus::Single,
+                2024,
+            )
+            .await;
+        assert!(matches!(
+            unsupported,
+            Err(TaxError::UnsupportedJurisdiction)
+        ));
+
+        assert_eq!(observer.fetch_count(), 3);
+    }
+}
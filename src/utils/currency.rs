@@ -3,7 +3,7 @@
 //! This module provides helper functions for working with currency strings
 //! and decimal values in a consistent format.
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::str::FromStr;
 
 /// Attempts to parse a currency string into a Decimal value.
@@ -34,10 +34,66 @@ use std::str::FromStr;
 /// assert_eq!(invalid, None);
 /// ```
 pub fn parse_currency_string(input: &str) -> Option<Decimal> {
-    let cleaned = input.trim().replace('$', "").replace(',', "");
+    let cleaned = input.trim().replace(['$', ','], "");
     Decimal::from_str(&cleaned).ok()
 }
 
+/// Attempts to parse a currency string in any of several common notations.
+///
+/// Extends [`parse_currency_string`] to also accept:
+/// - Accounting-style negatives in parentheses, such as `($1,234.56)`
+/// - Leading or trailing currency symbols and codes, such as `1234.56 USD`
+///   or `€1.234,56`
+/// - European notation, where `.` groups thousands and `,` marks decimals
+///
+/// Spaces (including non-breaking spaces) inside the number are treated as
+/// thousands separators in either notation.
+///
+/// # Arguments
+///
+/// * `input` - A string slice that represents a currency amount
+/// * `european` - Whether the amount uses `,` as its decimal separator
+///
+/// # Returns
+///
+/// * `Some(Decimal)` if a numeric value could be recovered
+/// * `None` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::utils::parse_currency_string_ext;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(parse_currency_string_ext("($1,234.56)", false), Some(dec!(-1234.56)));
+/// assert_eq!(parse_currency_string_ext("€1.234,56", true), Some(dec!(1234.56)));
+/// ```
+pub fn parse_currency_string_ext(input: &str, european: bool) -> Option<Decimal> {
+    // Anything that can't be part of the number, such as `$`, `€` or `USD`
+    let is_marker = |c: char| !(c.is_ascii_digit() || matches!(c, '-' | '(' | ')' | '.' | ','));
+
+    let mut text = input.trim_matches(is_marker);
+    let mut negative = false;
+    if let Some(inner) = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        negative = true;
+        text = inner.trim_matches(is_marker);
+    }
+    if let Some(unsigned) = text.strip_prefix('-') {
+        negative = true;
+        text = unsigned.trim_matches(is_marker);
+    }
+
+    let (group_separator, decimal_separator) = if european { ('.', ',') } else { (',', '.') };
+    let cleaned: String = text
+        .chars()
+        .filter(|&c| c != group_separator && !c.is_whitespace())
+        .map(|c| if c == decimal_separator { '.' } else { c })
+        .collect();
+
+    let amount = Decimal::from_str(&cleaned).ok()?;
+    Some(if negative { -amount } else { amount })
+}
+
 /// Formats a Decimal value as a currency string.
 ///
 /// The output format includes:
@@ -62,7 +118,203 @@ pub fn parse_currency_string(input: &str) -> Option<Decimal> {
 /// assert_eq!(formatted, "$1234.50");
 /// ```
 pub fn format_currency(amount: Decimal) -> String {
-    format!("${:.2}", amount)
+    format_currency_with(amount, RoundingStrategy::ToZero)
+}
+
+/// Formats a Decimal value as a currency string using a specific rounding strategy.
+///
+/// The amount is rounded to cents with `rounding` before formatting, for
+/// jurisdictions that require, for example, half-up rounding on tax displays.
+/// [`format_currency`] truncates extra digits (`ToZero`), so `0.129`
+/// formats as `$0.12`.
+///
+/// # Arguments
+///
+/// * `amount` - The Decimal value to format
+/// * `rounding` - How to round the amount to two decimal places
+///
+/// # Returns
+///
+/// A String representing the formatted currency amount
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::utils::format_currency_with;
+/// use rust_decimal::RoundingStrategy;
+/// use rust_decimal_macros::dec;
+///
+/// let formatted = format_currency_with(dec!(1234.565), RoundingStrategy::MidpointAwayFromZero);
+/// assert_eq!(formatted, "$1234.57");
+/// ```
+pub fn format_currency_with(amount: Decimal, rounding: RoundingStrategy) -> String {
+    format!("${:.2}", amount.round_dp_with_strategy(2, rounding))
+}
+
+/// Where the currency symbol is placed relative to the amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// Directly before the amount, as in `$1,234.50`
+    Before,
+    /// After the amount, separated by a space, as in `1 234,50 $`
+    After,
+}
+
+/// How negative amounts are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegativeStyle {
+    /// A leading minus sign, as in `-$1,234.50`
+    Minus,
+    /// Enclosed in parentheses, as in `($1,234.50)`
+    Parentheses,
+}
+
+/// Formatting rules for displaying currency amounts with grouped thousands.
+///
+/// Amounts are rounded to cents with banker's rounding, unlike
+/// [`format_currency`], which truncates.
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::utils::CurrencyFormat;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(CurrencyFormat::usd().format(dec!(1234.5)), "$1,234.50");
+/// assert_eq!(CurrencyFormat::cad_french().format(dec!(1234.5)), "1 234,50 $");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyFormat {
+    /// The currency symbol
+    pub symbol: String,
+    /// Where the symbol is placed
+    pub symbol_position: SymbolPosition,
+    /// Separator inserted between groups of three digits
+    pub thousands_separator: char,
+    /// Separator between whole units and cents
+    pub decimal_separator: char,
+    /// How negative amounts are written
+    pub negative_style: NegativeStyle,
+}
+
+impl CurrencyFormat {
+    /// Format for US dollars, such as `$1,234.50`.
+    pub fn usd() -> Self {
+        Self {
+            symbol: "$".to_string(),
+            symbol_position: SymbolPosition::Before,
+            thousands_separator: ',',
+            decimal_separator: '.',
+            negative_style: NegativeStyle::Minus,
+        }
+    }
+
+    /// Format for Canadian dollars in French, such as `1 234,50 $`.
+    pub fn cad_french() -> Self {
+        Self {
+            symbol: "$".to_string(),
+            symbol_position: SymbolPosition::After,
+            thousands_separator: ' ',
+            decimal_separator: ',',
+            negative_style: NegativeStyle::Minus,
+        }
+    }
+
+    /// Sets the currency symbol and where it is placed.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The currency symbol, such as `€`
+    /// * `position` - Whether the symbol goes before or after the amount
+    pub fn with_symbol(mut self, symbol: impl Into<String>, position: SymbolPosition) -> Self {
+        self.symbol = symbol.into();
+        self.symbol_position = position;
+        self
+    }
+
+    /// Sets the thousands and decimal separators.
+    ///
+    /// # Arguments
+    ///
+    /// * `thousands` - Separator between groups of three digits
+    /// * `decimal` - Separator between whole units and cents
+    pub fn with_separators(mut self, thousands: char, decimal: char) -> Self {
+        self.thousands_separator = thousands;
+        self.decimal_separator = decimal;
+        self
+    }
+
+    /// Sets how negative amounts are written.
+    ///
+    /// # Arguments
+    ///
+    /// * `negative_style` - A leading minus sign or parentheses
+    pub fn with_negative_style(mut self, negative_style: NegativeStyle) -> Self {
+        self.negative_style = negative_style;
+        self
+    }
+
+    /// Formats an amount according to these rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The Decimal value to format
+    ///
+    /// # Returns
+    ///
+    /// A String representing the formatted currency amount
+    pub fn format(&self, amount: Decimal) -> String {
+        let rounded = amount.round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven);
+        let digits = format!("{:.2}", rounded.abs());
+        let (whole, cents) = digits.split_once('.').unwrap_or((&digits, "00"));
+
+        let mut grouped = String::new();
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0 && (whole.len() - index) % 3 == 0 {
+                grouped.push(self.thousands_separator);
+            }
+            grouped.push(digit);
+        }
+        let number = format!("{}{}{}", grouped, self.decimal_separator, cents);
+
+        let with_symbol = match self.symbol_position {
+            SymbolPosition::Before => format!("{}{}", self.symbol, number),
+            SymbolPosition::After => format!("{} {}", number, self.symbol),
+        };
+
+        if !rounded.is_sign_negative() || rounded.is_zero() {
+            return with_symbol;
+        }
+        match self.negative_style {
+            NegativeStyle::Minus => format!("-{}", with_symbol),
+            NegativeStyle::Parentheses => format!("({})", with_symbol),
+        }
+    }
+}
+
+/// Formats a Decimal value as a US dollar string with thousands separators.
+///
+/// Shorthand for [`CurrencyFormat::usd`]; use [`CurrencyFormat`] directly for
+/// other symbols, separators or negative styles.
+///
+/// # Arguments
+///
+/// * `amount` - The Decimal value to format
+///
+/// # Returns
+///
+/// A String representing the formatted currency amount
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::utils::format_currency_grouped;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(format_currency_grouped(dec!(1234567.891)), "$1,234,567.89");
+/// ```
+pub fn format_currency_grouped(amount: Decimal) -> String {
+    CurrencyFormat::usd().format(amount)
 }
 
 #[cfg(test)]
@@ -78,10 +330,110 @@ mod tests {
         assert_eq!(parse_currency_string("invalid"), None);
     }
 
+    #[test]
+    fn test_parse_currency_string_ext() {
+        // The simple cases parse as before
+        assert_eq!(
+            parse_currency_string_ext("$1,234.56", false),
+            Some(dec!(1234.56))
+        );
+        assert_eq!(parse_currency_string_ext("1234", false), Some(dec!(1234)));
+        assert_eq!(parse_currency_string_ext("invalid", false), None);
+        assert_eq!(parse_currency_string_ext("", false), None);
+
+        // Negatives
+        assert_eq!(
+            parse_currency_string_ext("($1,234.56)", false),
+            Some(dec!(-1234.56))
+        );
+        assert_eq!(
+            parse_currency_string_ext("-$12.50", false),
+            Some(dec!(-12.50))
+        );
+        assert_eq!(
+            parse_currency_string_ext("$-12.50", false),
+            Some(dec!(-12.50))
+        );
+
+        // Trailing symbols and codes
+        assert_eq!(
+            parse_currency_string_ext("1234.56 USD", false),
+            Some(dec!(1234.56))
+        );
+        assert_eq!(
+            parse_currency_string_ext("CAD 1,000", false),
+            Some(dec!(1000))
+        );
+
+        // European notation
+        assert_eq!(
+            parse_currency_string_ext("€1.234,56", true),
+            Some(dec!(1234.56))
+        );
+        assert_eq!(
+            parse_currency_string_ext("1 234,50 $", true),
+            Some(dec!(1234.50))
+        );
+        assert_eq!(
+            parse_currency_string_ext("(1.234,56 EUR)", true),
+            Some(dec!(-1234.56))
+        );
+    }
+
     #[test]
     fn test_format_currency() {
         assert_eq!(format_currency(dec!(1234.56)), "$1234.56");
         assert_eq!(format_currency(dec!(1234.5)), "$1234.50");
         assert_eq!(format_currency(dec!(1234)), "$1234.00");
     }
+
+    #[test]
+    fn test_format_currency_with_rounding() {
+        assert_eq!(
+            format_currency_with(dec!(1234.565), RoundingStrategy::MidpointAwayFromZero),
+            "$1234.57"
+        );
+        assert_eq!(
+            format_currency_with(dec!(1234.565), RoundingStrategy::MidpointNearestEven),
+            "$1234.56"
+        );
+        assert_eq!(
+            format_currency_with(dec!(1234.575), RoundingStrategy::MidpointNearestEven),
+            "$1234.58"
+        );
+        // The default keeps truncating extra digits
+        assert_eq!(format_currency(dec!(0.129)), "$0.12");
+        assert_eq!(format_currency(dec!(1234.575)), "$1234.57");
+    }
+
+    #[test]
+    fn test_format_currency_grouped() {
+        assert_eq!(format_currency_grouped(dec!(0)), "$0.00");
+        assert_eq!(format_currency_grouped(dec!(0.5)), "$0.50");
+        assert_eq!(format_currency_grouped(dec!(999.99)), "$999.99");
+        assert_eq!(format_currency_grouped(dec!(1000)), "$1,000.00");
+        assert_eq!(format_currency_grouped(dec!(1234.5)), "$1,234.50");
+        assert_eq!(format_currency_grouped(dec!(123456789)), "$123,456,789.00");
+        assert_eq!(format_currency_grouped(dec!(-1234.5)), "-$1,234.50");
+        // Rounding to zero cents doesn't leave a negative sign
+        assert_eq!(format_currency_grouped(dec!(-0.001)), "$0.00");
+    }
+
+    #[test]
+    fn test_currency_format_styles() {
+        let french = CurrencyFormat::cad_french();
+        assert_eq!(french.format(dec!(1234.5)), "1 234,50 $");
+        assert_eq!(french.format(dec!(12.3)), "12,30 $");
+        assert_eq!(french.format(dec!(-1234567.5)), "-1 234 567,50 $");
+
+        let accounting = CurrencyFormat::usd().with_negative_style(NegativeStyle::Parentheses);
+        assert_eq!(accounting.format(dec!(-1234.5)), "($1,234.50)");
+        assert_eq!(accounting.format(dec!(-5)), "($5.00)");
+        assert_eq!(accounting.format(dec!(1234.5)), "$1,234.50");
+
+        let euro = CurrencyFormat::usd()
+            .with_symbol("€", SymbolPosition::After)
+            .with_separators('.', ',');
+        assert_eq!(euro.format(dec!(98765.4)), "98.765,40 €");
+    }
 }
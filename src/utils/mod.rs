@@ -4,5 +4,7 @@
 //! throughout the tax calculation system.
 
 pub mod currency;
+pub mod period;
 
 pub use self::currency::*;
+pub use self::period::*;
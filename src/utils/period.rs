@@ -0,0 +1,105 @@
+//! Conversions between annual figures and per-period equivalents.
+//!
+//! This module provides helpers for presenting annual amounts (tax owed,
+//! take-home pay) as monthly, biweekly or weekly figures and back.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Number of decimal places kept in per-period amounts.
+///
+/// Four places keep the rounding error of each period below 0.00005, so up to
+/// 200 periods can be summed back to the annual figure within one cent.
+pub const PERIOD_DECIMAL_PLACES: u32 = 4;
+
+/// Converts an annual amount into the equivalent amount per period.
+///
+/// The result is rounded half away from zero to [`PERIOD_DECIMAL_PLACES`]
+/// places, so summing `periods_per_year` results reconstitutes the annual
+/// amount within one cent. Round to cents only for display.
+///
+/// # Arguments
+///
+/// * `amount` - The annual amount
+/// * `periods_per_year` - Periods in a year (e.g., 12 for monthly, 26 for biweekly)
+///
+/// # Panics
+///
+/// Panics if `periods_per_year` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::utils::annual_to_period;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(annual_to_period(dec!(60000), 12), dec!(5000));
+/// assert_eq!(annual_to_period(dec!(100000), 12), dec!(8333.3333));
+/// ```
+pub fn annual_to_period(amount: Decimal, periods_per_year: u16) -> Decimal {
+    assert!(periods_per_year > 0, "periods_per_year must be positive");
+    (amount / Decimal::from(periods_per_year)).round_dp_with_strategy(
+        PERIOD_DECIMAL_PLACES,
+        RoundingStrategy::MidpointAwayFromZero,
+    )
+}
+
+/// Converts a per-period amount into the equivalent annual amount.
+///
+/// This is the inverse of [`annual_to_period`] and involves no rounding.
+///
+/// # Arguments
+///
+/// * `amount` - The amount per period
+/// * `periods_per_year` - Periods in a year (e.g., 12 for monthly, 52 for weekly)
+///
+/// # Examples
+///
+/// ```
+/// use tax_engine::utils::period_to_annual;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(period_to_annual(dec!(1000), 26), dec!(26000));
+/// ```
+pub fn period_to_annual(amount: Decimal, periods_per_year: u16) -> Decimal {
+    amount * Decimal::from(periods_per_year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn assert_reconstitutes(annual: Decimal, periods_per_year: u16) {
+        let per_period = annual_to_period(annual, periods_per_year);
+        let total = period_to_annual(per_period, periods_per_year);
+        assert!(
+            (total - annual).abs() < dec!(0.01),
+            "{} x {} = {} does not reconstitute {}",
+            per_period,
+            periods_per_year,
+            total,
+            annual
+        );
+    }
+
+    #[test]
+    fn test_monthly_conversion() {
+        assert_eq!(annual_to_period(dec!(100000), 12), dec!(8333.3333));
+        assert_reconstitutes(dec!(100000), 12);
+        assert_reconstitutes(dec!(12345.67), 12);
+    }
+
+    #[test]
+    fn test_biweekly_conversion() {
+        assert_eq!(annual_to_period(dec!(52000), 26), dec!(2000));
+        assert_reconstitutes(dec!(75000), 26);
+        assert_reconstitutes(dec!(98765.43), 26);
+    }
+
+    #[test]
+    fn test_weekly_conversion() {
+        assert_eq!(annual_to_period(dec!(100000), 52), dec!(1923.0769));
+        assert_reconstitutes(dec!(100000), 52);
+        assert_reconstitutes(dec!(33333.33), 52);
+    }
+}
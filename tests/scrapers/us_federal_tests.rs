@@ -1,7 +1,8 @@
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use tax_engine::{Country, Jurisdiction, TaxEntityType, TaxRateScraper, USFederalScraper};
-use tokio;
+use tax_engine::{
+    Country, FilingStatus, Jurisdiction, TaxEntityType, TaxRateScraper, USFederalScraper,
+};
 
 #[tokio::test]
 async fn test_live_fetch() {
@@ -10,6 +11,7 @@ async fn test_live_fetch() {
         .fetch_rates(
             &Jurisdiction::Federal(Country::USA),
             &TaxEntityType::Individual,
+            FilingStatus::Single,
             2024,
         )
         .await;
@@ -20,7 +22,7 @@ async fn test_live_fetch() {
             println!("======================");
             // Sort brackets by rate for clearer output
             let mut brackets = schedule.brackets;
-            brackets.sort_by(|a, b| a.rate.cmp(&b.rate));
+            brackets.sort_by_key(|b| b.rate);
 
             for bracket in &brackets {
                 println!(
@@ -43,3 +45,50 @@ async fn test_live_fetch() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_parses_bracket_tables_from_fixture() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock(
+            "GET",
+            "/newsroom/irs-provides-tax-inflation-adjustments-for-tax-year-2024",
+        )
+        .with_body(include_str!("../fixtures/irs_rate_tables_2024.html"))
+        .create_async()
+        .await;
+    let scraper = USFederalScraper::new().with_base_url(server.url());
+    let jurisdiction = Jurisdiction::Federal(Country::USA);
+
+    let single = scraper
+        .fetch_rates(
+            &jurisdiction,
+            &TaxEntityType::Individual,
+            FilingStatus::Single,
+            2024,
+        )
+        .await
+        .unwrap();
+    // The prose 37% bracket duplicates the table's top row
+    assert_eq!(single.brackets.len(), 7);
+    assert_eq!(single.brackets[0].upper_bound, Some(dec!(11600)));
+    assert_eq!(single.brackets[1].lower_bound, dec!(11600));
+    assert_eq!(single.brackets[1].rate, dec!(0.12));
+    assert_eq!(single.brackets[6].lower_bound, dec!(609350));
+    assert_eq!(single.brackets[6].upper_bound, None);
+    assert_eq!(single.brackets[6].rate, dec!(0.37));
+    assert!(single.validate().is_ok());
+
+    let joint = scraper
+        .fetch_rates(
+            &jurisdiction,
+            &TaxEntityType::Individual,
+            FilingStatus::MarriedFilingJointly,
+            2024,
+        )
+        .await
+        .unwrap();
+    assert_eq!(joint.brackets.len(), 7);
+    assert_eq!(joint.brackets[5].lower_bound, dec!(487450));
+    assert_eq!(joint.brackets[5].upper_bound, Some(dec!(731200)));
+}